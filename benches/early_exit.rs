@@ -0,0 +1,89 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_split_vec::*;
+
+fn std_vec(n: usize) -> Vec<u64> {
+    (0..n as u64).collect()
+}
+fn split_vec_linear(n: usize, constant_fragment_capacity_power: usize) -> SplitVec<u64, Linear> {
+    let mut vec = SplitVec::with_linear_growth(constant_fragment_capacity_power);
+    vec.extend(0..n as u64);
+    vec
+}
+fn split_vec_doubling(n: usize) -> SplitVec<u64, Doubling> {
+    let mut vec = SplitVec::with_doubling_growth();
+    vec.extend(0..n as u64);
+    vec
+}
+
+// early exit workloads
+fn calc_std_vec(vec: &[u64], target: u64) -> (Option<usize>, Option<&u64>, bool) {
+    let position = vec.iter().position(|x| *x == target);
+    let find = vec.iter().find(|x| **x == target);
+    let any = vec.contains(&target);
+    (position, find, any)
+}
+fn calc_split_vec<G: Growth>(
+    vec: &SplitVec<u64, G>,
+    target: u64,
+) -> (Option<usize>, Option<&u64>, bool) {
+    let position = vec.iter().position(|x| *x == target);
+    let find = vec.iter().find(|x| **x == target);
+    let any = vec.iter().any(|x| *x == target);
+    (position, find, any)
+}
+
+fn test_for_treatments(group: &mut BenchmarkGroup<'_, WallTime>, treatments: &[usize]) {
+    for n in treatments {
+        // target located at 3/4-th of the vector
+        let target = (*n * 3 / 4) as u64;
+        let treatment = format!("n={},target-at=3/4", n);
+
+        group.bench_with_input(BenchmarkId::new("std_vec", &treatment), n, |b, _| {
+            let vec = std_vec(black_box(*n));
+            b.iter(|| calc_std_vec(&vec, black_box(target)).0)
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("split_vec_linear - 2^10", &treatment),
+            n,
+            |b, _| {
+                let vec = split_vec_linear(black_box(*n), 10);
+                b.iter(|| calc_split_vec(&vec, black_box(target)).0)
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("split_vec_doubling", &treatment),
+            n,
+            |b, _| {
+                let vec = split_vec_doubling(black_box(*n));
+                b.iter(|| calc_split_vec(&vec, black_box(target)).0)
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("split_vec_recursive", &treatment),
+            n,
+            |b, _| {
+                let vec: SplitVec<_, Recursive> = split_vec_doubling(black_box(*n)).into();
+                b.iter(|| calc_split_vec(&vec, black_box(target)).0)
+            },
+        );
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let treatments = vec![1_024, 16_384, 262_144, 4_194_304];
+
+    let mut group = c.benchmark_group("early_exit");
+
+    test_for_treatments(&mut group, &treatments);
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+//! A custom growth strategy which can be used with the concurrent split vector.
+//!
+//! `FixedThenDoubling` allocates `NUM_FIXED` fragments of a fixed capacity first, and then,
+//! keeps doubling the capacity of the new fragments.
+//! Since the location of every element can be computed in constant time,
+//! it implements `GrowthWithConstantTimeAccess` and hence can be converted into a `ConcurrentSplitVec`.
+//!
+//! Custom growth strategies must pass the `growth_with_constant_time_access_conformance_check`
+//! before being used in concurrent programs.
+
+use orx_split_vec::*;
+
+#[derive(Clone, Copy, Debug)]
+struct FixedThenDoubling {
+    fixed_capacity: usize,
+}
+
+const NUM_FIXED: usize = 4;
+
+impl PseudoDefault for FixedThenDoubling {
+    fn pseudo_default() -> Self {
+        Self { fixed_capacity: 16 }
+    }
+}
+
+impl Growth for FixedThenDoubling {
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        self.fragment_capacity_of(fragment_capacities.len())
+    }
+
+    fn maximum_concurrent_capacity<T>(
+        &self,
+        fragments: &[Fragment<T>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+        (0..fragments_capacity)
+            .map(|f| self.fragment_capacity_of(f))
+            .sum()
+    }
+}
+
+impl GrowthWithConstantTimeAccess for FixedThenDoubling {
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        let fixed_total = NUM_FIXED * self.fixed_capacity;
+        match element_index < fixed_total {
+            true => (
+                element_index / self.fixed_capacity,
+                element_index % self.fixed_capacity,
+            ),
+            false => {
+                // doubling fragments have capacities 2c, 4c, 8c, ...
+                let c2 = 2 * self.fixed_capacity;
+                let j = element_index - fixed_total;
+                let m = (j / c2 + 1).ilog2() as usize;
+                let begin = c2 * ((1 << m) - 1);
+                (NUM_FIXED + m, j - begin)
+            }
+        }
+    }
+
+    fn fragment_capacity_of(&self, fragment_index: usize) -> usize {
+        match fragment_index < NUM_FIXED {
+            true => self.fixed_capacity,
+            false => self.fixed_capacity << (fragment_index - NUM_FIXED + 1),
+        }
+    }
+}
+
+fn main() {
+    let growth = FixedThenDoubling { fixed_capacity: 16 };
+    growth_with_constant_time_access_conformance_check(&growth, 10_000)
+        .expect("custom growth must conform");
+
+    let vec: SplitVec<usize, _> = SplitVec::with_growth(growth);
+    let mut con_vec = vec.into_concurrent();
+    unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 10_000) };
+
+    let (_, mut suffix) = con_vec.split_at_committed(0);
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            for i in 0..10_000 {
+                suffix.push(i).expect("within maximum capacity");
+            }
+        });
+    });
+    let len = suffix.len();
+
+    let vec = unsafe { con_vec.into_inner(len) };
+    assert_eq!(vec.len(), 10_000);
+    for (i, x) in vec.iter().enumerate() {
+        assert_eq!(i, *x);
+    }
+
+    let capacities: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+    println!("fragment capacities: {:?}", capacities);
+}
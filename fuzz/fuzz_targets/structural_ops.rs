@@ -0,0 +1,136 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use orx_split_vec::*;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Push(u16),
+    Insert(u16, u16),
+    Remove(u16),
+    Pop,
+    Truncate(u16),
+    Clear,
+    Append(Vec<u16>),
+    Extend(Vec<u16>),
+    ExtendFromSlice(Vec<u16>),
+    Slices(u16, u16),
+    SlicesMut(u16, u16),
+}
+
+#[derive(Arbitrary, Debug)]
+enum GrowthType {
+    Doubling,
+    Linear(u8),
+    Recursive,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    growth: GrowthType,
+    ops: Vec<Op>,
+}
+
+/// Maps an arbitrary value into `0..=len`.
+fn idx(x: u16, len: usize) -> usize {
+    x as usize % (len + 1)
+}
+
+fn assert_same<G: Growth>(vec: &SplitVec<u16, G>, oracle: &[u16]) {
+    assert_eq!(vec.len(), oracle.len());
+    assert_eq!(vec.iter().copied().collect::<Vec<_>>(), oracle);
+    assert_eq!(vec.iter_rev().copied().collect::<Vec<_>>(), {
+        let mut rev = oracle.to_vec();
+        rev.reverse();
+        rev
+    });
+    assert!(vec.capacity() >= vec.len());
+
+    let num_fragments = vec.fragments().len();
+    for (f, fragment) in vec.fragments().iter().enumerate() {
+        if f + 1 < num_fragments {
+            assert!(!fragment.is_empty(), "only the last fragment can be empty");
+        }
+    }
+}
+
+fn run<G: Growth>(
+    mut vec: SplitVec<u16, G>,
+    ops: Vec<Op>,
+    append: impl Fn(&mut SplitVec<u16, G>, Vec<u16>),
+) {
+    let mut oracle: Vec<u16> = Vec::new();
+
+    for op in ops {
+        match op {
+            Op::Push(x) => {
+                vec.push(x);
+                oracle.push(x);
+            }
+            Op::Insert(i, x) => {
+                let i = idx(i, oracle.len());
+                vec.insert(i, x);
+                oracle.insert(i, x);
+            }
+            Op::Remove(i) => {
+                if !oracle.is_empty() {
+                    let i = idx(i, oracle.len() - 1);
+                    assert_eq!(vec.remove(i), oracle.remove(i));
+                }
+            }
+            Op::Pop => assert_eq!(vec.pop(), oracle.pop()),
+            Op::Truncate(len) => {
+                let len = idx(len, oracle.len());
+                vec.truncate(len);
+                oracle.truncate(len);
+            }
+            Op::Clear => {
+                vec.clear();
+                oracle.clear();
+            }
+            Op::Append(values) => {
+                oracle.extend_from_slice(&values);
+                append(&mut vec, values);
+            }
+            Op::Extend(values) => {
+                oracle.extend(values.iter().copied());
+                vec.extend(values);
+            }
+            Op::ExtendFromSlice(values) => {
+                vec.extend_from_slice(&values);
+                oracle.extend_from_slice(&values);
+            }
+            Op::Slices(a, b) => {
+                let (a, b) = (idx(a, oracle.len()), idx(b, oracle.len()));
+                let flattened: Vec<_> = vec.slices(a..b).into_iter().flatten().copied().collect();
+                let expected = oracle.get(a..b).unwrap_or(&[]);
+                assert_eq!(flattened, expected);
+            }
+            Op::SlicesMut(a, b) => {
+                let (a, b) = (idx(a, oracle.len()), idx(b, oracle.len()));
+                for slice in vec.slices_mut(a..b) {
+                    slice.iter_mut().for_each(|x| *x = x.wrapping_add(1));
+                }
+                if a < b {
+                    oracle[a..b].iter_mut().for_each(|x| *x = x.wrapping_add(1));
+                }
+            }
+        }
+
+        assert_same(&vec, &oracle);
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let Input { growth, ops } = input;
+    match growth {
+        GrowthType::Doubling => run(SplitVec::with_doubling_growth(), ops, |v, x| v.extend(x)),
+        GrowthType::Linear(exp) => run(
+            SplitVec::with_linear_growth(1 + exp as usize % 8),
+            ops,
+            |v, x| v.extend(x),
+        ),
+        GrowthType::Recursive => run(SplitVec::with_recursive_growth(), ops, |v, x| v.append(x)),
+    }
+});
@@ -0,0 +1,276 @@
+use crate::{Fragment, Growth, IntoFragments, SplitVec};
+use alloc::vec::Vec;
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Consumes and appends `other` to the end of this vector, adopting the allocations of its fragments
+    /// whenever possible while making sure that the fragments obey the capacities of the growth strategy.
+    ///
+    /// Unlike [`SplitVec::append`] of the `Recursive` growth which takes the incoming fragments as they are,
+    /// the incoming fragments are shrunk, extended or split to have exactly the capacities that the growth `G`
+    /// would have allocated; see [`Fragment::from_vec_with_exact_capacity`].
+    /// Therefore, the internal structure of the vector is preserved, which allows, for instance,
+    /// to safely convert the vector into a [`ConcurrentSplitVec`] afterwards.
+    ///
+    /// The allocation of an incoming fragment is adopted when it fits into the next fragment of the growth as a whole;
+    /// its elements are moved into a new allocation only when the allocator is not able to adjust the capacity in place.
+    /// Otherwise, such as when the incoming fragment must be split or the last fragment of this vector has room which
+    /// is filled by the leading incoming elements, the incoming elements are moved into the fragments in a single pass.
+    ///
+    /// [`SplitVec::append`]: crate::SplitVec::append
+    /// [`ConcurrentSplitVec`]: crate::ConcurrentSplitVec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_, Doubling> = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// vec.adopt(vec![vec![4, 5, 6], (7..20).collect()]);
+    /// assert_eq!(vec, (0..20).collect::<Vec<_>>());
+    ///
+    /// let capacities: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+    /// assert_eq!(capacities, [4, 8, 16]);
+    ///
+    /// let con_vec = vec.into_concurrent();
+    /// let vec = unsafe { con_vec.into_inner(20) };
+    /// assert_eq!(vec, (0..20).collect::<Vec<_>>());
+    /// ```
+    pub fn adopt<I: IntoFragments<T>>(&mut self, other: I) {
+        self.drop_last_empty_fragment();
+
+        for fragment in other.into_fragments() {
+            let vec: Vec<T> = fragment.into();
+            let room = self.fragments.last().map(|x| x.room()).unwrap_or(0);
+
+            // the allocation of the incoming vec is adopted when it forms a single fragment as a whole
+            let capacity = self.growth.new_fragment_capacity(&self.fragments);
+            if room == 0 && !vec.is_empty() && vec.len() <= capacity {
+                let (fragment, _) = Fragment::from_vec_with_exact_capacity(vec, capacity);
+                self.len += fragment.len();
+                self.push_fragment(fragment);
+                continue;
+            }
+
+            // otherwise, the incoming elements are moved chunk by chunk in a single pass
+            let mut iter = vec.into_iter();
+            if let Some(last) = self.fragments.last_mut() {
+                let num_to_fill = room.min(iter.len());
+                last.extend(iter.by_ref().take(num_to_fill));
+                self.len += num_to_fill;
+            }
+
+            while iter.len() > 0 {
+                let capacity = self.growth.new_fragment_capacity(&self.fragments);
+                let mut fragment = Fragment::new(capacity);
+                fragment.extend(iter.by_ref().take(capacity));
+                self.len += fragment.len();
+                self.push_fragment(fragment);
+            }
+        }
+
+        if self.fragments.is_empty() {
+            let capacity = self.growth.first_fragment_capacity();
+            self.push_fragment(Fragment::new(capacity));
+        }
+    }
+
+    /// Consumes and appends `other` vector into this vector.
+    ///
+    /// If the growth strategy accepts fragments of arbitrary capacities, such as the `Recursive` growth,
+    /// the incoming fragments are appended as they are in constant time without memory copies.
+    ///
+    /// Otherwise, the fragments must obey the capacities of the growth strategy, as `Doubling` and `Linear`
+    /// growth strategies require for constant time random access; and hence, `other` is appended by [`SplitVec::adopt`].
+    /// Incoming fragments are adopted without copying whenever their capacities match the capacities of the growth;
+    /// otherwise, their elements are moved in bulk into fragments of the right capacities.
+    /// Therefore, appending is never element-by-element.
+    ///
+    /// See [`Growth::accepts_arbitrary_fragment_capacities`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_split_vec::*;
+    ///
+    /// let mut recursive = SplitVec::with_recursive_growth();
+    ///
+    /// recursive.push('a');
+    /// assert_eq!(recursive, &['a']);
+    ///
+    /// recursive.append(vec!['b', 'c']);
+    /// assert_eq!(recursive, &['a', 'b', 'c']);
+    ///
+    /// recursive.append(vec![vec!['d'], vec!['e', 'f']]);
+    /// assert_eq!(recursive, &['a', 'b', 'c', 'd', 'e', 'f']);
+    ///
+    /// let other_split_vec: SplitVec<_> = vec!['g', 'h'].into();
+    /// recursive.append(other_split_vec);
+    /// assert_eq!(recursive, &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h']);
+    ///
+    /// let mut doubling = SplitVec::with_doubling_growth();
+    /// doubling.extend_from_slice(&['a', 'b', 'c', 'd']);
+    ///
+    /// // adopted as the second fragment without copies
+    /// let mut fragment = Vec::with_capacity(8);
+    /// fragment.extend(['e', 'f']);
+    /// doubling.append(fragment);
+    /// assert_eq!(doubling, &['a', 'b', 'c', 'd', 'e', 'f']);
+    /// assert_eq!(doubling.get(5), Some(&'f'));
+    /// ```
+    pub fn append<I: IntoFragments<T>>(&mut self, other: I) {
+        match self.growth.accepts_arbitrary_fragment_capacities() {
+            true => {
+                let fragments = other.into_fragments();
+                for fragment in fragments {
+                    self.len += fragment.len();
+                    self.push_fragment(fragment);
+                }
+                // TODO: does this break internal structure of the vec; be careful on its impact on linked-list
+            }
+            false => self.adopt(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn adopt() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut expected = Vec::new();
+            for i in 0..5 {
+                vec.push(i);
+                expected.push(i);
+            }
+
+            let mut other = Vec::with_capacity(100);
+            other.extend(5..8);
+            vec.adopt(other);
+            expected.extend(5..8);
+            assert_eq!(vec, expected);
+
+            vec.adopt(vec![vec![], (8..20).collect(), (20..60).collect()]);
+            expected.extend(8..60);
+            assert_eq!(vec, expected);
+
+            let other: SplitVec<_> = (60..100).collect();
+            vec.adopt(other);
+            expected.extend(60..100);
+            assert_eq!(vec, expected);
+
+            let mut fragments = Vec::new();
+            for f in 0..vec.fragments().len() {
+                let capacity = vec.growth().new_fragment_capacity(&fragments);
+                assert_eq!(vec.fragments()[f].capacity(), capacity);
+                fragments.push(Fragment::<usize>::new(capacity));
+            }
+
+            let num_fragments = vec.fragments().len();
+            for f in 0..(num_fragments - 1) {
+                assert_eq!(vec.fragments()[f].room(), 0);
+            }
+
+            for i in 100..200 {
+                vec.push(i);
+                expected.push(i);
+            }
+            assert_eq!(vec, expected);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn adopt_into_empty() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.adopt(Vec::<usize>::new());
+            assert!(vec.is_empty());
+            assert_eq!(vec.fragments().len(), 1);
+
+            vec.adopt(vec![0, 1, 2]);
+            assert_eq!(vec, &[0, 1, 2]);
+            assert_eq!(
+                vec.fragments()[0].capacity(),
+                vec.growth().first_fragment_capacity()
+            );
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn adopt_then_into_concurrent() {
+        fn test<G: GrowthWithConstantTimeAccess>(mut vec: SplitVec<usize, G>) {
+            vec.push(0);
+            vec.adopt(vec![(1..3).collect::<Vec<_>>(), (3..50).collect()]);
+            vec.adopt((50..100).collect::<Vec<_>>());
+
+            let con_vec = vec.into_concurrent();
+            let vec = unsafe { con_vec.into_inner(100) };
+            assert_eq!(vec, (0..100).collect::<Vec<_>>());
+        }
+        test(SplitVec::with_doubling_growth());
+        test(SplitVec::with_linear_growth(3));
+    }
+
+    #[test]
+    fn append() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let arbitrary = vec.growth().accepts_arbitrary_fragment_capacities();
+            let mut expected = Vec::new();
+            for i in 0..20 {
+                let other: Vec<_> = (0..(2 + i * 7 % 23)).map(|x| x + expected.len()).collect();
+                expected.extend(other.iter().copied());
+                match i % 3 {
+                    0 => vec.append(other),
+                    1 => vec.append(vec![other[..1].to_vec(), other[1..].to_vec()]),
+                    _ => vec.append(other.into_iter().collect::<SplitVec<_>>()),
+                }
+                assert_eq!(vec, expected);
+            }
+
+            for (i, x) in expected.iter().enumerate() {
+                assert_eq!(vec.get(i), Some(x));
+            }
+
+            if !arbitrary {
+                let mut fragments = Vec::new();
+                for f in 0..vec.fragments().len() {
+                    let capacity = vec.growth().new_fragment_capacity(&fragments);
+                    assert_eq!(vec.fragments()[f].capacity(), capacity);
+                    fragments.push(Fragment::<usize>::new(capacity));
+                }
+            }
+
+            // adopts the allocation of a fragment matching the growth, or appends it as is
+            let capacity = vec.growth().new_fragment_capacity(vec.fragments());
+            vec.extend(0..(vec.capacity() - vec.len()));
+            let mut other = Vec::with_capacity(capacity);
+            other.push(42);
+            let ptr = other.as_ptr();
+            vec.append(other);
+            assert_eq!(vec.fragments().last().map(|x| x.as_ptr()), Some(ptr));
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn adopt_large_vec_into_small_fragments() {
+        let n = 200_000;
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.push(0);
+        vec.adopt((1..n).collect::<Vec<_>>());
+        assert_eq!(vec.len(), n);
+        assert_eq!(vec.fragments().len(), n / 4);
+        assert!(vec.fragments().iter().all(|x| x.capacity() == 4));
+        assert!(vec.iter().copied().eq(0..n));
+    }
+}
@@ -111,6 +111,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::redundant_closure, clippy::needless_range_loop)]
 mod tests {
     use super::*;
     use crate::{Doubling, Growth, Linear, Recursive};
@@ -1,2 +1,3 @@
 pub mod binary_search;
 pub mod in_place_sort;
+pub mod stable_sort;
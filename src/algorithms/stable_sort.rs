@@ -0,0 +1,36 @@
+use crate::Fragment;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Stable sort of the elements of the `fragments` as one sequence.
+///
+/// References to the elements are sorted with the stable sort of the standard library; the elements themselves
+/// are neither moved nor copied while `compare` is called. Hence, if `compare` panics, the fragments are left
+/// with their original elements in the original order.
+/// Once the order is determined, the elements are moved in sorted order into an auxiliary buffer and back to
+/// the fragments, which cannot panic.
+pub fn stable_sort_by<T, F>(fragments: &mut [Fragment<T>], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut order: Vec<&T> = fragments.iter().flat_map(|x| x.iter()).collect();
+    order.sort_by(|a, b| compare(a, b));
+
+    // the buffer is used only as memory; its length is never set, and hence, it never drops the elements
+    let mut buffer: Vec<T> = Vec::with_capacity(order.len());
+    let sorted = buffer.as_mut_ptr();
+    for (i, x) in order.into_iter().enumerate() {
+        // SAFETY: `order` is a permutation of the elements; hence, each element is read exactly once
+        unsafe { sorted.add(i).write(core::ptr::read(x)) };
+    }
+
+    let mut src = sorted as *const T;
+    for fragment in fragments.iter_mut() {
+        let n = fragment.len();
+        // SAFETY: each sorted element is moved back exactly once into the positions read from
+        unsafe {
+            core::ptr::copy_nonoverlapping(src, fragment.as_mut_ptr(), n);
+            src = src.add(n);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Growth, SplitVec};
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+use orx_pinned_vec::PinnedVec;
+
+unsafe impl<G: Growth> BufMut for SplitVec<u8, G> {
+    fn remaining_mut(&self) -> usize {
+        usize::MAX - self.len
+    }
+
+    /// Marks the next `cnt` bytes of the spare capacity of the last fragment as initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cnt` exceeds the length of the slice returned by the last call to `chunk_mut`.
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let fragment = match self.fragments.last_mut() {
+            Some(fragment) if cnt <= fragment.capacity() - fragment.len() => fragment,
+            _ => {
+                assert_eq!(cnt, 0, "cannot advance beyond the last chunk");
+                return;
+            }
+        };
+        let len = fragment.len() + cnt;
+        unsafe { fragment.set_len(len) };
+        self.len += cnt;
+    }
+
+    /// Returns the spare capacity of the last fragment; a new fragment is added if the last one is full.
+    ///
+    /// Therefore, the chunks written through `BufMut` are never moved, as is the case for all elements of the vector.
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if !self.has_capacity_for_one() {
+            self.add_fragment();
+        }
+        let spare: &mut [_] = match self.fragments.last_mut() {
+            Some(fragment) => fragment.spare_capacity_mut(),
+            None => &mut [],
+        };
+        UninitSlice::uninit(spare)
+    }
+
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+}
+
+impl<G: Growth> SplitVec<u8, G> {
+    /// Creates a [`SplitVecBuf`] cursor over the bytes of the vector, implementing `bytes::Buf`.
+    ///
+    /// Chunks of the cursor are the fragments of the vector; hence, the bytes are read without being copied into a
+    /// contiguous buffer.
+    ///
+    /// This method is available with the **bytes** feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use bytes::{Buf, BufMut};
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.put_u32(42);
+    /// vec.put_slice(b"payload");
+    /// assert_eq!(vec.fragments().len(), 3);
+    ///
+    /// let mut buf = vec.buf();
+    /// assert_eq!(buf.chunk(), &[0, 0, 0, 42]);
+    /// assert_eq!(buf.get_u32(), 42);
+    /// assert_eq!(buf.remaining(), 7);
+    /// assert_eq!(buf.copy_to_bytes(7).as_ref(), b"payload");
+    /// ```
+    pub fn buf(&self) -> SplitVecBuf<'_, G> {
+        let mut buf = SplitVecBuf {
+            vec: self,
+            f: 0,
+            i: 0,
+            remaining: self.len,
+        };
+        buf.skip_exhausted_fragments();
+        buf
+    }
+}
+
+/// A cursor over the bytes of a `SplitVec<u8, G>` implementing `bytes::Buf`, with the fragments as its chunks.
+///
+/// Created by the [`SplitVec::buf`] method.
+///
+/// This type is available with the **bytes** feature.
+pub struct SplitVecBuf<'a, G: Growth> {
+    vec: &'a SplitVec<u8, G>,
+    /// Fragment of the current position; it is never an exhausted fragment unless the cursor is at the end.
+    f: usize,
+    /// Position within the `f`-th fragment.
+    i: usize,
+    remaining: usize,
+}
+
+impl<G: Growth> SplitVecBuf<'_, G> {
+    fn skip_exhausted_fragments(&mut self) {
+        let fragments = &self.vec.fragments;
+        while self.f < fragments.len() && self.i == fragments[self.f].len() {
+            self.f += 1;
+            self.i = 0;
+        }
+    }
+}
+
+impl<G: Growth> Buf for SplitVecBuf<'_, G> {
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn chunk(&self) -> &[u8] {
+        match self.vec.fragments.get(self.f) {
+            Some(fragment) => &fragment[self.i..],
+            None => &[],
+        }
+    }
+
+    /// Advances the cursor by `cnt` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cnt` is greater than the number of remaining bytes.
+    fn advance(&mut self, cnt: usize) {
+        assert!(cnt <= self.remaining, "cannot advance beyond the end");
+        self.remaining -= cnt;
+
+        let mut cnt = cnt;
+        while cnt > 0 {
+            let available = self.vec.fragments[self.f].len() - self.i;
+            let n = cnt.min(available);
+            self.i += n;
+            cnt -= n;
+            self.skip_exhausted_fragments();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use bytes::{Buf, BufMut};
+
+    #[test]
+    fn buf_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            let mut expected = Vec::new();
+            for i in 0..100u16 {
+                vec.put_u16(i);
+                expected.put_u16(i);
+                vec.put_bytes(7, (i % 5) as usize);
+                expected.put_bytes(7, (i % 5) as usize);
+                vec.put_slice(&[1, 2, 3]);
+                expected.put_slice(&[1, 2, 3]);
+            }
+            assert_eq!(vec, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn chunk_mut_is_tail_of_last_fragment() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.put_slice(&[1, 2, 3]);
+
+        let chunk = vec.chunk_mut();
+        assert_eq!(chunk.len(), 1);
+        chunk.write_byte(0, 4);
+        unsafe { vec.advance_mut(1) };
+        assert_eq!(vec.fragments().len(), 1);
+
+        let chunk = vec.chunk_mut();
+        assert_eq!(chunk.len(), 4);
+        assert_eq!(vec.fragments().len(), 2);
+        assert_eq!(vec, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn buf() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            assert_eq!(vec.buf().remaining(), 0);
+            assert!(vec.buf().chunk().is_empty());
+
+            let expected: Vec<u8> = (0..300).map(|x| (x % 251) as u8).collect();
+            vec.extend_from_slice(&expected);
+
+            let mut buf = vec.buf();
+            let mut chunks = Vec::new();
+            while buf.has_remaining() {
+                let chunk = buf.chunk();
+                chunks.push(chunk.len());
+                let n = chunk.len();
+                buf.advance(n);
+            }
+            let fragment_lens: Vec<_> = vec.fragments().iter().map(|x| x.len()).collect();
+            assert_eq!(chunks, fragment_lens);
+
+            let mut buf = vec.buf();
+            let mut read = Vec::new();
+            let mut cnt = 1;
+            while buf.has_remaining() {
+                let n = cnt.min(buf.remaining());
+                read.extend_from_slice(&buf.copy_to_bytes(n));
+                cnt = cnt % 13 + 1;
+            }
+            assert_eq!(read, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+}
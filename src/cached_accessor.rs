@@ -0,0 +1,188 @@
+use crate::{Growth, SplitVec};
+
+/// A read-only accessor to the elements of a split vector, which caches the fragment of the most recent access.
+///
+/// Whenever the index of an access belongs to the cached fragment or one of its neighbors,
+/// the element is located without consulting the growth strategy.
+/// Otherwise, the accessor falls back to the growth's `get_fragment_and_inner_indices` and caches the new fragment.
+///
+/// This is particularly beneficial for growth strategies without constant time random access such as
+/// [`Recursive`](crate::Recursive), where the fallback requires a linear scan over the fragments,
+/// when the accessed indices are clustered; such as neighborhoods of nodes in graph algorithms.
+///
+/// This struct is created by `SplitVec::cached_accessor()` method.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let vec: SplitVec<_, Recursive> = (0..1000).collect();
+/// let mut accessor = vec.cached_accessor();
+///
+/// for i in [500, 501, 499, 510, 3, 4] {
+///     assert_eq!(accessor.get(i), Some(&i));
+/// }
+/// assert_eq!(accessor.get(1000), None);
+/// ```
+#[derive(Debug)]
+pub struct CachedAccessor<'a, T, G: Growth> {
+    vec: &'a SplitVec<T, G>,
+    f: usize,
+    begin: usize,
+}
+
+impl<T, G: Growth> Clone for CachedAccessor<'_, T, G> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec,
+            f: self.f,
+            begin: self.begin,
+        }
+    }
+}
+
+impl<'a, T, G: Growth> CachedAccessor<'a, T, G> {
+    pub(crate) fn new(vec: &'a SplitVec<T, G>) -> Self {
+        Self {
+            vec,
+            f: 0,
+            begin: 0,
+        }
+    }
+
+    /// Returns a reference to the element at the given `index`; returns None if the index is out of bounds.
+    ///
+    /// The lookup first checks the cached fragment and its neighbors; and falls back to the growth strategy otherwise.
+    pub fn get(&mut self, index: usize) -> Option<&'a T> {
+        let fragments = &self.vec.fragments;
+
+        if index >= self.vec.len {
+            return None;
+        }
+
+        let fragment = &fragments[self.f];
+        if index >= self.begin {
+            let i = index - self.begin;
+            if i < fragment.len() {
+                return fragment.get(i);
+            }
+
+            // next fragment
+            let next_begin = self.begin + fragment.len();
+            if let Some(next) = fragments.get(self.f + 1) {
+                let i = index - next_begin;
+                if i < next.len() {
+                    self.f += 1;
+                    self.begin = next_begin;
+                    return next.get(i);
+                }
+            }
+        } else if self.f > 0 {
+            // previous fragment
+            let prev = &fragments[self.f - 1];
+            let prev_begin = self.begin - prev.len();
+            if index >= prev_begin {
+                self.f -= 1;
+                self.begin = prev_begin;
+                return prev.get(index - prev_begin);
+            }
+        }
+
+        let (f, i) =
+            self.vec
+                .growth
+                .get_fragment_and_inner_indices(self.vec.len, fragments, index)?;
+        self.f = f;
+        self.begin = index - i;
+        fragments[f].get(i)
+    }
+
+    /// Returns the index of the fragment which is currently cached by the accessor.
+    pub fn cached_fragment(&self) -> usize {
+        self.f
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Creates a read-only accessor to the elements of the vector, which caches the fragment of the most recent access.
+    ///
+    /// Repeated nearby random accesses through the accessor skip the growth strategy's fragment lookup,
+    /// which is a linear scan over the fragments for growth strategies such as [`Recursive`](crate::Recursive).
+    ///
+    /// See [`CachedAccessor`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend(0..100);
+    /// vec.append(vec![100, 101, 102]);
+    ///
+    /// let mut accessor = vec.cached_accessor();
+    ///
+    /// assert_eq!(accessor.get(50), Some(&50));
+    /// let f = accessor.cached_fragment();
+    ///
+    /// assert_eq!(accessor.get(55), Some(&55));
+    /// assert_eq!(accessor.cached_fragment(), f);
+    ///
+    /// assert_eq!(accessor.get(101), Some(&101));
+    /// assert_eq!(accessor.cached_fragment(), vec.fragments().len() - 1);
+    /// ```
+    pub fn cached_accessor(&self) -> CachedAccessor<'_, T, G> {
+        CachedAccessor::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn cached_accessor() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            {
+                let mut accessor = vec.cached_accessor();
+                assert_eq!(accessor.get(0), None);
+            }
+
+            let n = 1733;
+            vec.extend(0..n);
+
+            let mut accessor = vec.cached_accessor();
+            let mut indices: Vec<_> = (0..n).collect();
+            indices.extend((0..n).rev());
+            indices.extend((0..n).map(|i| (i * 7919) % n));
+            indices.extend((0..n).map(|i| (i / 2 + (i % 3) * 10) % n));
+
+            for i in indices {
+                assert_eq!(accessor.get(i), Some(&i));
+            }
+
+            assert_eq!(accessor.get(n), None);
+            assert_eq!(accessor.get(n + 100), None);
+            assert_eq!(accessor.get(n - 1), Some(&(n - 1)));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn cached_accessor_with_appended_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.extend(0..10);
+        vec.append((10..13).collect::<Vec<_>>());
+        vec.append((13..50).collect::<Vec<_>>());
+        vec.extend(50..70);
+
+        let mut accessor = vec.cached_accessor();
+        for i in (0..70).chain((0..70).rev()).chain([3, 60, 11, 12, 13, 40]) {
+            assert_eq!(accessor.get(i), Some(&i));
+        }
+    }
+}
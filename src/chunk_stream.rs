@@ -0,0 +1,177 @@
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use orx_pinned_vec::PinnedVec;
+
+/// An async [`Stream`] yielding fragment-aligned chunks of a split vector, created by
+/// [`SplitVec::chunk_stream`] or [`SplitVec::chunk_stream_with_max_len`].
+///
+/// Each chunk is a contiguous slice which belongs to a single fragment; i.e., chunks never cross fragment boundaries.
+///
+/// The stream does not depend on any async runtime; it is always ready to yield the next chunk,
+/// and allows async pipelines to consume very large vectors chunk by chunk with backpressure.
+pub struct ChunkStream<'a, T> {
+    slices: alloc::vec::IntoIter<&'a [T]>,
+    current: &'a [T],
+    max_chunk_len: usize,
+}
+
+impl<'a, T> ChunkStream<'a, T> {
+    fn new(slices: Vec<&'a [T]>, max_chunk_len: usize) -> Self {
+        assert!(max_chunk_len > 0, "max_chunk_len must be positive");
+        Self {
+            slices: slices.into_iter(),
+            current: &[],
+            max_chunk_len,
+        }
+    }
+
+    fn next_chunk(&mut self) -> Option<&'a [T]> {
+        while self.current.is_empty() {
+            self.current = self.slices.next()?;
+        }
+
+        let len = self.current.len().min(self.max_chunk_len);
+        let (chunk, rest) = self.current.split_at(len);
+        self.current = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T> Stream for ChunkStream<'a, T> {
+    type Item = &'a [T];
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().next_chunk())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_chunks = |x: &[T]| x.len().div_ceil(self.max_chunk_len);
+        let remaining = num_chunks(self.current)
+            + self
+                .slices
+                .as_slice()
+                .iter()
+                .map(|x| num_chunks(x))
+                .sum::<usize>();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Creates an async [`ChunkStream`] yielding the fragments of the vector as slices.
+    ///
+    /// This method is available with the **async** feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use core::pin::pin;
+    /// use core::task::{Context, Poll, Waker};
+    /// use futures_core::Stream;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut stream = pin!(vec.chunk_stream());
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(&[0, 1, 2, 3][..])));
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(&[4, 5][..])));
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    /// ```
+    pub fn chunk_stream(&self) -> ChunkStream<'_, T> {
+        ChunkStream::new(self.slices(..), usize::MAX)
+    }
+
+    /// Creates an async [`ChunkStream`] yielding fragment-aligned chunks of the vector
+    /// each of which has a length of at most `max_chunk_len`.
+    ///
+    /// This method is available with the **async** feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_chunk_len` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use core::pin::pin;
+    /// use core::task::{Context, Poll, Waker};
+    /// use futures_core::Stream;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut stream = pin!(vec.chunk_stream_with_max_len(3));
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(&[0, 1, 2][..])));
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(&[3][..])));
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(&[4, 5][..])));
+    /// assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    /// ```
+    pub fn chunk_stream_with_max_len(&self, max_chunk_len: usize) -> ChunkStream<'_, T> {
+        ChunkStream::new(self.slices(..), max_chunk_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use futures_core::Stream;
+
+    fn collect_chunks<'a, T>(stream: ChunkStream<'a, T>) -> Vec<&'a [T]> {
+        let mut stream = pin!(stream);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut chunks = Vec::new();
+        while let Poll::Ready(Some(chunk)) = stream.as_mut().poll_next(&mut cx) {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    #[test]
+    fn chunk_stream() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(collect_chunks(vec.chunk_stream()).is_empty());
+
+            for i in 0..1033 {
+                vec.push(i);
+            }
+
+            let chunks = collect_chunks(vec.chunk_stream());
+            assert_eq!(chunks.len(), vec.fragments().len());
+            for (chunk, fragment) in chunks.iter().zip(vec.fragments()) {
+                assert_eq!(*chunk, fragment.as_slice());
+            }
+
+            for max_chunk_len in [1, 3, 4, 7, 100, 2000] {
+                let stream = vec.chunk_stream_with_max_len(max_chunk_len);
+                let (min, max) = stream.size_hint();
+
+                let chunks = collect_chunks(stream);
+                assert_eq!(min, chunks.len());
+                assert_eq!(max, Some(chunks.len()));
+                assert!(chunks
+                    .iter()
+                    .all(|x| !x.is_empty() && x.len() <= max_chunk_len));
+
+                let flattened: Vec<_> = chunks.into_iter().flatten().copied().collect();
+                assert_eq!(flattened, (0..1033).collect::<Vec<_>>());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+}
@@ -1,5 +1,7 @@
+use crate::range_helpers::{range_end, range_start};
 use crate::{Growth, SplitVec};
 use alloc::vec::Vec;
+use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
 
 impl<T, G> Clone for SplitVec<T, G>
@@ -16,13 +18,94 @@ where
             fragments.push(vec.into());
         }
 
-        Self::from_raw_parts(self.len(), fragments, self.growth().clone())
+        let mut clone = Self::from_parts(self.len(), fragments, self.growth().clone());
+        clone.memory_policy = self.memory_policy;
+        clone
+    }
+
+    /// Performs copy-assignment from `source`, reusing the allocations of the fragments of this vector.
+    ///
+    /// Each fragment with the same capacity as the corresponding fragment of `source` keeps its allocation;
+    /// its elements are overwritten by `clone_from_slice` and the remaining elements are appended in bulk.
+    /// Fragments with different capacities are replaced by new fragments with the capacity of the source fragment.
+    fn clone_from(&mut self, source: &Self) {
+        self.invalidate_stable_indices();
+        self.fragments.truncate(source.fragments.len());
+
+        for (f, src) in source.fragments.iter().enumerate() {
+            match self.fragments.get_mut(f) {
+                Some(dst) if dst.capacity() == src.capacity() => dst.data.clone_from(&src.data),
+                dst => {
+                    let mut vec = Vec::with_capacity(src.capacity());
+                    vec.extend_from_slice(src);
+                    match dst {
+                        Some(dst) => *dst = vec.into(),
+                        None => self.fragments.push(vec.into()),
+                    }
+                }
+            }
+        }
+
+        self.len = source.len;
+        self.growth = source.growth.clone();
+        self.memory_policy = source.memory_policy;
+        self.refresh_capacity();
+    }
+}
+
+impl<T, G> SplitVec<T, G>
+where
+    T: Clone,
+    G: Growth,
+{
+    /// Creates a new split vector with the same growth strategy containing clones of the elements in the given `range`.
+    ///
+    /// Elements are cloned slice by slice into the fragments of the new vector which are allocated with
+    /// the capacities of the growth strategy; hence, it is more efficient than
+    /// `vec.iter().skip(a).take(b - a).cloned().collect()`.
+    ///
+    /// Capacity for `range.len()` elements is reserved before cloning the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end is greater than the length of the vector;
+    /// similar to slice indexing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let clone = vec.clone_range(3..7);
+    /// assert_eq!(clone, &[3, 4, 5, 6]);
+    ///
+    /// let clone = vec.clone_range(8..);
+    /// assert_eq!(clone, &[8, 9]);
+    /// ```
+    pub fn clone_range<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let start = range_start(&range);
+        let end = range_end(&range, self.len);
+        assert!(start <= end, "clone range start must not exceed end");
+        assert!(end <= self.len, "clone range end is out of bounds");
+
+        let mut clone = Self::with_growth(self.growth.clone());
+        clone.reserve(end - start);
+        for slice in self.slices(start..end) {
+            clone.extend_from_slice(slice);
+        }
+        clone
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_all_growth_types;
     use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
 
     #[test]
     fn clone() {
@@ -54,4 +137,89 @@ mod tests {
 
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn clone_from() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let mut clone = vec.clone();
+            for n in [0, 1, 10, 168, 17, 0, 100] {
+                vec.clear();
+                vec.extend((0..n).map(|x| x.to_string()));
+                vec.push("last".to_string());
+
+                let pointers: Vec<_> = clone.fragments().iter().map(|x| x.as_ptr()).collect();
+                clone.clone_from(&vec);
+
+                assert_eq!(clone, vec);
+                assert_eq!(clone.capacity(), vec.capacity());
+                for (a, b) in vec.fragments().iter().zip(clone.fragments().iter()) {
+                    assert_eq!(a.len(), b.len());
+                    assert_eq!(a.capacity(), b.capacity());
+                }
+
+                // allocations of the fragments with matching capacities are reused
+                for (f, ptr) in pointers.iter().enumerate() {
+                    if let Some(fragment) = clone.fragments().get(f) {
+                        if fragment.capacity() == vec.fragments()[f].capacity() {
+                            assert_eq!(fragment.as_ptr(), *ptr);
+                        }
+                    }
+                }
+
+                clone.push("new".to_string());
+                assert_eq!(clone.len(), vec.len() + 1);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn clone_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.clone_range(..).is_empty());
+
+            for i in 0..168 {
+                vec.push(i);
+            }
+
+            for (a, b) in [
+                (0, 168),
+                (0, 0),
+                (0, 1),
+                (3, 4),
+                (3, 60),
+                (10, 168),
+                (150, 167),
+            ] {
+                let clone = vec.clone_range(a..b);
+                assert_eq!(clone, (a..b).collect::<Vec<_>>());
+
+                let num_fragments = clone.fragments().len();
+                for (f, fragment) in clone.fragments().iter().enumerate() {
+                    if f < num_fragments - 1 {
+                        assert_eq!(fragment.room(), 0);
+                    }
+                }
+            }
+
+            assert_eq!(vec.clone_range(..), vec);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clone_range_out_of_bounds() {
+        let vec: SplitVec<_> = (0..168).collect();
+        _ = vec.clone_range(100..169);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn clone_range_start_exceeding_end() {
+        let vec: SplitVec<_> = (0..168).collect();
+        _ = vec.clone_range(10..5);
+    }
 }
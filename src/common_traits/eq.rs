@@ -1,5 +1,8 @@
+use crate::range_helpers::{range_end, range_start};
 use crate::*;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
 
 impl<T, G, U> PartialEq<U> for SplitVec<T, G>
 where
@@ -39,28 +42,148 @@ where
     }
 }
 
-impl<T: PartialEq, G> PartialEq<SplitVec<T, G>> for SplitVec<T, G>
+impl<T: PartialEq, G, G2> PartialEq<SplitVec<T, G2>> for SplitVec<T, G>
 where
     G: Growth,
+    G2: Growth,
 {
-    fn eq(&self, other: &SplitVec<T, G>) -> bool {
-        let mut iter1 = self.iter();
-        let mut iter2 = other.iter();
-        loop {
-            match (iter1.next(), iter2.next()) {
-                (Some(x), Some(y)) => {
-                    if x != y {
+    fn eq(&self, other: &SplitVec<T, G2>) -> bool {
+        self.len == other.len
+            && compare_slices(
+                self.fragments.iter().map(|x| x.as_slice()),
+                other.fragments.iter().map(|x| x.as_slice()),
+                |a, b| match a == b {
+                    true => Some(Ordering::Equal),
+                    false => None,
+                },
+            ) == Some(Ordering::Equal)
+    }
+}
+
+impl<T: PartialEq, G: Growth> Eq for SplitVec<T, G> {}
+
+impl<T: PartialEq, G: Growth> SplitVec<T, G> {
+    /// Returns whether or not the vector starts with the elements of the `needle` slice.
+    ///
+    /// The prefix is compared against the slice fragment by fragment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert!(vec.starts_with(&[1, 2, 3, 4, 5]));
+    /// assert!(vec.starts_with(&[]));
+    /// assert!(!vec.starts_with(&[2]));
+    /// assert!(!vec.starts_with(&[1, 2, 3, 4, 5, 6, 7]));
+    /// ```
+    pub fn starts_with(&self, needle: &[T]) -> bool {
+        needle.len() <= self.len && self.eq_range(..needle.len(), needle)
+    }
+
+    /// Returns whether or not the vector ends with the elements of the `needle` slice.
+    ///
+    /// The suffix is compared against the slice fragment by fragment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert!(vec.ends_with(&[3, 4, 5, 6]));
+    /// assert!(vec.ends_with(&[]));
+    /// assert!(!vec.ends_with(&[5]));
+    /// assert!(!vec.ends_with(&[0, 1, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn ends_with(&self, needle: &[T]) -> bool {
+        needle.len() <= self.len && self.eq_range((self.len - needle.len()).., needle)
+    }
+
+    /// Returns whether or not the elements of the vector in the given `range` are equal to the elements of the `other` slice.
+    ///
+    /// The range is compared against the slice piecewise, fragment by fragment, by slice comparisons
+    /// without materializing the range.
+    ///
+    /// Returns false if the range is out of bounds or if its length is different than that of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(b"HDR:payload:END");
+    ///
+    /// assert!(vec.eq_range(0..4, b"HDR:"));
+    /// assert!(vec.eq_range(12.., b"END"));
+    /// assert!(!vec.eq_range(12.., b"EN"));
+    /// assert!(!vec.eq_range(12..16, b"END:"));
+    /// ```
+    pub fn eq_range<R: RangeBounds<usize>>(&self, range: R, other: &[T]) -> bool {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len);
+
+        match b.checked_sub(a) {
+            Some(len) if len == other.len() && b <= self.len => {
+                let mut beg = 0;
+                for slice in self.slices(a..b) {
+                    let end = beg + slice.len();
+                    if slice != &other[beg..end] {
                         return false;
                     }
+                    beg = end;
                 }
-                (None, None) => return true,
-                _ => return false,
+                true
             }
+            _ => false,
         }
     }
 }
 
-impl<T: PartialEq, G: Growth> Eq for SplitVec<T, G> {}
+/// Lexicographically compares the sequences formed by concatenating the slices of `a` and `b`.
+///
+/// Overlapping pieces of the slices are compared in bulk by `cmp`; the first non-equal result is returned.
+/// When all pieces are equal, the shorter sequence is the lesser one.
+pub(crate) fn compare_slices<'a, 'b, T, A, B, F>(a: A, b: B, mut cmp: F) -> Option<Ordering>
+where
+    T: 'a + 'b,
+    A: IntoIterator<Item = &'a [T]>,
+    B: IntoIterator<Item = &'b [T]>,
+    F: FnMut(&[T], &[T]) -> Option<Ordering>,
+{
+    let mut a = a.into_iter().filter(|x| !x.is_empty());
+    let mut b = b.into_iter().filter(|x| !x.is_empty());
+    let (mut x, mut y): (&[T], &[T]) = (&[], &[]);
+
+    loop {
+        if x.is_empty() {
+            x = a.next().unwrap_or_default();
+        }
+        if y.is_empty() {
+            y = b.next().unwrap_or_default();
+        }
+
+        match (x.is_empty(), y.is_empty()) {
+            (true, true) => return Some(Ordering::Equal),
+            (true, false) => return Some(Ordering::Less),
+            (false, true) => return Some(Ordering::Greater),
+            (false, false) => {
+                let n = x.len().min(y.len());
+                match cmp(&x[..n], &y[..n]) {
+                    Some(Ordering::Equal) => {}
+                    ordering => return ordering,
+                }
+                (x, y) = (&x[n..], &y[n..]);
+            }
+        }
+    }
+}
 
 pub(crate) fn are_fragments_eq_to_slice<T: PartialEq>(
     fragments: &[Fragment<T>],
@@ -100,4 +223,89 @@ mod tests {
 
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn eq_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.eq_range(.., &[]));
+            assert!(vec.eq_range(0..0, &[]));
+            assert!(!vec.eq_range(0..1, &[0]));
+
+            for i in 0..142 {
+                vec.push(i);
+            }
+            let expected: Vec<_> = (0..142).collect();
+
+            for (a, b) in [
+                (0, 142),
+                (0, 0),
+                (0, 1),
+                (3, 4),
+                (3, 60),
+                (10, 142),
+                (141, 142),
+            ] {
+                assert!(vec.eq_range(a..b, &expected[a..b]));
+
+                if a < b {
+                    let mut other = expected[a..b].to_vec();
+                    let last = other.len() - 1;
+                    other[last] += 1;
+                    assert!(!vec.eq_range(a..b, &other));
+
+                    assert!(!vec.eq_range(a..b, &expected[a..(b - 1)]));
+                }
+            }
+
+            assert!(vec.eq_range(.., &expected));
+            assert!(!vec.eq_range(140..143, &[140, 141, 142]));
+            #[allow(clippy::reversed_empty_ranges)]
+            let reversed = 5..4;
+            assert!(!vec.eq_range(reversed, &[]));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn eq_different_growth() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut other = SplitVec::with_linear_growth(3);
+            assert_eq!(vec, other);
+
+            vec.extend(0..142);
+            other.extend(0..142);
+            assert_eq!(vec, other);
+
+            other.push(142);
+            assert_ne!(vec, other);
+            other.pop();
+            *other.last_mut().expect("is-some") += 1;
+            assert_ne!(vec, other);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn starts_with_ends_with() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.starts_with(&[]));
+            assert!(vec.ends_with(&[]));
+            assert!(!vec.starts_with(&[0]));
+            assert!(!vec.ends_with(&[0]));
+
+            vec.extend(0..142);
+            let expected: Vec<_> = (0..142).collect();
+            for n in [0, 1, 3, 4, 12, 60, 141, 142] {
+                assert!(vec.starts_with(&expected[..n]));
+                assert!(vec.ends_with(&expected[(142 - n)..]));
+            }
+            assert!(!vec.starts_with(&[1]));
+            assert!(!vec.ends_with(&[140]));
+            assert!(!vec.starts_with(&(0..143).collect::<Vec<_>>()));
+        }
+
+        test_all_growth_types!(test);
+    }
 }
@@ -0,0 +1,203 @@
+use crate::{Fragment, Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+impl<'a, T: Clone + 'a, G> Extend<&'a T> for SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Clones and appends all elements in the iterator to the vec.
+    ///
+    /// Iterates over the `iter`, clones each element, and then appends
+    /// it to this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(4);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(vec, [1, 2, 3]);
+    ///
+    /// vec.extend(&[4, 5, 6, 7]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// let mut sec_vec = SplitVec::with_linear_growth(4);
+    /// sec_vec.extend(vec.iter());
+    /// assert_eq!(sec_vec, [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_from_iter(iter.into_iter().cloned());
+    }
+}
+
+impl<T, G> Extend<T> for SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Extends a collection with the contents of an iterator.
+    ///
+    /// Iterates over the `iter`, moves and appends each element
+    /// to this vector.
+    ///
+    /// Elements are written in chunks directly into the room of the last fragment.
+    /// Whenever the last fragment is full, a new fragment is allocated ahead if the `size_hint` of the iterator
+    /// guarantees at least one more element; otherwise, the next element is pulled first, and the fragment is
+    /// allocated only if the iterator is not yet exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(4);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(vec, [1, 2, 3]);
+    ///
+    /// vec.extend(vec![4, 5, 6, 7].into_iter());
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_from_iter(iter.into_iter());
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    fn extend_from_iter<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        loop {
+            if !self.has_capacity_for_one() {
+                match iter.size_hint().0 {
+                    0 => match iter.next() {
+                        Some(x) => self.push(x),
+                        None => return,
+                    },
+                    _ => _ = self.add_fragment(),
+                }
+                continue;
+            }
+
+            let f = self.fragments.len() - 1;
+            let last = &mut self.fragments[f];
+            let room = last.room();
+            let guard = AddLenOnDrop {
+                len_before: last.len(),
+                fragment: last,
+                len: &mut self.len,
+            };
+            guard.fragment.extend(iter.by_ref().take(room));
+            let num_added = guard.fragment.len() - guard.len_before;
+            drop(guard);
+
+            if num_added < room {
+                return;
+            }
+        }
+    }
+}
+
+/// Adds the number of elements pushed to the `fragment` to the length of the vector when dropped;
+/// so that the lengths are in sync even if the iterator panics while extending the fragment.
+struct AddLenOnDrop<'a, T> {
+    fragment: &'a mut Fragment<T>,
+    len_before: usize,
+    len: &'a mut usize,
+}
+
+impl<T> Drop for AddLenOnDrop<'_, T> {
+    fn drop(&mut self) {
+        *self.len += self.fragment.len() - self.len_before;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn extend() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..42);
+            vec.extend(&(42..63).collect::<Vec<_>>());
+            vec.extend((53..90).map(|i| i + 10));
+
+            assert_eq!(100, vec.len());
+            for i in 0..100 {
+                assert_eq!(i, vec[i]);
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn extend_does_not_allocate_unused_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut expected = Vec::new();
+            for n in [0, 1, 3, 4, 12, 13, 100] {
+                vec.extend(expected.len()..(expected.len() + n));
+                expected.extend(expected.len()..(expected.len() + n));
+                assert_eq!(vec, expected);
+
+                vec.extend((expected.len()..(expected.len() + n)).filter(|x| x % 2 == 0));
+                expected.extend((expected.len()..(expected.len() + n)).filter(|x| x % 2 == 0));
+                assert_eq!(vec, expected);
+
+                let num_fragments = vec.fragments().len();
+                assert!(num_fragments == 1 || !vec.fragments()[num_fragments - 1].is_empty());
+                for f in 0..num_fragments.saturating_sub(1) {
+                    assert_eq!(vec.fragments()[f].room(), 0);
+                }
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn extend_with_wrong_size_hint() {
+        struct Liar(core::ops::Range<usize>);
+        impl Iterator for Liar {
+            type Item = usize;
+            fn next(&mut self) -> Option<usize> {
+                self.0.next()
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (1000, None)
+            }
+        }
+
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(Liar(0..4));
+            vec.extend(Liar(4..4));
+            vec.extend(Liar(4..50));
+            assert_eq!(vec, (0..50).collect::<Vec<_>>());
+            vec.extend(50..60);
+            vec.push(60);
+            assert_eq!(vec, (0..61).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn extend_with_panicking_iterator() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for n in [0, 3, 4, 5, 20, 100] {
+                vec.clear();
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    vec.extend((0..).inspect(|i| assert!(*i < n, "iterator panics")))
+                }));
+                assert!(result.is_err());
+                assert_eq!(vec.len(), n);
+                assert_eq!(vec, (0..n).collect::<Vec<_>>());
+
+                vec.push(n);
+                assert_eq!(vec.get(n), Some(&n));
+            }
+        }
+        test_all_growth_types!(test);
+    }
+}
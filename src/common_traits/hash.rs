@@ -0,0 +1,72 @@
+use crate::{Growth, SplitVec};
+use core::hash::{Hash, Hasher};
+
+impl<T: Hash, G: Growth> Hash for SplitVec<T, G> {
+    /// Hashes the logical sequence of the vector, fragment by fragment.
+    ///
+    /// The length is written first and the elements follow, consistent with the `Hash` implementation of `[T]`.
+    /// Therefore, vectors that are equal to each other have equal hashes regardless of their growth strategies
+    /// or fragment layouts.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for fragment in &self.fragments {
+            T::hash_slice(fragment, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::hash::{BuildHasher, Hash};
+    use std::collections::HashMap;
+    use std::hash::RandomState;
+
+    fn hash_of<H: Hash + ?Sized>(state: &RandomState, value: &H) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[test]
+    fn hash_consistent_with_slice() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let state = RandomState::new();
+            let mut expected: Vec<String> = Vec::new();
+            assert_eq!(hash_of(&state, &vec), hash_of(&state, &expected[..]));
+
+            for i in 0..142 {
+                vec.push(i.to_string());
+                expected.push(i.to_string());
+                assert_eq!(hash_of(&state, &vec), hash_of(&state, &expected[..]));
+            }
+
+            let mut other = SplitVec::with_linear_growth(3);
+            other.extend(expected.iter().cloned());
+            assert_eq!(hash_of(&state, &vec), hash_of(&state, &other));
+
+            other.pop();
+            assert_ne!(hash_of(&state, &vec), hash_of(&state, &other));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut map = HashMap::new();
+        let mut a = SplitVec::with_doubling_growth();
+        a.extend(0..10);
+        let mut b = SplitVec::with_doubling_growth();
+        b.extend(0..11);
+
+        map.insert(a.clone(), 'a');
+        map.insert(b.clone(), 'b');
+
+        assert_eq!(map.get(&a), Some(&'a'));
+        assert_eq!(map.get(&b), Some(&'b'));
+        b.pop();
+        assert_eq!(map.get(&b), Some(&'a'));
+    }
+}
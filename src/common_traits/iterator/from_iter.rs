@@ -1,22 +1,26 @@
 use crate::{Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
 
 impl<T, G: Growth> FromIterator<T> for SplitVec<T, G>
 where
     SplitVec<T, G>: Default,
 {
+    /// Creates a split vector from the iterator.
+    ///
+    /// The fragments required to hold the lower bound of the `size_hint` of the iterator are allocated up front,
+    /// and the elements are written into each fragment in bulk.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
         let mut vec = Self::default();
-        for i in iter {
-            vec.push(i)
-        }
+        vec.reserve(iter.size_hint().0);
+        vec.extend(iter);
         vec
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Doubling, Recursive, SplitVec};
+    use crate::*;
+    use alloc::vec::Vec;
 
     #[test]
     fn collect() {
@@ -44,4 +48,35 @@ mod tests {
         let vec: SplitVec<_, Recursive> = (0..6).filter(|x| x % 2 == 0).collect();
         assert_eq!(&vec, &[0, 2, 4]);
     }
+
+    #[test]
+    fn collect_pre_sized() {
+        fn test<G: Growth>(vec: SplitVec<usize, G>)
+        where
+            SplitVec<usize, G>: Default,
+        {
+            for n in [0, 1, 4, 5, 100, 1000, 4567] {
+                let mut expected = vec.clone();
+                for i in 0..n {
+                    expected.push(i);
+                }
+
+                let collected: SplitVec<usize, G> = (0..n).collect();
+                assert_eq!(collected, expected);
+                assert_eq!(collected.reserved_capacity(), 0);
+
+                let capacities = |v: &SplitVec<usize, G>| -> Vec<_> {
+                    v.fragments().iter().map(|x| x.capacity()).collect()
+                };
+                assert_eq!(capacities(&collected), capacities(&expected));
+
+                // lower bound of size hint is zero
+                let collected: SplitVec<usize, G> = (0..n).filter(|x| x % 2 == 0).collect();
+                assert_eq!(collected, (0..n).filter(|x| x % 2 == 0).collect::<Vec<_>>());
+            }
+        }
+
+        test(SplitVec::<usize, Doubling>::default());
+        test(SplitVec::<usize, Recursive>::default());
+    }
 }
@@ -1,3 +1,4 @@
+use super::reductions;
 use crate::{Fragment, Growth, SplitVec};
 use alloc::vec::Vec;
 use core::iter::FusedIterator;
@@ -17,6 +18,7 @@ impl<T, G: Growth> IntoIterator for SplitVec<T, G> {
 pub struct IntoIter<T> {
     outer: alloc::vec::IntoIter<Fragment<T>>,
     inner: alloc::vec::IntoIter<T>,
+    back: alloc::vec::IntoIter<T>,
 }
 
 impl<T> IntoIter<T> {
@@ -27,7 +29,9 @@ impl<T> IntoIter<T> {
             .map(|f| f.data.into_iter())
             .unwrap_or(Vec::new().into_iter());
 
-        Self { outer, inner }
+        let back = Vec::new().into_iter();
+
+        Self { outer, inner, back }
     }
 
     fn next_fragment(&mut self) -> Option<T> {
@@ -36,7 +40,22 @@ impl<T> IntoIter<T> {
                 self.inner = f.data.into_iter();
                 self.next()
             }
-            None => None,
+            None => self.back.next(),
+        }
+    }
+
+    fn remaining_len(&self) -> usize {
+        let outer: usize = self.outer.as_slice().iter().map(|x| x.len()).sum();
+        self.inner.len() + outer + self.back.len()
+    }
+
+    fn next_back_fragment(&mut self) -> Option<T> {
+        match self.outer.next_back() {
+            Some(f) => {
+                self.back = f.data.into_iter();
+                self.next_back()
+            }
+            None => self.inner.next_back(),
         }
     }
 }
@@ -46,6 +65,7 @@ impl<T: Clone> Clone for IntoIter<T> {
         Self {
             outer: self.outer.clone(),
             inner: self.inner.clone(),
+            back: self.back.clone(),
         }
     }
 }
@@ -62,6 +82,31 @@ impl<T> Iterator for IntoIter<T> {
             self.next_fragment()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+
+    reductions::impl_reductions!(outer, inner, back, |f: Fragment<T>| f.data.into_iter());
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.back.next_back();
+        if next_element.is_some() {
+            next_element
+        } else {
+            self.next_back_fragment()
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.remaining_len()
+    }
 }
 
 impl<T> FusedIterator for IntoIter<T> {}
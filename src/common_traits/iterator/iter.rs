@@ -10,13 +10,15 @@ use core::iter::FusedIterator;
 pub struct Iter<'a, T> {
     outer: core::slice::Iter<'a, Fragment<T>>,
     inner: core::slice::Iter<'a, T>,
+    back: core::slice::Iter<'a, T>,
 }
 
 impl<'a, T> Iter<'a, T> {
     pub(crate) fn new(fragments: &'a [Fragment<T>]) -> Self {
         let mut outer = fragments.iter();
         let inner = outer.next().map(|x| x.iter()).unwrap_or([].iter());
-        Self { outer, inner }
+        let back = [].iter();
+        Self { outer, inner, back }
     }
 
     fn next_fragment(&mut self) -> Option<&'a T> {
@@ -25,7 +27,22 @@ impl<'a, T> Iter<'a, T> {
                 self.inner = f.iter();
                 self.next()
             }
-            None => None,
+            None => self.back.next(),
+        }
+    }
+
+    fn remaining_len(&self) -> usize {
+        let outer: usize = self.outer.as_slice().iter().map(|x| x.len()).sum();
+        self.inner.len() + outer + self.back.len()
+    }
+
+    fn next_back_fragment(&mut self) -> Option<&'a T> {
+        match self.outer.next_back() {
+            Some(f) => {
+                self.back = f.iter();
+                self.next_back()
+            }
+            None => self.inner.next_back(),
         }
     }
 }
@@ -35,6 +52,7 @@ impl<T> Clone for Iter<'_, T> {
         Self {
             outer: self.outer.clone(),
             inner: self.inner.clone(),
+            back: self.back.clone(),
         }
     }
 }
@@ -52,29 +70,29 @@ impl<'a, T> Iterator for Iter<'a, T> {
         }
     }
 
-    // reductions
-    fn all<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        reductions::all(&mut self.outer, &mut self.inner, f)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
     }
 
-    fn any<F>(&mut self, f: F) -> bool
-    where
-        Self: Sized,
-        F: FnMut(Self::Item) -> bool,
-    {
-        reductions::any(&mut self.outer, &mut self.inner, f)
+    reductions::impl_reductions!(outer, inner, back, |f: &'a Fragment<T>| f.iter());
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.back.next_back();
+        if next_element.is_some() {
+            next_element
+        } else {
+            self.next_back_fragment()
+        }
     }
+}
 
-    fn fold<B, F>(mut self, init: B, f: F) -> B
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> B,
-    {
-        reductions::fold(&mut self.outer, &mut self.inner, init, f)
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.remaining_len()
     }
 }
 
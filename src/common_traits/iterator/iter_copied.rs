@@ -0,0 +1,53 @@
+use crate::{Growth, SplitVec};
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Returns an iterator yielding copies of the elements of the vector.
+    ///
+    /// Unlike `vec.iter().copied()`, the iterator is composed of one contiguous slice iterator per fragment.
+    /// Therefore, consuming methods such as `sum`, `fold` or `for_each` run a tight loop over each fragment
+    /// which is more suitable for auto-vectorization, especially for primitive types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(vec.iter_copied().sum::<i32>(), 15);
+    /// assert_eq!(vec.iter_copied().rev().collect::<Vec<_>>(), [5, 4, 3, 2, 1, 0]);
+    /// ```
+    pub fn iter_copied(&self) -> impl DoubleEndedIterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        self.fragments.iter().flat_map(|x| x.iter().copied())
+    }
+
+    /// Returns an iterator yielding clones of the elements of the vector.
+    ///
+    /// Unlike `vec.iter().cloned()`, the iterator is composed of one contiguous slice iterator per fragment.
+    /// Therefore, consuming methods such as `fold` or `for_each` run a tight loop over each fragment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(["a", "b", "c", "d", "e"].map(String::from));
+    ///
+    /// let cloned: Vec<String> = vec.iter_cloned().collect();
+    /// assert_eq!(cloned, ["a", "b", "c", "d", "e"]);
+    /// ```
+    pub fn iter_cloned(&self) -> impl DoubleEndedIterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        self.fragments.iter().flat_map(|x| x.iter().cloned())
+    }
+}
@@ -1,3 +1,4 @@
+use super::reductions;
 use crate::fragment::fragment_struct::Fragment;
 use core::iter::FusedIterator;
 
@@ -9,6 +10,7 @@ use core::iter::FusedIterator;
 pub struct IterMut<'a, T> {
     iter_outer: core::slice::IterMut<'a, Fragment<T>>,
     iter_inner: core::slice::IterMut<'a, T>,
+    iter_back: core::slice::IterMut<'a, T>,
 }
 
 impl<'a, T> IterMut<'a, T> {
@@ -21,6 +23,7 @@ impl<'a, T> IterMut<'a, T> {
         Self {
             iter_outer,
             iter_inner,
+            iter_back: [].iter_mut(),
         }
     }
 
@@ -30,11 +33,32 @@ impl<'a, T> IterMut<'a, T> {
                 self.iter_inner = f.iter_mut();
                 self.next()
             }
-            None => None,
+            None => self.iter_back.next(),
+        }
+    }
+
+    fn remaining_len(&self) -> usize {
+        let outer: usize = self.iter_outer.as_slice().iter().map(|x| x.len()).sum();
+        self.iter_inner.len() + outer + self.iter_back.len()
+    }
+
+    fn next_back_fragment(&mut self) -> Option<&'a mut T> {
+        match self.iter_outer.next_back() {
+            Some(f) => {
+                self.iter_back = f.iter_mut();
+                self.next_back()
+            }
+            None => self.iter_inner.next_back(),
         }
     }
 }
 
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.remaining_len()
+    }
+}
+
 impl<T> FusedIterator for IterMut<'_, T> {}
 
 impl<'a, T> Iterator for IterMut<'a, T> {
@@ -49,4 +73,25 @@ impl<'a, T> Iterator for IterMut<'a, T> {
             self.next_fragment()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+
+    reductions::impl_reductions!(iter_outer, iter_inner, iter_back, |f: &'a mut Fragment<
+        T,
+    >| f.iter_mut());
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.iter_back.next_back();
+        if next_element.is_some() {
+            next_element
+        } else {
+            self.next_back_fragment()
+        }
+    }
 }
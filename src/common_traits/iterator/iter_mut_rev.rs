@@ -1,3 +1,4 @@
+use super::reductions;
 use crate::fragment::fragment_struct::Fragment;
 use core::iter::{FusedIterator, Rev};
 
@@ -7,13 +8,13 @@ use core::iter::{FusedIterator, Rev};
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IterMutRev<'a, T> {
-    iter_outer: Rev<core::slice::IterMut<'a, Fragment<T>>>,
+    iter_outer: FragmentsRev<'a, T>,
     iter_inner: Rev<core::slice::IterMut<'a, T>>,
 }
 
 impl<'a, T> IterMutRev<'a, T> {
     pub(crate) fn new(fragments: &'a mut [Fragment<T>]) -> Self {
-        let mut iter_outer = fragments.iter_mut().rev();
+        let mut iter_outer = FragmentsRev(fragments.iter_mut());
         let iter_inner = iter_outer
             .next()
             .map(|x| x.iter_mut())
@@ -34,6 +35,11 @@ impl<'a, T> IterMutRev<'a, T> {
             None => None,
         }
     }
+
+    fn remaining_len(&self) -> usize {
+        let outer: usize = self.iter_outer.0.as_slice().iter().map(|x| x.len()).sum();
+        self.iter_inner.len() + outer
+    }
 }
 
 impl<'a, T> Iterator for IterMutRev<'a, T> {
@@ -48,6 +54,36 @@ impl<'a, T> Iterator for IterMutRev<'a, T> {
             self.next_fragment()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+
+    reductions::impl_reductions!(iter_outer, iter_inner, |f: &'a mut Fragment<T>| f
+        .iter_mut()
+        .rev());
+}
+
+impl<T> ExactSizeIterator for IterMutRev<'_, T> {
+    fn len(&self) -> usize {
+        self.remaining_len()
+    }
 }
 
 impl<T> FusedIterator for IterMutRev<'_, T> {}
+
+/// Mutable iterator over the fragments in reverse order.
+///
+/// Unlike `Rev`, it allows to access the remaining fragments which is required to compute the exact length.
+#[derive(Debug)]
+struct FragmentsRev<'a, T>(core::slice::IterMut<'a, Fragment<T>>);
+
+impl<'a, T> Iterator for FragmentsRev<'a, T> {
+    type Item = &'a mut Fragment<T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
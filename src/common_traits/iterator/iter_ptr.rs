@@ -60,6 +60,7 @@ impl<T> Iterator for IterPtr<T> {
 impl<T> FusedIterator for IterPtr<T> {}
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop, clippy::let_and_return)]
 mod tests {
     use super::*;
     use alloc::{
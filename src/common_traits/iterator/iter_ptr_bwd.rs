@@ -60,6 +60,7 @@ impl<T> Iterator for IterPtrBackward<T> {
 impl<T> FusedIterator for IterPtrBackward<T> {}
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop, clippy::let_and_return)]
 mod tests {
     use super::*;
     use alloc::{
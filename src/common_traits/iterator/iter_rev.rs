@@ -1,3 +1,4 @@
+use super::reductions;
 use crate::fragment::fragment_struct::Fragment;
 use core::iter::{FusedIterator, Rev};
 
@@ -33,6 +34,11 @@ impl<'a, T> IterRev<'a, T> {
             None => None,
         }
     }
+
+    fn remaining_len(&self) -> usize {
+        let outer: usize = self.iter_outer.clone().map(|x| x.len()).sum();
+        self.iter_inner.len() + outer
+    }
 }
 
 impl<T> Clone for IterRev<'_, T> {
@@ -56,6 +62,19 @@ impl<'a, T> Iterator for IterRev<'a, T> {
             self.next_fragment()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+
+    reductions::impl_reductions!(iter_outer, iter_inner, |f: &'a Fragment<T>| f.iter().rev());
+}
+
+impl<T> ExactSizeIterator for IterRev<'_, T> {
+    fn len(&self) -> usize {
+        self.remaining_len()
+    }
 }
 
 impl<T> FusedIterator for IterRev<'_, T> {}
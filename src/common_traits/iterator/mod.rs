@@ -2,12 +2,14 @@ mod eq;
 mod from_iter;
 pub(crate) mod into_iter;
 pub(crate) mod iter;
+mod iter_copied;
 pub(crate) mod iter_mut;
 pub(crate) mod iter_mut_rev;
 pub(crate) mod iter_ptr;
 pub(crate) mod iter_ptr_bwd;
 pub(crate) mod iter_rev;
 mod reductions;
+pub(crate) mod slice_mut_iter;
 
 #[cfg(test)]
 mod tests;
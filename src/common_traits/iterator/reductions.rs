@@ -1,48 +1,244 @@
-use crate::Fragment;
-use core::slice::Iter;
+//! Fragment-by-fragment implementations of the iterator reductions.
+//!
+//! `Iterator::try_fold` cannot be overridden on stable since the `Try` trait is unstable.
+//! Instead, the short-circuiting methods which are by default implemented by `try_fold`,
+//! such as `all`, `any`, `find` or `position`, are overridden. Each of them runs the inner loop
+//! on the contiguous fragment and moves on to the next fragment only when the current one is exhausted.
+//!
+//! The iterator remains valid after an early exit; i.e., calling `next` continues with the element
+//! right after the one that caused the early exit.
 
-type Outer<'a, T> = Iter<'a, Fragment<T>>;
-type Inner<'a, T> = Iter<'a, T>;
+pub fn all<O, I, N, F>(
+    outer: &mut O,
+    inner: &mut I,
+    mut back: Option<&mut I>,
+    next_inner: N,
+    mut f: F,
+) -> bool
+where
+    O: Iterator,
+    I: Iterator,
+    N: Fn(O::Item) -> I,
+    F: FnMut(I::Item) -> bool,
+{
+    loop {
+        if !inner.all(&mut f) {
+            return false;
+        }
+        match outer.next() {
+            Some(fragment) => *inner = next_inner(fragment),
+            None => match back.take() {
+                Some(back) => core::mem::swap(inner, back),
+                None => return true,
+            },
+        }
+    }
+}
+
+pub fn any<O, I, N, F>(
+    outer: &mut O,
+    inner: &mut I,
+    mut back: Option<&mut I>,
+    next_inner: N,
+    mut f: F,
+) -> bool
+where
+    O: Iterator,
+    I: Iterator,
+    N: Fn(O::Item) -> I,
+    F: FnMut(I::Item) -> bool,
+{
+    loop {
+        if inner.any(&mut f) {
+            return true;
+        }
+        match outer.next() {
+            Some(fragment) => *inner = next_inner(fragment),
+            None => match back.take() {
+                Some(back) => core::mem::swap(inner, back),
+                None => return false,
+            },
+        }
+    }
+}
 
-pub fn all<'a, T, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, mut f: F) -> bool
+pub fn find<O, I, N, P>(
+    outer: &mut O,
+    inner: &mut I,
+    mut back: Option<&mut I>,
+    next_inner: N,
+    mut predicate: P,
+) -> Option<I::Item>
 where
-    F: FnMut(&'a T) -> bool,
+    O: Iterator,
+    I: Iterator,
+    N: Fn(O::Item) -> I,
+    P: FnMut(&I::Item) -> bool,
 {
-    if !inner.all(&mut f) {
-        false
-    } else {
-        for fragment in outer {
-            if !fragment.iter().all(&mut f) {
-                return false;
-            }
-        }
-        true
+    loop {
+        if let Some(x) = inner.find(&mut predicate) {
+            return Some(x);
+        }
+        match outer.next() {
+            Some(fragment) => *inner = next_inner(fragment),
+            None => match back.take() {
+                Some(back) => core::mem::swap(inner, back),
+                None => return None,
+            },
+        }
     }
 }
 
-pub fn any<'a, T, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, mut f: F) -> bool
+pub fn find_map<O, I, N, B, F>(
+    outer: &mut O,
+    inner: &mut I,
+    mut back: Option<&mut I>,
+    next_inner: N,
+    mut f: F,
+) -> Option<B>
 where
-    F: FnMut(&'a T) -> bool,
+    O: Iterator,
+    I: Iterator,
+    N: Fn(O::Item) -> I,
+    F: FnMut(I::Item) -> Option<B>,
 {
-    if inner.any(&mut f) {
-        true
-    } else {
-        for fragment in outer {
-            if fragment.iter().any(&mut f) {
-                return true;
-            }
-        }
-        false
+    loop {
+        if let Some(x) = inner.find_map(&mut f) {
+            return Some(x);
+        }
+        match outer.next() {
+            Some(fragment) => *inner = next_inner(fragment),
+            None => match back.take() {
+                Some(back) => core::mem::swap(inner, back),
+                None => return None,
+            },
+        }
     }
 }
 
-pub fn fold<'a, T, B, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, init: B, mut f: F) -> B
+pub fn position<O, I, N, P>(
+    outer: &mut O,
+    inner: &mut I,
+    mut back: Option<&mut I>,
+    next_inner: N,
+    mut predicate: P,
+) -> Option<usize>
 where
-    F: FnMut(B, &'a T) -> B,
+    O: Iterator,
+    I: ExactSizeIterator,
+    N: Fn(O::Item) -> I,
+    P: FnMut(I::Item) -> bool,
+{
+    let mut offset = 0;
+    loop {
+        let len = inner.len();
+        if let Some(x) = inner.position(&mut predicate) {
+            return Some(offset + x);
+        }
+        offset += len;
+        match outer.next() {
+            Some(fragment) => *inner = next_inner(fragment),
+            None => match back.take() {
+                Some(back) => core::mem::swap(inner, back),
+                None => return None,
+            },
+        }
+    }
+}
+
+pub fn fold<O, I, N, B, F>(
+    outer: O,
+    inner: I,
+    back: Option<I>,
+    next_inner: N,
+    init: B,
+    mut f: F,
+) -> B
+where
+    O: Iterator,
+    I: Iterator,
+    N: Fn(O::Item) -> I,
+    F: FnMut(B, I::Item) -> B,
 {
     let mut res = inner.fold(init, &mut f);
     for fragment in outer {
-        res = fragment.iter().fold(res, &mut f);
+        res = next_inner(fragment).fold(res, &mut f);
+    }
+    match back {
+        Some(back) => back.fold(res, f),
+        None => res,
     }
-    res
 }
+
+/// Overrides the reductions of an iterator over fragments with the functions of this module.
+///
+/// * `$outer` is the field of the iterator over the fragments,
+/// * `$inner` is the field of the iterator over elements of the current fragment,
+/// * `$back` is the optional field of the iterator over elements of the fragment currently consumed from the back,
+///   which is reduced after all fragments of `$outer` are exhausted,
+/// * `$next_inner` creates the iterator over elements of a fragment yielded by `$outer`.
+macro_rules! impl_reductions {
+    ($outer:ident, $inner:ident, $next_inner:expr) => {
+        super::reductions::impl_reductions!(@impl $outer, $inner, [], $next_inner);
+    };
+    ($outer:ident, $inner:ident, $back:ident, $next_inner:expr) => {
+        super::reductions::impl_reductions!(@impl $outer, $inner, [$back], $next_inner);
+    };
+    (@impl $outer:ident, $inner:ident, [$($back:ident)?], $next_inner:expr) => {
+        fn all<F>(&mut self, f: F) -> bool
+        where
+            Self: Sized,
+            F: FnMut(Self::Item) -> bool,
+        {
+            let back = None $(.or(Some(&mut self.$back)))?;
+            super::reductions::all(&mut self.$outer, &mut self.$inner, back, $next_inner, f)
+        }
+
+        fn any<F>(&mut self, f: F) -> bool
+        where
+            Self: Sized,
+            F: FnMut(Self::Item) -> bool,
+        {
+            let back = None $(.or(Some(&mut self.$back)))?;
+            super::reductions::any(&mut self.$outer, &mut self.$inner, back, $next_inner, f)
+        }
+
+        fn find<P>(&mut self, predicate: P) -> Option<Self::Item>
+        where
+            Self: Sized,
+            P: FnMut(&Self::Item) -> bool,
+        {
+            let back = None $(.or(Some(&mut self.$back)))?;
+            super::reductions::find(&mut self.$outer, &mut self.$inner, back, $next_inner, predicate)
+        }
+
+        fn find_map<B, F>(&mut self, f: F) -> Option<B>
+        where
+            Self: Sized,
+            F: FnMut(Self::Item) -> Option<B>,
+        {
+            let back = None $(.or(Some(&mut self.$back)))?;
+            super::reductions::find_map(&mut self.$outer, &mut self.$inner, back, $next_inner, f)
+        }
+
+        fn position<P>(&mut self, predicate: P) -> Option<usize>
+        where
+            Self: Sized,
+            P: FnMut(Self::Item) -> bool,
+        {
+            let back = None $(.or(Some(&mut self.$back)))?;
+            super::reductions::position(&mut self.$outer, &mut self.$inner, back, $next_inner, predicate)
+        }
+
+        fn fold<B, F>(self, init: B, f: F) -> B
+        where
+            Self: Sized,
+            F: FnMut(B, Self::Item) -> B,
+        {
+            let back = None $(.or(Some(self.$back)))?;
+            super::reductions::fold(self.$outer, self.$inner, back, $next_inner, init, f)
+        }
+    };
+}
+
+pub(crate) use impl_reductions;
@@ -0,0 +1,115 @@
+use crate::fragment::fragment_struct::Fragment;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// Iterator over the mutable slices which together form a range of the `SplitVec`.
+///
+/// This struct is created by `SplitVec::slices_mut()` method.
+///
+/// The slices are created lazily from the fragments; hence, obtaining the slices of a range does not allocate.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SliceMutIter<'a, T> {
+    slices: Slices<'a, T>,
+}
+
+#[derive(Debug)]
+enum Slices<'a, T> {
+    Fragments {
+        first: Option<&'a mut [T]>,
+        middle: core::slice::IterMut<'a, Fragment<T>>,
+        last: Option<&'a mut [T]>,
+    },
+    Collected(alloc::vec::IntoIter<&'a mut [T]>),
+}
+
+impl<T> Default for SliceMutIter<'_, T> {
+    fn default() -> Self {
+        Self::new(None, Default::default(), None)
+    }
+}
+
+impl<'a, T> SliceMutIter<'a, T> {
+    /// Creates the iterator yielding `first`, all elements of the `middle` fragments, and `last`, in this order.
+    pub(crate) fn new(
+        first: Option<&'a mut [T]>,
+        middle: &'a mut [Fragment<T>],
+        last: Option<&'a mut [T]>,
+    ) -> Self {
+        let middle = middle.iter_mut();
+        Self {
+            slices: Slices::Fragments {
+                first,
+                middle,
+                last,
+            },
+        }
+    }
+
+    /// Returns whether or not there exists any remaining slice.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, T> From<Vec<&'a mut [T]>> for SliceMutIter<'a, T> {
+    fn from(slices: Vec<&'a mut [T]>) -> Self {
+        Self {
+            slices: Slices::Collected(slices.into_iter()),
+        }
+    }
+}
+
+impl<'a, T> Iterator for SliceMutIter<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.slices {
+            Slices::Fragments {
+                first,
+                middle,
+                last,
+            } => first
+                .take()
+                .or_else(|| middle.next().map(|x| x.as_mut_slice()))
+                .or_else(|| last.take()),
+            Slices::Collected(x) => x.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceMutIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.slices {
+            Slices::Fragments {
+                first,
+                middle,
+                last,
+            } => last
+                .take()
+                .or_else(|| middle.next_back().map(|x| x.as_mut_slice()))
+                .or_else(|| first.take()),
+            Slices::Collected(x) => x.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for SliceMutIter<'_, T> {
+    fn len(&self) -> usize {
+        match &self.slices {
+            Slices::Fragments {
+                first,
+                middle,
+                last,
+            } => first.is_some() as usize + middle.len() + last.is_some() as usize,
+            Slices::Collected(x) => x.len(),
+        }
+    }
+}
+
+impl<T> FusedIterator for SliceMutIter<'_, T> {}
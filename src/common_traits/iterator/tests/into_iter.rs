@@ -71,3 +71,43 @@ fn clone() {
     }
     test_all_growth_types!(test);
 }
+
+#[test]
+fn into_iter_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<alloc::string::String, G>) {
+        use alloc::string::ToString;
+
+        let n = 564;
+        vec.extend((0..n).map(|x| x.to_string()));
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next_back(), Some((n - 1).to_string()));
+        assert_eq!(iter.next(), Some(0.to_string()));
+
+        let rest: Vec<_> = iter.rev().collect();
+        let expected: Vec<_> = (1..(n - 1)).rev().map(|x| x.to_string()).collect();
+        assert_eq!(rest, expected);
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn into_iter_exact_size() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        vec.extend(0..n);
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        for len in (0..n).rev() {
+            match len % 3 {
+                0 => _ = iter.next_back(),
+                1 => _ = iter.next(),
+                _ => _ = iter.find(|_| true),
+            }
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(iter.next(), None);
+    }
+    test_all_growth_types!(test);
+}
@@ -120,3 +120,75 @@ fn fold() {
     }
     test_all_growth_types!(test);
 }
+
+#[test]
+fn iter_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        vec.extend(0..n);
+
+        assert_eq!(
+            vec.iter().rev().copied().collect::<Vec<_>>(),
+            (0..n).rev().collect::<Vec<_>>()
+        );
+
+        for num_front in [0, 1, 3, 4, 100, 300, n] {
+            let mut iter = vec.iter();
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for _ in 0..num_front {
+                front.extend(iter.next());
+            }
+            while let Some(x) = iter.next_back() {
+                back.push(*x);
+                if let Some(x) = iter.next() {
+                    front.push(*x);
+                }
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            back.reverse();
+            let all: Vec<_> = front.into_iter().chain(back).collect();
+            assert_eq!(all, (0..n).collect::<Vec<_>>());
+        }
+
+        assert_eq!(vec.iter().rfind(|x| **x % 100 == 7), Some(&507));
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.next_back(), Some(&(n - 1)));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.position(|x| *x == n - 2), Some(n - 3));
+        assert_eq!(iter.next(), None);
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn iter_exact_size() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        assert_eq!(vec.iter().len(), 0);
+        vec.extend(0..n);
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), n);
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+
+        let mut len = n;
+        while len > 0 {
+            match len % 3 {
+                0 => _ = iter.next_back(),
+                1 => _ = iter.next(),
+                _ => _ = iter.find(|_| true),
+            }
+            len -= 1;
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        assert_eq!(vec.iter().rposition(|x| *x == 3), Some(3));
+    }
+    test_all_growth_types!(test);
+}
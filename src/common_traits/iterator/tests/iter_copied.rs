@@ -0,0 +1,49 @@
+use crate::{test_all_growth_types, Growth, SplitVec};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+#[test]
+fn iter_copied() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        assert_eq!(vec.iter_copied().count(), 0);
+
+        let n = 564;
+        vec.extend(0..n);
+
+        assert_eq!(
+            vec.iter_copied().collect::<Vec<_>>(),
+            (0..n).collect::<Vec<_>>()
+        );
+        assert_eq!(vec.iter_copied().sum::<usize>(), (0..n).sum());
+        assert_eq!(
+            vec.iter_copied().rev().collect::<Vec<_>>(),
+            (0..n).rev().collect::<Vec<_>>()
+        );
+
+        vec.truncate(33);
+        assert_eq!(
+            vec.iter_copied().collect::<Vec<_>>(),
+            (0..33).collect::<Vec<_>>()
+        );
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn iter_cloned() {
+    fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+        assert_eq!(vec.iter_cloned().count(), 0);
+
+        let n = 564;
+        vec.extend((0..n).map(|x| x.to_string()));
+
+        let expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+        assert_eq!(vec.iter_cloned().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            vec.iter_cloned().rev().collect::<Vec<_>>(),
+            expected.into_iter().rev().collect::<Vec<_>>()
+        );
+    }
+    test_all_growth_types!(test);
+}
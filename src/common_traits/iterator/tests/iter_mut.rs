@@ -71,3 +71,61 @@ fn iter_one_fragment() {
     }
     test_all_growth_types!(test);
 }
+
+#[test]
+fn iter_mut_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        vec.extend(0..n);
+
+        for (i, x) in vec.iter_mut().rev().enumerate() {
+            *x += 10 * i;
+        }
+        assert_eq!(
+            vec,
+            (0..n).map(|i| i + 10 * (n - 1 - i)).collect::<Vec<_>>()
+        );
+
+        vec.clear();
+        vec.extend(0..n);
+        let mut iter = vec.iter_mut();
+        loop {
+            match (iter.next(), iter.next_back()) {
+                (Some(a), Some(b)) => {
+                    *a *= 2;
+                    *b *= 3;
+                }
+                (Some(a), None) => *a *= 2,
+                _ => break,
+            }
+        }
+        let half = n.div_ceil(2);
+        let expected: Vec<_> = (0..n)
+            .map(|i| if i < half { i * 2 } else { i * 3 })
+            .collect();
+        assert_eq!(vec, expected);
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn iter_mut_exact_size() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        assert_eq!(vec.iter_mut().len(), 0);
+        vec.extend(0..n);
+
+        let mut iter = vec.iter_mut();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        for len in (0..n).rev() {
+            match len % 3 {
+                0 => _ = iter.next_back(),
+                1 => _ = iter.next(),
+                _ => _ = iter.find(|_| true),
+            }
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(iter.next(), None);
+    }
+    test_all_growth_types!(test);
+}
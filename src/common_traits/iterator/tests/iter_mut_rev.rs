@@ -64,3 +64,24 @@ fn iter_one_fragment() {
     }
     test_all_growth_types!(test);
 }
+
+#[test]
+fn iter_mut_rev_exact_size() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        assert_eq!(vec.iter_mut_rev().len(), 0);
+        vec.extend(0..n);
+
+        let mut iter = vec.iter_mut_rev();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        for len in (0..n).rev() {
+            match len % 2 {
+                0 => _ = iter.next(),
+                _ => _ = iter.find(|_| true),
+            }
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(iter.next(), None);
+    }
+    test_all_growth_types!(test);
+}
@@ -74,3 +74,24 @@ fn clone() {
     }
     test_all_growth_types!(test);
 }
+
+#[test]
+fn iter_rev_exact_size() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        assert_eq!(vec.iter_rev().len(), 0);
+        vec.extend(0..n);
+
+        let mut iter = vec.iter_rev();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        for len in (0..n).rev() {
+            match len % 2 {
+                0 => _ = iter.next(),
+                _ => _ = iter.find(|_| true),
+            }
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(iter.next(), None);
+    }
+    test_all_growth_types!(test);
+}
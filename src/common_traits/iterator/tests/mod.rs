@@ -1,5 +1,8 @@
 mod into_iter;
 mod iter;
+mod iter_copied;
 mod iter_mut;
 mod iter_mut_rev;
 mod iter_rev;
+mod reductions;
+mod slice_mut_iter;
@@ -0,0 +1,125 @@
+use crate::{test_all_growth_types, Growth, SplitVec};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+const N: usize = 564;
+
+fn filled<G: Growth>(mut vec: SplitVec<usize, G>) -> SplitVec<usize, G> {
+    vec.clear();
+    vec.extend(0..N);
+    vec
+}
+
+fn order_sensitive(a: usize, b: usize) -> usize {
+    a.wrapping_mul(31).wrapping_add(b)
+}
+
+#[test]
+fn short_circuit_and_resume() {
+    fn test<G: Growth>(vec: SplitVec<usize, G>) {
+        let mut vec = filled(vec);
+        let std_vec: Vec<_> = (0..N).collect();
+
+        for target in [0, 1, 3, 4, 5, 11, 12, 100, 563] {
+            let mut iter = vec.iter();
+            let mut expected = std_vec.iter();
+            assert_eq!(
+                iter.position(|x| *x == target),
+                expected.position(|x| *x == target)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.find(|x| **x > target + 7),
+                expected.find(|x| **x > target + 7)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.any(|x| *x == target + 20),
+                expected.any(|x| *x == target + 20)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.all(|x| *x < target + 30),
+                expected.all(|x| *x < target + 30)
+            );
+            assert_eq!(iter.next(), expected.next());
+            let f = |x: &usize| (*x > 40).then_some(*x * 2);
+            assert_eq!(iter.find_map(f), expected.find_map(f));
+            assert_eq!(
+                iter.fold(0, |a, b| order_sensitive(a, *b)),
+                expected.fold(0, |a, b| order_sensitive(a, *b))
+            );
+
+            let mut iter = vec.iter_rev();
+            let mut expected = std_vec.iter().rev();
+            assert_eq!(
+                iter.position(|x| *x == target),
+                expected.position(|x| *x == target)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.find(|x| **x < target / 2),
+                expected.find(|x| **x < target / 2)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.fold(0, |a, b| order_sensitive(a, *b)),
+                expected.fold(0, |a, b| order_sensitive(a, *b))
+            );
+
+            let mut iter = vec.iter_mut();
+            assert_eq!(iter.position(|x| *x == target), Some(target));
+            iter.for_each(|x| *x += 1);
+            let expected_after: Vec<_> = (0..N)
+                .map(|i| if i <= target { i } else { i + 1 })
+                .collect();
+            assert_eq!(vec, expected_after);
+            vec.iter_mut().skip(target + 1).for_each(|x| *x -= 1);
+
+            let mut iter = vec.iter_mut_rev();
+            assert_eq!(iter.position(|x| *x == target), Some(N - 1 - target));
+            assert_eq!(iter.next().map(|x| *x), target.checked_sub(1));
+            assert_eq!(
+                iter.fold(0, |a, b| order_sensitive(a, *b)),
+                (0..target.saturating_sub(1)).rev().fold(0, order_sensitive)
+            );
+
+            let mut iter = vec.clone().into_iter();
+            let mut expected = std_vec.clone().into_iter();
+            assert_eq!(
+                iter.position(|x| x == target),
+                expected.position(|x| x == target)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.find(|x| *x > target + 7),
+                expected.find(|x| *x > target + 7)
+            );
+            assert_eq!(
+                iter.all(|x| x < target + 30),
+                expected.all(|x| x < target + 30)
+            );
+            assert_eq!(iter.next(), expected.next());
+            assert_eq!(
+                iter.fold(0, order_sensitive),
+                expected.fold(0, order_sensitive)
+            );
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn short_circuit_on_empty() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        vec.clear();
+        assert_eq!(vec.iter().position(|_| true), None);
+        assert_eq!(vec.iter().find(|_| true), None);
+        assert!(!vec.iter().any(|_| true));
+        assert!(vec.iter().all(|_| false));
+        assert_eq!(vec.iter_rev().find_map(|x| (*x > 0).then_some(*x)), None);
+        assert_eq!(vec.iter_mut().position(|_| true), None);
+        assert_eq!(vec.clone().into_iter().fold(0, order_sensitive), 0);
+    }
+    test_all_growth_types!(test);
+}
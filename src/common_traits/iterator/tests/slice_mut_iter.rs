@@ -0,0 +1,49 @@
+use crate::{test_all_growth_types, Growth, SplitVec};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+#[test]
+fn slice_mut_iter_len_and_order() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 564;
+        vec.extend(0..n);
+
+        for (a, b) in [(0, 0), (0, 1), (3, 4), (3, 60), (10, n), (0, n), (n - 1, n)] {
+            let expected: Vec<_> = vec.slices(a..b).iter().map(|x| x.to_vec()).collect();
+
+            let mut iter = vec.slices_mut(a..b);
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.is_empty(), expected.is_empty());
+
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for i in 0..expected.len() {
+                assert_eq!(iter.len(), expected.len() - i);
+                match i % 2 == 0 {
+                    true => front.push(iter.next().expect("is-some").to_vec()),
+                    false => back.push(iter.next_back().expect("is-some").to_vec()),
+                }
+            }
+            assert!(iter.is_empty());
+            assert!(iter.next().is_none());
+            assert!(iter.next_back().is_none());
+
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front, expected);
+        }
+    }
+
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn slice_mut_iter_within_length() {
+    let mut vec: SplitVec<usize, crate::Recursive> = SplitVec::with_recursive_growth();
+    vec.append((0..3).collect::<Vec<_>>());
+    vec.fragments[0].reserve(10);
+    vec.append((3..7).collect::<Vec<_>>());
+
+    let slices: Vec<_> = vec.slices_mut(1..6).map(|x| x.to_vec()).collect();
+    assert_eq!(slices, [alloc::vec![1, 2], alloc::vec![3, 4, 5]]);
+}
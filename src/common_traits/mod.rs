@@ -1,5 +1,8 @@
 mod clone;
 mod debug;
-mod eq;
+pub(crate) mod eq;
+mod extend;
+mod hash;
 mod index;
 pub(crate) mod iterator;
+mod ord;
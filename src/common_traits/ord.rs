@@ -0,0 +1,97 @@
+use crate::common_traits::eq::compare_slices;
+use crate::{Growth, SplitVec};
+use core::cmp::Ordering;
+
+impl<T: PartialOrd, G, G2> PartialOrd<SplitVec<T, G2>> for SplitVec<T, G>
+where
+    G: Growth,
+    G2: Growth,
+{
+    /// Lexicographically compares the vectors; the fragments are compared piecewise by slice comparisons.
+    fn partial_cmp(&self, other: &SplitVec<T, G2>) -> Option<Ordering> {
+        compare_slices(
+            self.fragments.iter().map(|x| x.as_slice()),
+            other.fragments.iter().map(|x| x.as_slice()),
+            |a, b| a.partial_cmp(b),
+        )
+    }
+}
+
+impl<T, G, U> PartialOrd<U> for SplitVec<T, G>
+where
+    U: AsRef<[T]>,
+    T: PartialOrd,
+    G: Growth,
+{
+    /// Lexicographically compares the vector with the slice; the fragments are compared piecewise by slice comparisons.
+    fn partial_cmp(&self, other: &U) -> Option<Ordering> {
+        compare_slices(
+            self.fragments.iter().map(|x| x.as_slice()),
+            [other.as_ref()],
+            |a, b| a.partial_cmp(b),
+        )
+    }
+}
+
+impl<T: Ord, G: Growth> Ord for SplitVec<T, G> {
+    /// Lexicographically compares the vectors; the fragments are compared piecewise by slice comparisons.
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_slices(
+            self.fragments.iter().map(|x| x.as_slice()),
+            other.fragments.iter().map(|x| x.as_slice()),
+            |a, b| Some(a.cmp(b)),
+        )
+        .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn ord() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut other = SplitVec::with_linear_growth(3);
+            let mut expected: Vec<usize> = Vec::new();
+            assert_eq!(vec.partial_cmp(&other), Some(Ordering::Equal));
+            assert_eq!(vec.partial_cmp(&expected), Some(Ordering::Equal));
+
+            vec.extend(0..142);
+            other.extend(0..142);
+            expected.extend(0..142);
+            assert_eq!(vec.partial_cmp(&other), Some(Ordering::Equal));
+            assert_eq!(vec.partial_cmp(&expected), Some(Ordering::Equal));
+            assert_eq!(vec.cmp(&vec.clone()), Ordering::Equal);
+
+            other.push(0);
+            assert!(vec < other);
+            assert!(vec < [&expected[..], &[0]].concat());
+
+            other.pop();
+            *other.get_mut(100).expect("is-some") += 1;
+            assert!(vec < other);
+            assert!(other > vec);
+
+            let mut lesser = vec.clone();
+            *lesser.get_mut(141).expect("is-some") -= 1;
+            assert_eq!(lesser.cmp(&vec), Ordering::Less);
+            assert_eq!(vec.cmp(&lesser), Ordering::Greater);
+            assert!(vec > &expected[..141]);
+            assert!(vec < &[1][..]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn partial_ord_incomparable() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend([1.0, f64::NAN, 3.0]);
+        assert_eq!(vec.partial_cmp(&[1.0, 2.0, 3.0]), None);
+        assert_eq!(vec.partial_cmp(&[0.0, 2.0, 3.0]), Some(Ordering::Greater));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::new_split_vec::fragment_capacities_for;
+use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Rebuilds the fragments of the vector to the minimal set of fragments that the growth strategy would create
+    /// for the current length, and releases all remaining memory including the reserved fragments,
+    /// regardless of the [`MemoryPolicy`].
+    ///
+    /// This is useful after a large number of elements are removed by `truncate` or `pop` while the vector keeps
+    /// the capacity; such as, with a memory policy keeping the fragments. After compaction, the capacity profile of
+    /// the vector is as if the current elements were pushed to a new vector, and the subsequent pushes allocate
+    /// fragments following the growth strategy from this point on.
+    ///
+    /// When the existing fragments already start with the minimal set, which is always the case for the `Doubling`
+    /// and `Linear` growth strategies, the trailing fragments are simply released and the elements are not moved.
+    /// Otherwise, the elements are moved in bulk into the newly allocated fragments; in this case,
+    /// the pinned element guarantees do not hold.
+    ///
+    /// [`MemoryPolicy`]: crate::MemoryPolicy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.set_memory_policy(MemoryPolicy::KeepAll);
+    ///
+    /// vec.extend(0..100);
+    /// vec.truncate(10);
+    /// assert_eq!(vec.fragments().len(), 2);
+    /// assert_eq!(vec.reserved_capacity(), 16 + 32 + 64);
+    ///
+    /// let first = vec.fragments()[0].as_ptr();
+    /// vec.compact_capacity();
+    /// assert_eq!(vec.reserved_capacity(), 0);
+    /// assert_eq!(vec.capacity(), 4 + 8);
+    /// assert_eq!(vec.fragments()[0].as_ptr(), first);
+    /// assert_eq!(vec, (0..10).collect::<Vec<_>>());
+    /// ```
+    pub fn compact_capacity(&mut self) {
+        self.reserved.clear();
+        self.reserved.shrink_to_fit();
+
+        let capacities = fragment_capacities_for(&self.growth, self.len);
+        let num_fragments = capacities.len();
+
+        let keeps_prefix = self.fragments.len() >= num_fragments
+            && self
+                .fragments
+                .iter()
+                .zip(&capacities)
+                .all(|(f, c)| f.capacity() == *c)
+            && self.fragments[num_fragments..].iter().all(|f| f.is_empty());
+
+        match keeps_prefix {
+            true => {
+                while self.fragments.len() > num_fragments {
+                    self.pop_fragment();
+                }
+            }
+            false => self.move_into_fragments(capacities),
+        }
+
+        self.fragments.shrink_to_fit();
+    }
+
+    /// Moves all elements, in order, into newly allocated fragments with the given `capacities`,
+    /// whose sum must be at least the length of the vector.
+    fn move_into_fragments(&mut self, capacities: Vec<usize>) {
+        self.invalidate_stable_indices();
+
+        let mut old_fragments: Vec<_> = core::iter::from_fn(|| self.pop_fragment()).collect();
+        old_fragments.reverse();
+        let mut new_fragments: Vec<Fragment<T>> =
+            capacities.into_iter().map(Fragment::new).collect();
+
+        let mut f = 0;
+        for mut fragment in old_fragments {
+            let len = fragment.len();
+            // SAFETY: the elements are moved out below; they must not be dropped by the old fragment
+            unsafe { fragment.set_len(0) };
+
+            let mut begin = 0;
+            while begin < len {
+                while new_fragments[f].room() == 0 {
+                    f += 1;
+                }
+                let dst = &mut new_fragments[f];
+                let count = dst.room().min(len - begin);
+                let dst_len = dst.len();
+                // SAFETY: source elements are initialized, and the destination has `count` free positions
+                unsafe {
+                    let src = fragment.as_ptr().add(begin);
+                    core::ptr::copy_nonoverlapping(src, dst.as_mut_ptr().add(dst_len), count);
+                    dst.set_len(dst_len + count);
+                }
+                begin += count;
+            }
+        }
+
+        for fragment in new_fragments {
+            self.push_fragment(fragment);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn compact_capacity() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            for policy in [MemoryPolicy::EagerShrink, MemoryPolicy::KeepAll] {
+                vec.set_memory_policy(policy);
+                for len in [0, 1, 4, 5, 100, 1033] {
+                    vec.clear();
+                    vec.extend((0..2000).map(|x| x.to_string()));
+                    vec.truncate(len);
+                    vec.compact_capacity();
+
+                    let fresh: SplitVec<String, G> =
+                        SplitVec::from_fn_with_growth(len, vec.growth().clone(), |x| x.to_string());
+                    assert_eq!(vec, fresh);
+                    assert_eq!(vec.capacity(), fresh.capacity());
+                    assert_eq!(vec.fragments().len(), fresh.fragments().len());
+                    assert_eq!(vec.reserved_capacity(), 0);
+
+                    vec.extend((len..(len + 50)).map(|x| x.to_string()));
+                    let expected: Vec<_> = (0..(len + 50)).map(|x| x.to_string()).collect();
+                    assert_eq!(vec, expected);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn compact_capacity_moves_appended_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.append((0..3).collect::<Vec<_>>());
+        vec.append(Vec::<usize>::new());
+        vec.append((3..7).collect::<Vec<_>>());
+
+        vec.compact_capacity();
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(vec.fragments().len(), 2);
+        vec.push(7);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
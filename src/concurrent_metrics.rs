@@ -0,0 +1,53 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A snapshot of the metrics collected on the growth of a [`ConcurrentSplitVec`].
+///
+/// Metrics are counted only for the growth requests made on the concurrent vector;
+/// fragments that were already allocated when the split vector was converted into the
+/// concurrent vector are not included.
+///
+/// [`ConcurrentSplitVec`]: crate::ConcurrentSplitVec
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ConcurrentSplitVecMetrics {
+    /// Number of fragments allocated by growth requests.
+    pub num_allocations: usize,
+    /// Sum of capacities of the fragments allocated by growth requests.
+    pub grown_capacity: usize,
+    /// Number of growth requests that did not lead to an allocation since the capacity was already sufficient.
+    ///
+    /// A high number is an indicator of contention, where multiple threads concurrently observe that
+    /// the vector must grow while only one of them actually needs to allocate.
+    pub num_grow_calls_with_sufficient_capacity: usize,
+}
+
+#[derive(Default)]
+pub(crate) struct AtomicMetrics {
+    num_allocations: AtomicUsize,
+    grown_capacity: AtomicUsize,
+    num_grow_calls_with_sufficient_capacity: AtomicUsize,
+}
+
+impl AtomicMetrics {
+    #[inline(always)]
+    pub(crate) fn on_allocation(&self, fragment_capacity: usize) {
+        self.num_allocations.fetch_add(1, Ordering::Relaxed);
+        self.grown_capacity
+            .fetch_add(fragment_capacity, Ordering::Relaxed);
+    }
+
+    #[inline(always)]
+    pub(crate) fn on_sufficient_capacity(&self) {
+        self.num_grow_calls_with_sufficient_capacity
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ConcurrentSplitVecMetrics {
+        ConcurrentSplitVecMetrics {
+            num_allocations: self.num_allocations.load(Ordering::Relaxed),
+            grown_capacity: self.grown_capacity.load(Ordering::Relaxed),
+            num_grow_calls_with_sufficient_capacity: self
+                .num_grow_calls_with_sufficient_capacity
+                .load(Ordering::Relaxed),
+        }
+    }
+}
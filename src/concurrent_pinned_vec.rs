@@ -1,12 +1,13 @@
 use crate::{
+    concurrent_metrics::{AtomicMetrics, ConcurrentSplitVecMetrics},
     fragment::transformations::{fragment_from_raw, fragment_into_raw},
     range_helpers::{range_end, range_start},
-    Doubling, Fragment, GrowthWithConstantTimeAccess, SplitVec,
+    Doubling, Fragment, GrowthWithConstantTimeAccess, SliceMutIter, SplitVec, Zeroable,
 };
 use alloc::vec::Vec;
 use core::cell::UnsafeCell;
 use core::ops::RangeBounds;
-use core::sync::atomic::{AtomicUsize, Ordering};
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
 use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
 
 struct FragmentData {
@@ -16,25 +17,30 @@ struct FragmentData {
 }
 
 /// Concurrent wrapper ([`orx_pinned_vec::ConcurrentPinnedVec`]) for the `SplitVec`.
+///
+/// The concurrent wrapper depends only on `core` and `alloc`; hence, it is available on `no_std` targets.
 pub struct ConcurrentSplitVec<T, G: GrowthWithConstantTimeAccess = Doubling> {
     growth: G,
     data: Vec<UnsafeCell<*mut T>>,
     capacity: AtomicUsize,
     maximum_capacity: usize,
     max_num_fragments: usize,
-    pinned_vec_len: usize,
+    len: AtomicUsize,
+    metrics: AtomicMetrics,
 }
 
 impl<T, G: GrowthWithConstantTimeAccess> Drop for ConcurrentSplitVec<T, G> {
     fn drop(&mut self) {
         let mut take_fragment = |_fragment: Fragment<T>| {};
-        unsafe { self.process_into_fragments(self.pinned_vec_len, &mut take_fragment) };
+        // the length is clamped to the capacity to avoid panicking while dropping
+        let len = (*self.len.get_mut()).min(self.capacity());
+        unsafe { self.process_into_fragments(len, &mut take_fragment) };
         self.zero();
     }
 }
 
 impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
-    unsafe fn get_raw_mut_unchecked_fi(&self, f: usize, i: usize) -> *mut T {
+    pub(crate) unsafe fn get_raw_mut_unchecked_fi(&self, f: usize, i: usize) -> *mut T {
         let p = *self.data[f].get();
         p.add(i)
     }
@@ -44,7 +50,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
         self.get_raw_mut_unchecked_fi(f, i)
     }
 
-    fn capacity_of(&self, f: usize) -> usize {
+    pub(crate) fn capacity_of(&self, f: usize) -> usize {
         self.growth.fragment_capacity_of(f)
     }
 
@@ -82,7 +88,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
         Q: FnMut(FragmentData),
     {
         let capacity = self.capacity();
-        assert!(capacity >= len);
+        debug_assert!(capacity >= len);
 
         let mut remaining_len = len;
         let mut f = 0;
@@ -117,7 +123,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
         self.capacity = 0.into();
         self.maximum_capacity = 0;
         self.max_num_fragments = 0;
-        self.pinned_vec_len = 0;
+        *self.len.get_mut() = 0;
     }
 
     fn num_fragments_for_capacity(&self, capacity: usize) -> usize {
@@ -131,6 +137,773 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
             }
         }
     }
+
+    fn grow_to_allocating<A>(
+        &self,
+        new_capacity: usize,
+        allocate: A,
+    ) -> Result<usize, orx_pinned_vec::PinnedVecGrowthError>
+    where
+        A: Fn(usize) -> *mut T,
+    {
+        let capacity = self.capacity.load(Ordering::Acquire);
+        match new_capacity <= capacity {
+            true => {
+                self.metrics.on_sufficient_capacity();
+                Ok(capacity)
+            }
+            false if new_capacity > self.maximum_capacity => {
+                Err(orx_pinned_vec::PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+            }
+            false => {
+                let mut f = self.num_fragments_for_capacity(capacity);
+                let mut current_capacity = capacity;
+
+                while new_capacity > current_capacity {
+                    let new_fragment_capacity = self.capacity_of(f);
+                    let ptr = allocate(new_fragment_capacity);
+                    unsafe { *self.data[f].get() = ptr };
+                    self.metrics.on_allocation(new_fragment_capacity);
+
+                    f += 1;
+                    current_capacity += new_fragment_capacity;
+                }
+
+                self.capacity.store(current_capacity, Ordering::Release);
+
+                Ok(current_capacity)
+            }
+        }
+    }
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+    /// Appends all elements of the `iter` to the end of the vector, given exclusive access to the vector;
+    /// and returns the new length of the vector.
+    ///
+    /// The vector is assumed to have the length of the split vector it is created from, or the length most
+    /// recently set by `set_pinned_vec_len`; this length is updated as the elements are appended.
+    ///
+    /// Elements are written fragment by fragment and new fragments are allocated only when required,
+    /// without converting back and forth between `SplitVec` and `ConcurrentSplitVec`.
+    /// This is useful for loading data into the vector before concurrent operations start.
+    /// Since exclusive access is available, maximum capacity of the vector is increased whenever required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..3).collect();
+    /// let mut con_vec = vec.into_concurrent();
+    ///
+    /// let len = con_vec.extend_sequential(3..100).unwrap();
+    /// assert_eq!(len, 100);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(len) };
+    /// assert_eq!(vec, (0..100).collect::<Vec<_>>());
+    /// ```
+    pub fn extend_sequential<I>(
+        &mut self,
+        iter: I,
+    ) -> Result<usize, orx_pinned_vec::PinnedVecGrowthError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut len = *self.len.get_mut();
+
+        while let Some(first) = iter.next() {
+            if len == self.capacity() {
+                if len == self.maximum_capacity {
+                    // SAFETY: exclusive access is guaranteed by &mut self
+                    unsafe { self.reserve_maximum_concurrent_capacity(len, len + 1) };
+                }
+                self.grow_to(len + 1)?;
+            }
+
+            let (f, i) = self.growth.get_fragment_and_inner_indices_unchecked(len);
+            let fragment_capacity = self.capacity_of(f);
+
+            // SAFETY: positions i..fragment_capacity of fragment f are allocated and not initialized
+            unsafe { self.get_raw_mut_unchecked_fi(f, i).write(first) };
+            len += 1;
+
+            for j in (i + 1)..fragment_capacity {
+                match iter.next() {
+                    Some(value) => {
+                        unsafe { self.get_raw_mut_unchecked_fi(f, j).write(value) };
+                        len += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            *self.len.get_mut() = len;
+        }
+
+        Ok(len)
+    }
+
+    /// Truncates the vector from `old_len` to `new_len`, given exclusive access to the vector.
+    ///
+    /// Elements in positions `new_len..old_len` are dropped in place, and the trailing fragments which
+    /// become completely empty are deallocated; the first fragment is always kept.
+    /// Does nothing if `new_len >= old_len`.
+    ///
+    /// This avoids the `into_inner`, `truncate` and `into_concurrent` round trip which reallocates
+    /// the collection of fragment pointers.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `old_len` is the current length of the vector;
+    /// i.e., exactly the positions `0..old_len` are initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..100).map(|x| x.to_string()).collect();
+    /// let mut con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.capacity(), 124);
+    ///
+    /// unsafe { con_vec.truncate(10, 100) };
+    /// assert_eq!(con_vec.capacity(), 12);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(10) };
+    /// assert_eq!(vec, (0..10).map(|x| x.to_string()).collect::<Vec<_>>());
+    /// ```
+    pub unsafe fn truncate(&mut self, new_len: usize, old_len: usize) {
+        if new_len >= old_len {
+            return;
+        }
+
+        for slice in self.slices_mut(new_len..old_len) {
+            core::ptr::drop_in_place(slice as *mut [T]);
+        }
+
+        let capacity = self.capacity();
+        let num_fragments = self.num_fragments_for_capacity(capacity);
+        let num_fragments_to_keep = self.num_fragments_for_capacity(new_len).max(1);
+
+        let mut new_capacity = capacity;
+        for f in num_fragments_to_keep..num_fragments {
+            let capacity = self.capacity_of(f);
+            let _fragment_to_drop = self.to_fragment(FragmentData {
+                f,
+                len: 0,
+                capacity,
+            });
+            *self.data[f].get() = core::ptr::null_mut();
+            new_capacity -= capacity;
+        }
+
+        self.capacity.store(new_capacity, Ordering::Release);
+        *self.len.get_mut() = new_len;
+    }
+
+    /// Returns the length of the vector tracked by the concurrent vector itself.
+    ///
+    /// The length is initially the length of the split vector the concurrent vector is created from.
+    /// It is increased by [`push_within_capacity`], and it is set by the methods requiring exclusive access,
+    /// such as `extend_sequential`, `truncate` and `set_pinned_vec_len`.
+    ///
+    /// Note that the length counts the positions reserved by the pushes; an element is guaranteed to be written
+    /// only once the `push_within_capacity` call writing it returns.
+    ///
+    /// Consumers keeping their own length bookkeeping over the `grow_to` and `get_ptr_mut` methods do not need
+    /// this length; in this case, the length is only used to drop the elements when the vector is dropped,
+    /// and it must be set by `set_pinned_vec_len` beforehand.
+    ///
+    /// [`push_within_capacity`]: ConcurrentSplitVec::push_within_capacity
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns whether or not the length tracked by the concurrent vector is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the `value` to the end of the vector if there exists capacity for it without growing,
+    /// and returns the position that the value is written to; returns back the value as the error otherwise.
+    ///
+    /// The position is reserved by atomically incrementing the length tracked by the vector; therefore,
+    /// concurrent producers can append elements with a shared reference without coordinating a length externally.
+    /// When the capacity is not sufficient, the producer may use [`reserve_slot`] which grows the vector lock-free.
+    /// Note that `grow_to` is not safe against concurrent producers and requires external exclusion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_, _> = SplitVec::with_linear_growth_and_fragments_capacity(2, 4);
+    /// let con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.capacity(), 4);
+    ///
+    /// for i in 0..4 {
+    ///     assert_eq!(con_vec.push_within_capacity(i), Ok(i));
+    /// }
+    /// assert_eq!(con_vec.push_within_capacity(4), Err(4));
+    ///
+    /// con_vec.grow_to(5).unwrap();
+    /// assert_eq!(con_vec.push_within_capacity(4), Ok(4));
+    ///
+    /// assert_eq!(con_vec.len(), 5);
+    /// let vec = unsafe { con_vec.into_inner(5) };
+    /// assert_eq!(vec, [0, 1, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`reserve_slot`]: ConcurrentSplitVec::reserve_slot
+    pub fn push_within_capacity(&self, value: T) -> Result<usize, T> {
+        let mut len = self.len.load(Ordering::Acquire);
+        loop {
+            if len >= self.capacity() {
+                return Err(value);
+            }
+
+            match self
+                .len
+                .compare_exchange(len, len + 1, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => {
+                    // SAFETY: position len is within capacity and it is reserved exclusively by this call
+                    unsafe { self.get_raw_mut_unchecked_idx(len).write(value) };
+                    return Ok(len);
+                }
+                Err(current) => len = current,
+            }
+        }
+    }
+
+    /// Tries to make sure that the vector has a capacity of at least `new_capacity`, over-allocating by
+    /// `num_fragments_ahead` additional fragments; and returns the new capacity of the vector.
+    ///
+    /// Similar to `grow_to`, the fragment containing position `new_capacity - 1` is always allocated completely.
+    /// Additionally, the next `num_fragments_ahead` fragments are pre-allocated, as long as they fit in the
+    /// maximum capacity of the vector; the over-allocation stops silently at the maximum capacity.
+    ///
+    /// When many threads grow the vector one element at a time, pre-allocating the next fragment reduces
+    /// the number of times the growth is triggered; and hence, the contention on growth.
+    ///
+    /// Passing `num_fragments_ahead = 0` is equivalent to calling `grow_to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<usize> = SplitVec::with_doubling_growth();
+    /// let con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.capacity(), 4);
+    ///
+    /// // completes the fragment of position 4, which has capacity 8
+    /// assert_eq!(con_vec.grow_to_at_least(5, 0), Ok(12));
+    ///
+    /// // additionally pre-allocates the next fragment of capacity 32
+    /// assert_eq!(con_vec.grow_to_at_least(13, 1), Ok(60));
+    ///
+    /// // capacity is already sufficient
+    /// assert_eq!(con_vec.grow_to_at_least(20, 1), Ok(60));
+    /// ```
+    pub fn grow_to_at_least(
+        &self,
+        new_capacity: usize,
+        num_fragments_ahead: usize,
+    ) -> Result<usize, orx_pinned_vec::PinnedVecGrowthError> {
+        let capacity = self.capacity();
+        if new_capacity <= capacity {
+            self.metrics.on_sufficient_capacity();
+            return Ok(capacity);
+        }
+
+        let num_fragments = self.num_fragments_for_capacity(new_capacity);
+        let mut target_capacity = (0..num_fragments)
+            .map(|f| self.capacity_of(f))
+            .sum::<usize>();
+        let end = num_fragments
+            .saturating_add(num_fragments_ahead)
+            .min(self.max_num_fragments);
+        for f in num_fragments..end {
+            match target_capacity.checked_add(self.capacity_of(f)) {
+                Some(c) if c <= self.maximum_capacity => target_capacity = c,
+                _ => break,
+            }
+        }
+
+        self.grow_to(target_capacity.max(new_capacity))
+    }
+
+    /// Reserves the next position of the vector and returns the position together with a pointer to it,
+    /// allocating the fragment of the position if it is not allocated yet.
+    ///
+    /// The position is reserved by atomically incrementing the length tracked by the vector.
+    /// When the position falls into a fragment which is not allocated yet, the reserving thread allocates it
+    /// and installs it by a compare-exchange on the fragment pointer. If another thread has installed the
+    /// fragment in the meantime, the redundant allocation is released and the installed fragment is used.
+    /// Therefore, unlike `grow_to` which requires external synchronization, multiple threads can trigger
+    /// growth concurrently by this method without any lock.
+    ///
+    /// The capacity of the vector is advanced once all fragments up to and including the new fragment are
+    /// installed; hence, `capacity` never covers a fragment which is not allocated yet.
+    ///
+    /// Returns an error if the maximum capacity of the vector is reached; the length is not changed in this case.
+    ///
+    /// Note that `reserve_slot` must not be called concurrently with the methods growing the vector by an
+    /// explicit capacity, such as `grow_to` or `grow_to_and_fill_with`.
+    ///
+    /// # Safety
+    ///
+    /// The returned position counts in the length of the vector, and the elements within the length are dropped
+    /// together with the vector. Therefore, the caller must write a valid value to the returned pointer before the
+    /// vector is dropped or converted back into a `SplitVec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<usize> = SplitVec::with_doubling_growth();
+    /// let con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.capacity(), 4);
+    ///
+    /// for i in 0..5 {
+    ///     let (idx, ptr) = unsafe { con_vec.reserve_slot() }.unwrap();
+    ///     assert_eq!(idx, i);
+    ///     unsafe { ptr.write(i * 10) };
+    /// }
+    /// assert_eq!(con_vec.len(), 5);
+    /// assert_eq!(con_vec.capacity(), 12);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(5) };
+    /// assert_eq!(vec, [0, 10, 20, 30, 40]);
+    /// ```
+    pub unsafe fn reserve_slot(
+        &self,
+    ) -> Result<(usize, *mut T), orx_pinned_vec::PinnedVecGrowthError> {
+        let mut idx = self.len.load(Ordering::Acquire);
+        loop {
+            if idx >= self.maximum_capacity {
+                return Err(
+                    orx_pinned_vec::PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned,
+                );
+            }
+
+            match self
+                .len
+                .compare_exchange(idx, idx + 1, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(current) => idx = current,
+            }
+        }
+
+        let (f, i) = self.growth.get_fragment_and_inner_indices_unchecked(idx);
+        let ptr = self.install_fragment(f);
+        // SAFETY: i is within the capacity of the allocated fragment f
+        Ok((idx, unsafe { ptr.add(i) }))
+    }
+
+    /// Returns the fragment pointer at position `f` as an atomic pointer.
+    fn fragment_ptr(&self, f: usize) -> &AtomicPtr<T> {
+        // SAFETY: the cell is valid and aligned for the lifetime of self; concurrent writes to the fragment
+        // pointers are performed only through the atomic pointer
+        unsafe { AtomicPtr::from_ptr(self.data[f].get()) }
+    }
+
+    /// Makes sure that the `f`-th fragment is allocated and returns its pointer; the fragment is installed
+    /// by a compare-exchange so that the allocation of only one of the racing threads survives.
+    fn install_fragment(&self, f: usize) -> *mut T {
+        let slot = self.fragment_ptr(f);
+        let current = slot.load(Ordering::SeqCst);
+        if !current.is_null() {
+            return current;
+        }
+
+        let fragment_capacity = self.capacity_of(f);
+        let layout = Self::layout(fragment_capacity);
+        let new_ptr = unsafe { alloc::alloc::alloc(layout) as *mut T };
+
+        let ptr = match slot.compare_exchange(
+            core::ptr::null_mut(),
+            new_ptr,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(_) => {
+                self.metrics.on_allocation(fragment_capacity);
+                new_ptr
+            }
+            Err(installed) => {
+                unsafe { alloc::alloc::dealloc(new_ptr as *mut u8, layout) };
+                installed
+            }
+        };
+
+        self.advance_capacity_over_installed_fragments();
+        ptr
+    }
+
+    /// Increases the capacity over the consecutive installed fragments following the current capacity.
+    fn advance_capacity_over_installed_fragments(&self) {
+        let mut capacity = self.capacity.load(Ordering::SeqCst);
+        loop {
+            let f = self.num_fragments_for_capacity(capacity);
+            if f >= self.max_num_fragments || self.fragment_ptr(f).load(Ordering::SeqCst).is_null()
+            {
+                return;
+            }
+
+            let new_capacity = capacity + self.capacity_of(f);
+            capacity = match self.capacity.compare_exchange(
+                capacity,
+                new_capacity,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => new_capacity,
+                Err(current) => current,
+            };
+        }
+    }
+
+    /// Returns the slices which together form the given `range` of the vector, bounded by the given `len`.
+    ///
+    /// Unlike `slices` which is bounded by the capacity, positions at or beyond `len` are never included;
+    /// hence, the slices do not expose the uninitialized positions of the vector.
+    /// The range is clamped to `0..len`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the positions `0..len` are initialized and not written concurrently
+    /// while the slices are in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..10).collect();
+    /// let con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.capacity(), 12);
+    ///
+    /// let slices = unsafe { con_vec.slices_of_len(2.., 10) };
+    /// assert_eq!(slices, [&[2, 3][..], &[4, 5, 6, 7, 8, 9][..]]);
+    ///
+    /// let slices = unsafe { con_vec.slices_of_len(.., 3) };
+    /// assert_eq!(slices, [&[0, 1, 2][..]]);
+    /// ```
+    pub unsafe fn slices_of_len<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+        len: usize,
+    ) -> <SplitVec<T, G> as PinnedVec<T>>::SliceIter<'_> {
+        let len = len.min(self.capacity());
+        let a = range_start(&range);
+        let b = range_end(&range, len).min(len);
+        match a < b {
+            true => self.slices(a..b),
+            false => Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over the elements in the given `range` of the vector, bounded by the given `len`.
+    ///
+    /// Positions at or beyond `len` are never visited; the range is clamped to `0..len`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the positions `0..len` are initialized and not written concurrently
+    /// while the iterator is in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..10).collect();
+    /// let con_vec = vec.into_concurrent();
+    ///
+    /// let values: Vec<_> = unsafe { con_vec.iter_over_range_of_len(5..20, 10) }.copied().collect();
+    /// assert_eq!(values, [5, 6, 7, 8, 9]);
+    /// ```
+    pub unsafe fn iter_over_range_of_len<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+        len: usize,
+    ) -> impl Iterator<Item = &T> + '_ {
+        self.slices_of_len(range, len)
+            .into_iter()
+            .flat_map(|x| x.iter())
+    }
+
+    /// Returns a snapshot of the metrics collected on the growth of the concurrent vector,
+    /// such as the number of fragment allocations and the total grown capacity.
+    ///
+    /// The counters are updated with relaxed atomic operations only on growth requests;
+    /// therefore, collecting metrics has a negligible cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<u32, _> = SplitVec::with_linear_growth_and_fragments_capacity(2, 8);
+    /// let con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.metrics(), ConcurrentSplitVecMetrics::default());
+    ///
+    /// con_vec.grow_to(10).unwrap();
+    /// con_vec.grow_to(5).unwrap();
+    ///
+    /// let metrics = con_vec.metrics();
+    /// assert_eq!(metrics.num_allocations, 2);
+    /// assert_eq!(metrics.grown_capacity, 8);
+    /// assert_eq!(metrics.num_grow_calls_with_sufficient_capacity, 1);
+    /// ```
+    pub fn metrics(&self) -> ConcurrentSplitVecMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Increases the maximum capacity of the vector to at least `new_maximum_capacity`, given exclusive access to the vector,
+    /// by reserving the minimum number of fragments whose cumulative capacity reaches `new_maximum_capacity`.
+    ///
+    /// Returns a tuple of the new maximum capacity and the number of fragments added.
+    ///
+    /// Only the slots of the fragments are reserved; the fragments themselves are allocated later as the vector grows.
+    ///
+    /// See [`ConcurrentSplitVec::try_reserve_maximum_concurrent_capacity_exact`] for the variant which fails rather than
+    /// exceeding the maximum number of fragments of the growth strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the growth strategy cannot reach `new_maximum_capacity`; i.e., if the required number of fragments exceeds
+    /// [`Growth::max_fragments`].
+    ///
+    /// [`Growth::max_fragments`]: crate::Growth::max_fragments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<char> = SplitVec::with_doubling_growth_and_fragments_capacity(2);
+    /// let mut con_vec = vec.into_concurrent();
+    /// assert_eq!(con_vec.max_capacity(), 4 + 8);
+    ///
+    /// assert_eq!(con_vec.reserve_maximum_concurrent_capacity_exact(10), (4 + 8, 0));
+    /// assert_eq!(con_vec.reserve_maximum_concurrent_capacity_exact(13), (4 + 8 + 16, 1));
+    /// assert_eq!(con_vec.reserve_maximum_concurrent_capacity_exact(100), (4 + 8 + 16 + 32 + 64, 2));
+    /// ```
+    pub fn reserve_maximum_concurrent_capacity_exact(
+        &mut self,
+        new_maximum_capacity: usize,
+    ) -> (usize, usize) {
+        let mut num_fragments = self.data.len();
+        let mut maximum_capacity = self.maximum_capacity;
+
+        while maximum_capacity < new_maximum_capacity {
+            maximum_capacity += self.capacity_of(num_fragments);
+            num_fragments += 1;
+        }
+
+        let num_additional_fragments = num_fragments - self.data.len();
+        if num_additional_fragments > 0 {
+            self.data.reserve_exact(num_additional_fragments);
+            for _ in 0..num_additional_fragments {
+                self.data.push(UnsafeCell::new(core::ptr::null_mut()));
+            }
+        }
+
+        self.maximum_capacity = maximum_capacity;
+        self.max_num_fragments = self.data.len();
+
+        (self.maximum_capacity, num_additional_fragments)
+    }
+
+    /// Increases the maximum capacity of the vector to at least `new_maximum_capacity` exactly as
+    /// [`ConcurrentSplitVec::reserve_maximum_concurrent_capacity_exact`] does, provided that the required number of fragments
+    /// does not exceed [`Growth::max_fragments`].
+    ///
+    /// Returns a tuple of the new maximum capacity and the number of fragments added.
+    ///
+    /// Returns the [`PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned`] error, leaving the vector unchanged,
+    /// if the growth strategy cannot reach `new_maximum_capacity` within its maximum number of fragments.
+    ///
+    /// [`Growth::max_fragments`]: crate::Growth::max_fragments
+    /// [`PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned`]: orx_pinned_vec::PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<char> = SplitVec::with_doubling_growth_and_fragments_capacity(2);
+    /// let mut con_vec = vec.into_concurrent();
+    ///
+    /// assert_eq!(con_vec.try_reserve_maximum_concurrent_capacity_exact(13), Ok((4 + 8 + 16, 1)));
+    ///
+    /// assert!(con_vec.try_reserve_maximum_concurrent_capacity_exact(usize::MAX).is_err());
+    /// assert_eq!(con_vec.max_capacity(), 4 + 8 + 16);
+    /// ```
+    pub fn try_reserve_maximum_concurrent_capacity_exact(
+        &mut self,
+        new_maximum_capacity: usize,
+    ) -> Result<(usize, usize), orx_pinned_vec::PinnedVecGrowthError> {
+        if let Some(max_fragments) = self.growth.max_fragments() {
+            let mut num_fragments = self.data.len();
+            let mut maximum_capacity = self.maximum_capacity;
+
+            while maximum_capacity < new_maximum_capacity {
+                if num_fragments >= max_fragments {
+                    return Err(
+                        orx_pinned_vec::PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned,
+                    );
+                }
+                maximum_capacity = maximum_capacity.saturating_add(self.capacity_of(num_fragments));
+                num_fragments += 1;
+            }
+        }
+
+        Ok(self.reserve_maximum_concurrent_capacity_exact(new_maximum_capacity))
+    }
+
+    /// Returns an iterator of pointers to the elements at positions `start`, `start + step`, `start + 2 * step`, ...
+    /// which are less than `len`.
+    ///
+    /// Only the position of the first element is computed by the growth's constant time index math;
+    /// the subsequent positions are reached by moving forward across the fragments, without any division.
+    /// This enables parallel algorithms that process interleaved partitions, where thread `t` out of `n`
+    /// threads handles the positions `t, t + n, t + 2n, ...`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, or if `len` is greater than the capacity of the vector.
+    ///
+    /// # Safety
+    ///
+    /// The returned pointers are within the allocated memory of the vector; however, they might point to
+    /// uninitialized memory if `len` is greater than the length of the vector.
+    /// Further, the caller is responsible for avoiding data races while reading from or writing to the pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..20).collect();
+    /// let con_vec = vec.into_concurrent();
+    ///
+    /// let odds: Vec<_> = unsafe { con_vec.ptr_iter_strided(1, 2, 20) }
+    ///     .map(|p| unsafe { *p })
+    ///     .collect();
+    /// assert_eq!(odds, (0..10).map(|x| 2 * x + 1).collect::<Vec<_>>());
+    ///
+    /// // interleaved partitions, each of which could be handled by a different thread
+    /// for t in 0..3 {
+    ///     for p in unsafe { con_vec.ptr_iter_strided(t, 3, 20) } {
+    ///         unsafe { *p *= 10 };
+    ///     }
+    /// }
+    ///
+    /// let vec = unsafe { con_vec.into_inner(20) };
+    /// assert_eq!(vec, (0..20).map(|x| x * 10).collect::<Vec<_>>());
+    /// ```
+    pub unsafe fn ptr_iter_strided(
+        &self,
+        start: usize,
+        step: usize,
+        len: usize,
+    ) -> impl ExactSizeIterator<Item = *mut T> + '_ {
+        assert!(step > 0, "step of a strided iterator must be positive");
+        assert!(
+            len <= self.capacity(),
+            "len of a strided iterator cannot exceed the capacity of the vector"
+        );
+
+        let remaining = match start < len {
+            true => (len - start - 1) / step + 1,
+            false => 0,
+        };
+        let (f, i) = match remaining > 0 {
+            true => self.growth.get_fragment_and_inner_indices_unchecked(start),
+            false => (0, 0),
+        };
+
+        PtrIterStrided {
+            vec: self,
+            f,
+            i,
+            step,
+            remaining,
+        }
+    }
+}
+
+struct PtrIterStrided<'a, T, G: GrowthWithConstantTimeAccess> {
+    vec: &'a ConcurrentSplitVec<T, G>,
+    f: usize,
+    i: usize,
+    step: usize,
+    remaining: usize,
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> Iterator for PtrIterStrided<'_, T, G> {
+    type Item = *mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                // SAFETY: (f, i) is within the first len <= capacity positions
+                let ptr = unsafe { self.vec.get_raw_mut_unchecked_fi(self.f, self.i) };
+                self.remaining -= 1;
+
+                if self.remaining > 0 {
+                    self.i += self.step;
+                    loop {
+                        let capacity = self.vec.capacity_of(self.f);
+                        match self.i < capacity {
+                            true => break,
+                            false => {
+                                self.i -= capacity;
+                                self.f += 1;
+                            }
+                        }
+                    }
+                }
+
+                Some(ptr)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ExactSizeIterator for PtrIterStrided<'_, T, G> {}
+
+impl<T: Zeroable, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+    /// Tries to make sure that the vector has a capacity of at least `new_capacity`,
+    /// where all positions of the newly allocated fragments are zeroed.
+    ///
+    /// This is equivalent to `grow_to_and_fill_with(new_capacity, || zero)`;
+    /// however, rather than writing elements one by one, the new fragments are allocated with `alloc_zeroed`.
+    /// This is useful for use cases such as concurrent counters or histograms.
+    ///
+    /// Returns the new capacity of the vector.
+    pub fn grow_to_zeroed(
+        &self,
+        new_capacity: usize,
+    ) -> Result<usize, orx_pinned_vec::PinnedVecGrowthError> {
+        self.grow_to_allocating(new_capacity, |capacity| {
+            let layout = Self::layout(capacity);
+            unsafe { alloc::alloc::alloc_zeroed(layout) as *mut T }
+        })
+    }
 }
 
 impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSplitVec<T, G> {
@@ -138,7 +911,11 @@ impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSpli
         let (fragments, growth, pinned_vec_len) = (value.fragments, value.growth, value.len);
 
         let num_fragments = fragments.len();
-        let max_num_fragments = fragments.capacity();
+        let max_num_fragments = match growth.max_fragments() {
+            Some(max_fragments) => fragments.capacity().min(max_fragments),
+            None => fragments.capacity(),
+        }
+        .max(num_fragments);
 
         let mut data = Vec::with_capacity(max_num_fragments);
         let mut total_len = 0;
@@ -158,7 +935,7 @@ impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSpli
         assert_eq!(total_len, pinned_vec_len);
         let capacity = maximum_capacity;
 
-        for f in num_fragments..data.capacity() {
+        for f in num_fragments..max_num_fragments {
             let expected_cap = growth.fragment_capacity_of(f);
             maximum_capacity += expected_cap;
 
@@ -171,7 +948,8 @@ impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSpli
             capacity: capacity.into(),
             maximum_capacity,
             max_num_fragments,
-            pinned_vec_len,
+            len: pinned_vec_len.into(),
+            metrics: AtomicMetrics::default(),
         }
     }
 }
@@ -180,6 +958,10 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     type P = SplitVec<T, G>;
 
     unsafe fn into_inner(mut self, len: usize) -> Self::P {
+        assert!(
+            len <= self.capacity(),
+            "length must not exceed the capacity of the vector"
+        );
         let mut fragments = Vec::with_capacity(self.max_num_fragments);
         let mut take_fragment = |fragment| fragments.push(fragment);
         self.process_into_fragments(len, &mut take_fragment);
@@ -290,7 +1072,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         let b = range_end(&range, self.capacity());
 
         match b.saturating_sub(a) {
-            0 => alloc::vec![],
+            0 => SliceMutIter::default(),
             _ => {
                 let (sf, si) = fragment_and_inner_indices(a);
                 let (ef, ei) = fragment_and_inner_indices(b - 1);
@@ -299,7 +1081,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
                     true => {
                         let p = unsafe { self.get_raw_mut_unchecked_fi(sf, si) };
                         let slice = unsafe { from_raw_parts_mut(p, ei - si + 1) };
-                        alloc::vec![slice]
+                        SliceMutIter::new(Some(slice), &mut [], None)
                     }
                     false => {
                         let mut vec = Vec::with_capacity(ef - sf + 1);
@@ -321,7 +1103,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
                         let slice = unsafe { from_raw_parts_mut(p, slice_len) };
                         vec.push(slice);
 
-                        vec
+                        vec.into()
                     }
                 }
             }
@@ -332,9 +1114,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     where
         T: 'a,
     {
-        self.slices_mut(0..len)
-            .into_iter()
-            .flat_map(|x| x.iter_mut())
+        self.slices_mut(0..len).flat_map(|x| x.iter_mut())
     }
 
     unsafe fn get(&self, index: usize) -> Option<&T> {
@@ -370,28 +1150,10 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     }
 
     fn grow_to(&self, new_capacity: usize) -> Result<usize, orx_pinned_vec::PinnedVecGrowthError> {
-        let capacity = self.capacity.load(Ordering::Acquire);
-        match new_capacity <= capacity {
-            true => Ok(capacity),
-            false => {
-                let mut f = self.num_fragments_for_capacity(capacity);
-                let mut current_capacity = capacity;
-
-                while new_capacity > current_capacity {
-                    let new_fragment_capacity = self.capacity_of(f);
-                    let layout = Self::layout(new_fragment_capacity);
-                    let ptr = unsafe { alloc::alloc::alloc(layout) } as *mut T;
-                    unsafe { *self.data[f].get() = ptr };
-
-                    f += 1;
-                    current_capacity += new_fragment_capacity;
-                }
-
-                self.capacity.store(current_capacity, Ordering::Release);
-
-                Ok(current_capacity)
-            }
-        }
+        self.grow_to_allocating(new_capacity, |capacity| {
+            let layout = Self::layout(capacity);
+            unsafe { alloc::alloc::alloc(layout) as *mut T }
+        })
     }
 
     fn grow_to_and_fill_with<F>(
@@ -402,34 +1164,14 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     where
         F: Fn() -> T,
     {
-        let capacity = self.capacity.load(Ordering::Acquire);
-        match new_capacity <= capacity {
-            true => Ok(capacity),
-            false => {
-                let mut f = self.num_fragments_for_capacity(capacity);
-
-                let mut current_capacity = capacity;
-
-                while new_capacity > current_capacity {
-                    let new_fragment_capacity = self.capacity_of(f);
-                    let layout = Self::layout(new_fragment_capacity);
-                    let ptr = unsafe { alloc::alloc::alloc(layout) } as *mut T;
-
-                    for i in 0..new_fragment_capacity {
-                        unsafe { ptr.add(i).write(fill_with()) };
-                    }
-
-                    unsafe { *self.data[f].get() = ptr };
-
-                    f += 1;
-                    current_capacity += new_fragment_capacity;
-                }
-
-                self.capacity.store(current_capacity, Ordering::Release);
-
-                Ok(current_capacity)
+        self.grow_to_allocating(new_capacity, |capacity| {
+            let layout = Self::layout(capacity);
+            let ptr = unsafe { alloc::alloc::alloc(layout) } as *mut T;
+            for i in 0..capacity {
+                unsafe { ptr.add(i).write(fill_with()) };
             }
-        }
+            ptr
+        })
     }
 
     fn fill_with<F>(&self, range: core::ops::Range<usize>, fill_with: F)
@@ -451,11 +1193,12 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
 
         let mut num_required_fragments = 0;
         let mut max_cap = self.maximum_capacity;
-        let f = self.data.len();
+        let mut f = self.data.len();
 
         while max_cap < new_maximum_capacity {
             max_cap += self.capacity_of(f);
             num_required_fragments += 1;
+            f += 1;
         }
 
         if num_required_fragments > 0 {
@@ -497,7 +1240,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     }
 
     unsafe fn set_pinned_vec_len(&mut self, len: usize) {
-        self.pinned_vec_len = len;
+        *self.len.get_mut() = len;
     }
 
     unsafe fn clear(&mut self, len: usize) {
@@ -511,8 +1254,9 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         for _ in 0..max_num_fragments {
             self.data.push(UnsafeCell::new(core::ptr::null_mut()));
         }
+        self.max_num_fragments = self.data.len();
 
         self.maximum_capacity = (0..self.data.len()).map(|f| self.capacity_of(f)).sum();
-        self.pinned_vec_len = 0;
+        *self.len.get_mut() = 0;
     }
 }
@@ -0,0 +1,179 @@
+use crate::{ConcurrentSplitVec, GrowthWithConstantTimeAccess};
+use alloc::vec::Vec;
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVecGrowthError};
+
+/// Immutable view over the committed prefix of a [`ConcurrentSplitVec`],
+/// created by [`ConcurrentSplitVec::split_at_committed`].
+///
+/// All elements of the prefix are initialized and none of them is mutated while the view is alive;
+/// therefore, the view provides safe shared access and can be shared among threads,
+/// while the [`ConcurrentSplitVecSuffix`] keeps appending elements to the vector.
+pub struct ConcurrentSplitVecPrefix<'a, T, G: GrowthWithConstantTimeAccess> {
+    vec: &'a ConcurrentSplitVec<T, G>,
+    len: usize,
+}
+
+// SAFETY: the prefix only provides shared access to the committed elements which are never mutated,
+// and growth of the vector by the suffix never touches the memory of the committed elements.
+unsafe impl<T: Sync, G: GrowthWithConstantTimeAccess + Sync> Sync
+    for ConcurrentSplitVecPrefix<'_, T, G>
+{
+}
+
+// SAFETY: see the Sync implementation; a prefix behaves like a `&[T]`.
+unsafe impl<T: Sync, G: GrowthWithConstantTimeAccess + Sync> Send
+    for ConcurrentSplitVecPrefix<'_, T, G>
+{
+}
+
+impl<'a, T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVecPrefix<'a, T, G> {
+    /// Number of elements in the committed prefix.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the committed prefix is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the `index`-th position of the prefix;
+    /// returns None if the `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index < self.len {
+            // SAFETY: index is within the committed length which is guaranteed to be initialized
+            true => unsafe { self.vec.get(index) },
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the elements of the committed prefix.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        // SAFETY: elements within the committed length are guaranteed to be initialized
+        unsafe { self.vec.iter(self.len) }
+    }
+
+    /// Returns the slices which, when chained, form the committed prefix.
+    pub fn slices(&self) -> Vec<&'a [T]> {
+        self.vec.slices(0..self.len)
+    }
+}
+
+/// Append-only handle to the suffix of a [`ConcurrentSplitVec`] beyond its committed length,
+/// created by [`ConcurrentSplitVec::split_at_committed`].
+///
+/// The handle is the single writer of the vector while it is alive.
+/// It never touches the committed prefix; hence, it can safely be used while
+/// [`ConcurrentSplitVecPrefix`] is being read by other threads.
+pub struct ConcurrentSplitVecSuffix<'a, T, G: GrowthWithConstantTimeAccess> {
+    vec: &'a ConcurrentSplitVec<T, G>,
+    len: usize,
+}
+
+// SAFETY: the suffix exclusively owns the positions beyond the committed length.
+unsafe impl<T: Send, G: GrowthWithConstantTimeAccess + Sync> Send
+    for ConcurrentSplitVecSuffix<'_, T, G>
+{
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVecSuffix<'_, T, G> {
+    /// Total length of the vector; i.e., the committed length plus the number of elements appended by the suffix.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends the `value` to the end of the vector, growing the vector if required;
+    /// and returns the index of the appended element.
+    ///
+    /// Returns an error if the vector cannot grow beyond its maximum capacity;
+    /// see [`ConcurrentPinnedVec::max_capacity`].
+    pub fn push(&mut self, value: T) -> Result<usize, PinnedVecGrowthError> {
+        let idx = self.len;
+        if idx >= self.vec.capacity() {
+            if idx >= self.vec.max_capacity() {
+                return Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned);
+            }
+            self.vec.grow_to(idx + 1)?;
+        }
+
+        // SAFETY: idx is within capacity and is exclusively owned by the suffix
+        unsafe { self.vec.get_ptr_mut(idx).write(value) };
+        self.len += 1;
+        Ok(idx)
+    }
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+    /// Splits the concurrent vector logically at the `committed_len` into:
+    /// * an immutable [`ConcurrentSplitVecPrefix`] view over the first `committed_len` elements
+    ///   which provides safe shared access and can be shared among reader threads, and
+    /// * an append-only [`ConcurrentSplitVecSuffix`] handle for the single writer.
+    ///
+    /// This encodes the common single-writer / many-readers pattern in types:
+    /// readers can never observe uninitialized or concurrently written elements,
+    /// and the writer can never mutate the committed elements.
+    ///
+    /// Once both handles are dropped, the length of the vector is `suffix.len()`.
+    /// Since the concurrent vector does not track its length, the caller is responsible for
+    /// using this length in later calls, such as `set_pinned_vec_len` or `into_inner`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `committed_len` is greater than the length tracked by the vector, see [`ConcurrentSplitVec::len`];
+    /// since only the elements within the tracked length are known to be initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..5).collect();
+    /// let mut con_vec = vec.into_concurrent();
+    ///
+    /// let (prefix, mut suffix) = con_vec.split_at_committed(5);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..2 {
+    ///         s.spawn(|| assert_eq!(prefix.iter().sum::<i32>(), 10));
+    ///     }
+    ///     s.spawn(|| {
+    ///         for i in 5..20 {
+    ///             assert_eq!(suffix.push(i), Ok(i as usize));
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// let len = suffix.len();
+    /// assert_eq!(len, 20);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(len) };
+    /// assert_eq!(vec, (0..20).collect::<Vec<_>>());
+    /// ```
+    pub fn split_at_committed(
+        &mut self,
+        committed_len: usize,
+    ) -> (
+        ConcurrentSplitVecPrefix<'_, T, G>,
+        ConcurrentSplitVecSuffix<'_, T, G>,
+    ) {
+        assert!(
+            committed_len <= self.len(),
+            "committed length must not exceed the length of the vector"
+        );
+        let vec = &*self;
+        let prefix = ConcurrentSplitVecPrefix {
+            vec,
+            len: committed_len,
+        };
+        let suffix = ConcurrentSplitVecSuffix {
+            vec,
+            len: committed_len,
+        };
+        (prefix, suffix)
+    }
+}
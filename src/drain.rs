@@ -0,0 +1,500 @@
+use crate::range_helpers::{range_end, range_start};
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::RangeBounds;
+
+/// A draining iterator over a range of a split vector, yielding the removed elements by value.
+///
+/// When the iterator is dropped, the elements of the range which are not yet yielded are dropped,
+/// and the tail of the vector is shifted left across fragment boundaries to close the gap.
+///
+/// If the iterator is leaked, such as by `core::mem::forget`, the vector is truncated to the start of the range,
+/// and the elements of the range that are not yielded as well as the tail are leaked.
+///
+/// This struct is created by `SplitVec::drain()` method.
+pub struct Drain<'a, T, G: Growth> {
+    vec: &'a mut SplitVec<T, G>,
+    /// Original lengths of the fragments starting from fragment `start.0`.
+    lens: Vec<usize>,
+    /// Position of the first drained element, which is also the destination of the first element of the tail.
+    start: (usize, usize),
+    /// Position of the next element to be yielded from the front.
+    front: (usize, usize),
+    /// Position right after the next element to be yielded from the back.
+    back: (usize, usize),
+    /// Position of the first element of the tail.
+    end: (usize, usize),
+    remaining: usize,
+    tail_len: usize,
+}
+
+impl<'a, T, G: Growth> Drain<'a, T, G> {
+    fn new(vec: &'a mut SplitVec<T, G>, start: usize, end: usize) -> Self {
+        vec.invalidate_stable_indices();
+        if start == end || vec.fragments.is_empty() {
+            // nothing to drain; the vector is not touched
+            return Self {
+                vec,
+                lens: Vec::new(),
+                start: (0, 0),
+                front: (0, 0),
+                back: (0, 0),
+                end: (0, 0),
+                remaining: 0,
+                tail_len: 0,
+            };
+        }
+
+        let tail_len = vec.len - end;
+        let start_position = position_of(&vec.fragments, start);
+        let end_position = position_of(&vec.fragments, end);
+        let (sf, si) = start_position;
+
+        let lens: Vec<_> = vec.fragments[sf..].iter().map(|x| x.len()).collect();
+
+        // SAFETY: lengths are only decreased; elements beyond the lengths are handled by the drain
+        unsafe { vec.fragments[sf].set_len(si) };
+        for fragment in &mut vec.fragments[(sf + 1)..] {
+            unsafe { fragment.set_len(0) };
+        }
+        vec.len = start;
+
+        Self {
+            vec,
+            lens,
+            start: start_position,
+            front: start_position,
+            back: end_position,
+            end: end_position,
+            remaining: end - start,
+            tail_len,
+        }
+    }
+
+    #[inline(always)]
+    fn len_of(&self, f: usize) -> usize {
+        self.lens[f - self.start.0]
+    }
+
+    #[inline(always)]
+    fn ptr_at(&mut self, (f, i): (usize, usize)) -> *mut T {
+        // SAFETY: (f, i) is within the original length of fragment f
+        unsafe { self.vec.fragments[f].as_mut_ptr().add(i) }
+    }
+
+    /// Drops the elements of the range which are not yet yielded in place, piecewise as slices of the fragments.
+    fn drop_remaining(&mut self) {
+        let mut remaining = core::mem::take(&mut self.remaining);
+        let mut front = self.front;
+        self.front = self.back;
+        while remaining > 0 {
+            while front.1 == self.len_of(front.0) {
+                front = (front.0 + 1, 0);
+            }
+            let n = (self.len_of(front.0) - front.1).min(remaining);
+            let slice = core::ptr::slice_from_raw_parts_mut(self.ptr_at(front), n);
+            // SAFETY: elements are within the drained range and not yielded; they are marked as yielded above
+            unsafe { core::ptr::drop_in_place(slice) };
+            front.1 += n;
+            remaining -= n;
+        }
+    }
+
+    /// Moves the tail of the vector to the start of the drained range and sets the lengths of the fragments.
+    fn close_gap(&mut self) {
+        if self.lens.is_empty() {
+            return;
+        }
+
+        let (mut src, mut dst) = (self.end, self.start);
+        let mut remaining = self.tail_len;
+        while remaining > 0 {
+            while src.1 == self.len_of(src.0) {
+                src = (src.0 + 1, 0);
+            }
+            while dst.1 == self.len_of(dst.0) {
+                dst = (dst.0 + 1, 0);
+            }
+
+            let n = (self.len_of(src.0) - src.1)
+                .min(self.len_of(dst.0) - dst.1)
+                .min(remaining);
+            let (src_ptr, dst_ptr) = (self.ptr_at(src), self.ptr_at(dst));
+            // SAFETY: destination precedes the source; ptr::copy allows overlapping regions
+            unsafe { core::ptr::copy(src_ptr, dst_ptr, n) };
+
+            src.1 += n;
+            dst.1 += n;
+            remaining -= n;
+        }
+
+        let (sf, si) = self.start;
+        let mut to_place = si + self.tail_len;
+        for f in sf..self.vec.fragments.len() {
+            let len = self.len_of(f).min(to_place);
+            // SAFETY: first `len` elements of the fragment are initialized by the above moves
+            unsafe { self.vec.fragments[f].set_len(len) };
+            to_place -= len;
+        }
+
+        while self.vec.fragments.len() > 1
+            && self.vec.fragments.last().map(|x| x.is_empty()) == Some(true)
+        {
+            self.vec
+                .release_fragments_after(self.vec.fragments.len() - 1);
+        }
+
+        self.vec.len += self.tail_len;
+    }
+}
+
+impl<T, G: Growth> Iterator for Drain<'_, T, G> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                while self.front.1 == self.len_of(self.front.0) {
+                    self.front = (self.front.0 + 1, 0);
+                }
+                let ptr = self.ptr_at(self.front);
+                self.front.1 += 1;
+                self.remaining -= 1;
+                // SAFETY: element is within the drained range and is read only once
+                Some(unsafe { ptr.read() })
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, G: Growth> DoubleEndedIterator for Drain<'_, T, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                while self.back.1 == 0 {
+                    self.back = (self.back.0 - 1, self.len_of(self.back.0 - 1));
+                }
+                self.back.1 -= 1;
+                self.remaining -= 1;
+                let ptr = self.ptr_at(self.back);
+                // SAFETY: element is within the drained range and is read only once
+                Some(unsafe { ptr.read() })
+            }
+        }
+    }
+}
+
+impl<T, G: Growth> ExactSizeIterator for Drain<'_, T, G> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T, G: Growth> FusedIterator for Drain<'_, T, G> {}
+
+impl<T, G: Growth> Drop for Drain<'_, T, G> {
+    fn drop(&mut self) {
+        self.drop_remaining();
+        self.close_gap();
+    }
+}
+
+impl<'a, T, G: Growth> Drain<'a, T, G> {
+    /// Completes the drain as it would be on drop, and returns back the mutable reference to the vector.
+    pub(crate) fn finish(self) -> &'a mut SplitVec<T, G> {
+        let mut drain = core::mem::ManuallyDrop::new(self);
+        drain.drop_remaining();
+        drain.close_gap();
+        // SAFETY: drain is never dropped; hence, its fields are read exactly once
+        drop(unsafe { core::ptr::read(&drain.lens) });
+        unsafe { core::ptr::read(&drain.vec) }
+    }
+}
+
+/// Returns the fragment and inner indices of the element at the given `index` with respect to the
+/// lengths of the fragments; `index` equal to the length of the vector maps to the end of the last fragment.
+fn position_of<T>(fragments: &[crate::Fragment<T>], index: usize) -> (usize, usize) {
+    let mut begin = 0;
+    for (f, fragment) in fragments.iter().enumerate() {
+        let end = begin + fragment.len();
+        if index < end {
+            return (f, index - begin);
+        }
+        begin = end;
+    }
+    match fragments.len() {
+        0 => (0, 0),
+        n => (n - 1, fragments[n - 1].len()),
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Removes the specified `range` from the vector in bulk, returning all removed elements as an iterator.
+    ///
+    /// When the iterator is dropped, all elements in the range are removed from the vector,
+    /// even if the iterator was not fully consumed; and the elements after the range are shifted left
+    /// across the fragment boundaries.
+    ///
+    /// Note that the memory locations of the elements after the range change;
+    /// just as they would with `remove`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// let drained: Vec<_> = vec.drain(2..7).collect();
+    /// assert_eq!(drained, [2, 3, 4, 5, 6]);
+    /// assert_eq!(vec, [0, 1, 7, 8, 9]);
+    ///
+    /// // not consumed elements are dropped as well
+    /// let mut drain = vec.drain(1..4);
+    /// assert_eq!(drain.next_back(), Some(8));
+    /// drop(drain);
+    /// assert_eq!(vec, [0, 9]);
+    ///
+    /// vec.drain(..);
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, G> {
+        let start = range_start(&range);
+        let end = range_end(&range, self.len);
+        assert!(start <= end, "drain range start must not exceed end");
+        assert!(end <= self.len, "drain range end is out of bounds");
+        Drain::new(self, start, end)
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Removes the elements in the specified `range` from the vector without returning them.
+    ///
+    /// This is a cheaper alternative to `drain(range)` when the removed values are not needed:
+    /// the elements are never read out one by one; instead, the elements of the range are dropped in place,
+    /// piecewise as whole slices of the fragments, the tail of the vector is shifted left across the fragments
+    /// once by memory moves, and the fragments which become empty are released.
+    ///
+    /// Note that the memory locations of the elements after the range change;
+    /// just as they would with `remove`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.remove_range(2..7);
+    /// assert_eq!(vec, [0, 1, 7, 8, 9]);
+    ///
+    /// vec.remove_range(3..);
+    /// assert_eq!(vec, [0, 1, 7]);
+    /// assert_eq!(vec.fragments().len(), 1);
+    /// ```
+    pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let start = range_start(&range);
+        let end = range_end(&range, self.len);
+        assert!(start <= end, "range start must not exceed end");
+        assert!(end <= self.len, "range end is out of bounds");
+        if start < end {
+            // no element is yielded: the range is dropped by slices and the gap is closed once
+            Drain::new(self, start, end).finish();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn drain_without_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.push("a".to_string());
+            vec.truncate(0);
+
+            assert_eq!(vec.drain(..).count(), 0);
+            assert_eq!(vec.drain(0..0).count(), 0);
+            vec.remove_range(..);
+            assert_eq!(vec.splice(.., ["b".to_string()]).count(), 0);
+            assert_eq!(vec, ["b".to_string()]);
+
+            vec.truncate(0);
+            vec.push("c".to_string());
+            assert_eq!(vec, ["c".to_string()]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 157;
+            for (a, b) in [(0, 0), (0, n), (3, 4), (10, 100), (0, 33), (40, n), (n, n)] {
+                vec.clear();
+                vec.extend((0..n).map(|x| x.to_string()));
+                let mut expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+                let drained: Vec<_> = vec.drain(a..b).collect();
+                let expected_drained: Vec<_> = expected.drain(a..b).collect();
+                assert_eq!(drained, expected_drained);
+                assert_eq!(vec, expected);
+                assert_eq!(vec.len(), expected.len());
+
+                vec.push("x".to_string());
+                expected.push("x".to_string());
+                vec.insert(0, "y".to_string());
+                expected.insert(0, "y".to_string());
+                assert_eq!(vec, expected);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_partially_consumed() {
+        fn test<G: Growth>(mut vec: SplitVec<Rc<usize>, G>) {
+            let n = 64;
+            let rc = Rc::new(0);
+            vec.extend((0..n).map(|_| rc.clone()));
+
+            let mut drain = vec.drain(5..50);
+            assert_eq!(drain.len(), 45);
+            assert!(drain.next().is_some());
+            assert!(drain.next_back().is_some());
+            assert_eq!(drain.len(), 43);
+            drop(drain);
+
+            assert_eq!(vec.len(), n - 45);
+            assert_eq!(Rc::strong_count(&rc), n - 45 + 1);
+
+            vec.drain(..);
+            assert!(vec.is_empty());
+            assert_eq!(Rc::strong_count(&rc), 1);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_double_ended() {
+        let mut vec: SplitVec<_> = (0..100).collect();
+        let mut drain = vec.drain(10..90);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            match (drain.next(), drain.next_back()) {
+                (Some(x), Some(y)) => {
+                    front.push(x);
+                    back.push(y);
+                }
+                (Some(x), None) => front.push(x),
+                _ => break,
+            }
+        }
+        drop(drain);
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, (10..90).collect::<Vec<_>>());
+        assert_eq!(vec, (0..10).chain(90..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn drain_with_appended_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.extend(0..10);
+        vec.append((10..13).collect::<Vec<_>>());
+        vec.append((13..50).collect::<Vec<_>>());
+
+        let drained: Vec<_> = vec.drain(8..15).collect();
+        assert_eq!(drained, (8..15).collect::<Vec<_>>());
+        assert_eq!(vec, (0..8).chain(15..50).collect::<Vec<_>>());
+        for i in 0..vec.len() {
+            assert_eq!(vec.get(i), Some(&(if i < 8 { i } else { i + 7 })));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn drain_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.drain(5..11);
+    }
+
+    #[test]
+    fn remove_range() {
+        fn test<G: Growth>(mut vec: SplitVec<Rc<usize>, G>) {
+            let n = 97;
+            let tracked = Rc::new(0);
+            let ranges = [(0, 0), (0, n), (3, 4), (10, 60), (40, n), (n, n), (5, 9)];
+            for (a, b) in ranges {
+                vec.clear();
+                vec.extend((0..n).map(|_| tracked.clone()));
+                let num_fragments = vec.fragments().len();
+
+                vec.remove_range(a..b);
+                assert_eq!(vec.len(), n - (b - a));
+                assert_eq!(Rc::strong_count(&tracked), 1 + n - (b - a));
+                assert!(vec.fragments().len() <= num_fragments);
+            }
+
+            let mut expected: Vec<_> = (0..n).collect();
+            let mut values = SplitVec::with_linear_growth(3);
+            values.extend(0..n);
+            values.remove_range(11..50);
+            expected.drain(11..50);
+            assert_eq!(values, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn remove_range_without_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.push(1);
+            vec.truncate(0);
+            vec.remove_range(..);
+            vec.remove_range(0..0);
+            assert!(vec.is_empty());
+
+            vec.extend(0..10);
+            vec.remove_range(..10);
+            vec.remove_range(..);
+            assert!(vec.is_empty());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_range_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.remove_range(5..11);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::growth_observer::GrowthEvent;
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Removes the first `n` elements of the vector.
+    ///
+    /// If the growth strategy allows, such as the `Recursive` growth which allows fragments of arbitrary capacities,
+    /// the leading fragments which are entirely within the first `n` elements are dropped as a whole, which is
+    /// ***O(#fragments)***. Only the remaining elements of the fragment containing the `n`-th element are moved to
+    /// the front of their fragment; the elements of the other fragments are not moved.
+    /// This allows to use the vector as a growable FIFO queue without per-element removals.
+    ///
+    /// Otherwise, the growth strategy determines the capacities of the fragments by their positions, as `Doubling`
+    /// and `Linear` growth strategies do for constant time random access; and hence, the leading fragments cannot be
+    /// dropped. Instead, the first `n` elements are dropped in place and the remaining elements are shifted to the front
+    /// across the fragments once, similar to `remove_range(..n)`, which is ***O(len)***.
+    /// See [`Growth::accepts_arbitrary_fragment_capacities`].
+    ///
+    /// Note that the memory locations of the moved elements change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend(0..12);
+    /// assert_eq!(vec.fragments().len(), 2);
+    ///
+    /// let ptr_of_last = vec.last().unwrap() as *const i32;
+    ///
+    /// // the first fragment is dropped as a whole
+    /// vec.drop_front(4);
+    /// assert_eq!(vec, [4, 5, 6, 7, 8, 9, 10, 11]);
+    /// assert_eq!(vec.fragments().len(), 1);
+    /// assert_eq!(vec.last().unwrap() as *const i32, ptr_of_last);
+    ///
+    /// // remaining elements of the partially dropped fragment are moved to its front
+    /// vec.drop_front(2);
+    /// assert_eq!(vec, [6, 7, 8, 9, 10, 11]);
+    ///
+    /// // elements are shifted with growth strategies that cannot drop leading fragments
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.drop_front(3);
+    /// assert_eq!(vec, [3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// vec.drop_front(7);
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn drop_front(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot drop more elements than the length");
+
+        match self.growth.accepts_arbitrary_fragment_capacities() {
+            true => self.drop_leading_fragments(n),
+            false => self.remove_range(..n),
+        }
+    }
+
+    fn drop_leading_fragments(&mut self, n: usize) {
+        self.invalidate_stable_indices();
+
+        let Some((f, i)) = self.get_fragment_and_inner_indices(n) else {
+            self.clear();
+            return;
+        };
+
+        self.len -= n;
+        let dropped: Vec<_> = self.fragments.drain(..f).collect();
+        for (fragment_index, fragment) in dropped.iter().enumerate() {
+            self.on_growth_event(GrowthEvent::FragmentRemoved {
+                fragment_index,
+                capacity: fragment.capacity(),
+            });
+        }
+
+        let fragment = &mut self.fragments[0];
+        let len = fragment.len();
+        // SAFETY: the first `i` elements are dropped and the rest are moved to the front of the fragment;
+        // the length is set to zero beforehand, so that a panicking drop leaks rather than double drops
+        unsafe {
+            fragment.set_len(0);
+            let ptr = fragment.as_mut_ptr();
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(ptr, i));
+            core::ptr::copy(ptr.add(i), ptr, len - i);
+            fragment.set_len(len - i);
+        }
+
+        self.refresh_capacity();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::rc::Rc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn drop_front() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let tracked = Rc::new(0);
+            let mut rcs: SplitVec<_, G> = SplitVec::with_growth(vec.growth().clone());
+            let mut expected: Vec<usize> = Vec::new();
+
+            let mut next = 0;
+            for (num_push, num_drop) in [
+                (10, 0),
+                (10, 3),
+                (50, 17),
+                (0, 40),
+                (5, 10),
+                (100, 5),
+                (0, 100),
+            ] {
+                for _ in 0..num_push {
+                    vec.push(next);
+                    rcs.push(tracked.clone());
+                    expected.push(next);
+                    next += 1;
+                }
+
+                vec.drop_front(num_drop);
+                rcs.drop_front(num_drop);
+                expected.drain(..num_drop);
+
+                assert_eq!(vec, expected);
+                assert_eq!(Rc::strong_count(&tracked), 1 + rcs.len());
+                assert_eq!(
+                    vec.capacity(),
+                    vec.fragments().iter().map(|x| x.capacity()).sum()
+                );
+            }
+            assert!(vec.is_empty());
+
+            vec.extend(0..10);
+            assert_eq!(vec, (0..10).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drop_front_appended_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(alloc::vec![0, 1, 2]);
+        vec.append(alloc::vec![3, 4]);
+        vec.append(alloc::vec![5, 6, 7]);
+        let ptr = vec.fragments()[vec.fragments().len() - 1].as_ptr();
+
+        vec.drop_front(5);
+        assert_eq!(vec, [5, 6, 7]);
+        assert_eq!(vec.fragments().len(), 1);
+        assert_eq!(vec.fragments()[0].as_ptr(), ptr);
+    }
+
+    #[test]
+    #[should_panic]
+    fn drop_front_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.drop_front(11);
+    }
+}
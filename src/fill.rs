@@ -0,0 +1,156 @@
+use crate::range_helpers::{range_end, range_start};
+use crate::{Growth, SplitVec};
+use core::ops::RangeBounds;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Fills the vector with elements by cloning `value`.
+    ///
+    /// Each fragment is filled in bulk by `slice::fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// vec.fill(7);
+    /// assert_eq!(vec, [7, 7, 7, 7, 7, 7]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for fragment in &mut self.fragments {
+            fragment.fill(value.clone());
+        }
+    }
+
+    /// Fills the vector with elements returned by calling the closure `f` repeatedly, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// let mut next = 10;
+    /// vec.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(vec, [11, 12, 13, 14, 15, 16]);
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for fragment in &mut self.fragments {
+            fragment.fill_with(&mut f);
+        }
+    }
+
+    /// Fills the elements within the given `range` by cloning `value`.
+    ///
+    /// The slices of the fragments belonging to the range are obtained by `slices_mut`, and each slice is filled
+    /// in bulk by `slice::fill`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.fill_range(3..6, 0);
+    /// assert_eq!(vec, [0, 1, 2, 0, 0, 0, 6, 7, 8, 9]);
+    ///
+    /// vec.fill_range(8.., 1);
+    /// assert_eq!(vec, [0, 1, 2, 0, 0, 0, 6, 7, 1, 1]);
+    /// ```
+    pub fn fill_range<R>(&mut self, range: R, value: T)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let start = range_start(&range);
+        let end = range_end(&range, self.len);
+        assert!(start <= end, "fill range start must not exceed end");
+        assert!(end <= self.len, "fill range end is out of bounds");
+
+        for slice in self.slices_mut(start..end) {
+            slice.fill(value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn fill() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.fill("x".to_string());
+            assert!(vec.is_empty());
+
+            let n = 157;
+            vec.extend((0..n).map(|x| x.to_string()));
+            vec.fill("x".to_string());
+            assert_eq!(vec, (0..n).map(|_| "x".to_string()).collect::<Vec<_>>());
+
+            let mut i = 0;
+            vec.fill_with(|| {
+                i += 1;
+                i.to_string()
+            });
+            assert_eq!(vec, (1..=n).map(|x| x.to_string()).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn fill_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 157;
+            for a in [0, 1, 4, 12, 60, n - 1, n] {
+                for b in [a, a + 1, a + 7, a + 50, n] {
+                    let b = b.min(n);
+                    vec.clear();
+                    vec.extend(0..n);
+
+                    vec.fill_range(a..b, 1000);
+                    let expected: Vec<_> = (0..n)
+                        .map(|x| match (a..b).contains(&x) {
+                            true => 1000,
+                            false => x,
+                        })
+                        .collect();
+                    assert_eq!(vec, expected);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_range_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.fill_range(5..11, 0);
+    }
+}
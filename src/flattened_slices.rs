@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// An iterator over the elements of a sequence of slices, such as the slices returned by the `slices`
+/// methods of the [`SplitVec`](crate::SplitVec) and the [`ConcurrentSplitVec`](crate::ConcurrentSplitVec).
+///
+/// Unlike `slices.into_iter().flatten()`, this iterator knows its exact length;
+/// therefore, it can be passed to APIs requiring an `ExactSizeIterator`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_linear_growth(2);
+/// vec.extend(0..20);
+///
+/// let iter = FlattenedSlices::from(vec.slices(3..13));
+/// assert_eq!(iter.len(), 10);
+/// assert_eq!(iter.copied().collect::<Vec<_>>(), (3..13).collect::<Vec<_>>());
+///
+/// let con_vec = vec.into_concurrent();
+/// let iter = FlattenedSlices::from(con_vec.slices(15..));
+/// assert_eq!(iter.len(), 5);
+/// assert_eq!(iter.copied().collect::<Vec<_>>(), (15..20).collect::<Vec<_>>());
+/// ```
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenedSlices<'a, T> {
+    slices: alloc::vec::IntoIter<&'a [T]>,
+    inner: core::slice::Iter<'a, T>,
+    len: usize,
+}
+
+impl<T> Clone for FlattenedSlices<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slices: self.slices.clone(),
+            inner: self.inner.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> From<Vec<&'a [T]>> for FlattenedSlices<'a, T> {
+    fn from(slices: Vec<&'a [T]>) -> Self {
+        let len = slices.iter().map(|x| x.len()).sum();
+        Self {
+            slices: slices.into_iter(),
+            inner: [].iter(),
+            len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for FlattenedSlices<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.inner.next() {
+                self.len -= 1;
+                return Some(x);
+            }
+            self.inner = self.slices.next()?.iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for FlattenedSlices<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> FusedIterator for FlattenedSlices<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn flattened_slices() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 564;
+            vec.extend(0..n);
+
+            for (a, b) in [(0, 0), (0, 1), (3, 4), (3, 60), (10, n), (0, n), (n - 1, n)] {
+                let mut iter = FlattenedSlices::from(vec.slices(a..b));
+                for (i, expected) in (a..b).enumerate() {
+                    assert_eq!(iter.len(), b - a - i);
+                    assert_eq!(iter.next(), Some(&expected));
+                }
+                assert_eq!(iter.len(), 0);
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next(), None);
+            }
+
+            let iter = FlattenedSlices::from(alloc::vec![&[][..], &[1, 2][..], &[][..], &[3][..]]);
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.copied().collect::<Vec<_>>(), [1, 2, 3]);
+        }
+
+        test_all_growth_types!(test);
+    }
+}
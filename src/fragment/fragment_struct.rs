@@ -1,3 +1,5 @@
+use crate::Zeroable;
+use alloc::alloc::Layout;
 use alloc::vec::Vec;
 
 #[derive(Default)]
@@ -35,6 +37,56 @@ impl<T> Fragment<T> {
         Self { data }
     }
 
+    /// Creates a fragment with exactly the given `capacity` from the `vec`, reusing its allocation whenever possible.
+    ///
+    /// * If `vec.len() <= capacity`, the vec is shrunk or extended in place (when supported by the allocator)
+    ///   to have exactly the required capacity; the returned remainder is empty.
+    /// * Otherwise, the first `capacity` elements of the vec form the fragment while the remaining elements
+    ///   are returned as the remainder.
+    ///
+    /// Note that the capacity of a vector of a zero-sized type is always `usize::MAX`; hence, for zero-sized types,
+    /// the capacity of the fragment cannot be exactly `capacity`.
+    ///
+    /// To split a vec into several fragments, the remainder should not be split again repeatedly, since each split
+    /// moves all the remaining elements; rather, the elements should be moved chunk by chunk in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = Vec::with_capacity(10);
+    /// let (fragment, rest) = Fragment::<i32>::from_vec_with_exact_capacity(vec, 4);
+    /// assert_eq!(fragment.capacity(), 4);
+    /// assert!(rest.is_empty());
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4, 5];
+    /// let (fragment, rest) = Fragment::from_vec_with_exact_capacity(vec, 4);
+    /// assert_eq!(fragment.capacity(), 4);
+    /// assert_eq!(fragment, &[0, 1, 2, 3]);
+    /// assert_eq!(rest, &[4, 5]);
+    /// ```
+    pub fn from_vec_with_exact_capacity(mut vec: Vec<T>, capacity: usize) -> (Self, Vec<T>) {
+        let rest = match vec.len() > capacity {
+            true => vec.split_off(capacity),
+            false => Vec::new(),
+        };
+
+        match vec.capacity().cmp(&capacity) {
+            core::cmp::Ordering::Greater => vec.shrink_to(capacity),
+            core::cmp::Ordering::Less => vec.reserve_exact(capacity - vec.len()),
+            core::cmp::Ordering::Equal => {}
+        }
+
+        if vec.capacity() != capacity {
+            let mut exact = Vec::with_capacity(capacity);
+            exact.append(&mut vec);
+            vec = exact;
+        }
+
+        (Self { data: vec }, rest)
+    }
+
     /// Returns whether the fragment has room to push a new item or not.
     pub fn has_capacity_for_one(&self) -> bool {
         self.data.len() < self.data.capacity()
@@ -69,6 +121,32 @@ impl<T> Fragment<T> {
         fragments
     }
 
+    /// Creates a new fragment with length and capacity equal to the given `capacity`, where the memory is allocated
+    /// by `alloc_zeroed`; hence, all elements are zero.
+    pub(crate) fn new_zeroed(capacity: usize) -> Self
+    where
+        T: Zeroable,
+    {
+        let data = match capacity == 0 || core::mem::size_of::<T>() == 0 {
+            // SAFETY: T is Zeroable
+            true => (0..capacity)
+                .map(|_| unsafe { core::mem::zeroed() })
+                .collect(),
+            false => {
+                let layout = Layout::array::<T>(capacity).expect("capacity must not overflow");
+                // SAFETY: layout has a positive size; the all-zero bit pattern is a valid T
+                unsafe {
+                    let ptr = alloc::alloc::alloc_zeroed(layout) as *mut T;
+                    if ptr.is_null() {
+                        alloc::alloc::handle_alloc_error(layout);
+                    }
+                    Vec::from_raw_parts(ptr, capacity, capacity)
+                }
+            }
+        };
+        Self { data }
+    }
+
     /// Zeroes out all memory; i.e., positions in `0..fragment.capacity()`, of the fragment.
     #[inline(always)]
     pub(crate) unsafe fn zero(&mut self) {
@@ -1,4 +1,6 @@
 use crate::{Fragment, Growth, SplitVec};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 
 /// Converts self into a collection of [`Fragment`]s.
@@ -30,3 +32,78 @@ impl<T, G: Growth> IntoFragments<T> for SplitVec<T, G> {
         self.fragments.into_iter()
     }
 }
+
+impl<T> IntoFragments<T> for Box<[T]> {
+    /// The boxed slice is adopted as a fragment without copying its elements.
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+        [Fragment::from(Vec::from(self))].into_iter()
+    }
+}
+
+impl<T, const N: usize> IntoFragments<T> for [T; N] {
+    /// The elements of the array are moved into a single fragment with exact capacity.
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+        [Fragment::from(Vec::from(self))].into_iter()
+    }
+}
+
+impl<T> IntoFragments<T> for VecDeque<T> {
+    /// The buffer of the deque is adopted as a fragment without allocating; when the elements of the deque wrap around
+    /// its buffer, its two slices are made contiguous by moving the elements within the buffer.
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+        [Fragment::from(Vec::from(self))].into_iter()
+    }
+}
+
+impl<T: Clone> IntoFragments<T> for &[T] {
+    /// The elements of the slice are cloned into a single fragment with exact capacity.
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+        [Fragment::from(self.to_vec())].into_iter()
+    }
+}
+
+impl<T: Clone> IntoFragments<T> for Vec<&[T]> {
+    /// The elements of each slice are cloned into a separate fragment with exact capacity.
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+        self.into_iter().map(|x| Fragment::from(x.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::boxed::Box;
+    use alloc::collections::VecDeque;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn append_into_fragments_sources() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.push(0);
+
+        let boxed: Box<[usize]> = alloc::vec![1, 2].into_boxed_slice();
+        let boxed_ptr = boxed.as_ptr();
+        vec.append(boxed);
+        assert_eq!(vec.fragments()[1].as_ptr(), boxed_ptr);
+
+        vec.append([3, 4, 5]);
+
+        let mut deque = VecDeque::with_capacity(4);
+        deque.extend([7, 8]);
+        deque.push_front(6);
+        vec.append(deque);
+
+        vec.append(&[9, 10][..]);
+        vec.append(alloc::vec![&[11][..], &[12, 13][..]]);
+
+        let mut other = SplitVec::with_linear_growth(2);
+        other.extend(14..20);
+        vec.append(other);
+
+        assert_eq!(vec, (0..20).collect::<Vec<_>>());
+        assert_eq!(vec.fragments().len(), 1 + 1 + 1 + 1 + 1 + 2 + 2);
+
+        vec.push(20);
+        assert_eq!(vec, (0..21).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Growth, SplitVec};
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns a mutable reference to the first element of the vector; returns None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// assert!(vec.first_mut().is_none());
+    ///
+    /// vec.extend([1, 2, 3]);
+    /// if let Some(first) = vec.first_mut() {
+    ///     *first = 10;
+    /// }
+    /// assert_eq!(vec, [10, 2, 3]);
+    /// ```
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.fragments.first_mut().and_then(|x| x.first_mut())
+    }
+
+    /// Returns a mutable reference to the last element of the vector; returns None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// assert!(vec.last_mut().is_none());
+    ///
+    /// vec.extend([1, 2, 3]);
+    /// if let Some(last) = vec.last_mut() {
+    ///     *last = 30;
+    /// }
+    /// assert_eq!(vec, [1, 2, 30]);
+    /// ```
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.fragments.last_mut().and_then(|x| x.last_mut())
+    }
+
+    /// Returns mutable references to the elements at the given `indices` at once.
+    ///
+    /// Returns None if any of the indices is out of bounds, or if the same index is provided more than once;
+    /// hence, the returned references never alias.
+    /// Validation of disjointness takes ***O(N^2)*** time which is negligible for small `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// if let Some([a, b, c]) = vec.get_many_mut([0, 9, 5]) {
+    ///     core::mem::swap(a, b);
+    ///     *c *= 10;
+    /// }
+    /// assert_eq!(vec, [9, 1, 2, 3, 4, 50, 6, 7, 8, 0]);
+    ///
+    /// // out of bounds
+    /// assert!(vec.get_many_mut([1, 10]).is_none());
+    ///
+    /// // overlapping
+    /// assert!(vec.get_many_mut([1, 3, 1]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        let mut positions = [(0, 0); N];
+        for (k, index) in indices.iter().enumerate() {
+            if indices[..k].contains(index) {
+                return None;
+            }
+            positions[k] = self.get_fragment_and_inner_indices(*index)?;
+        }
+
+        let fragments = self.fragments.as_mut_ptr();
+        Some(positions.map(|(f, i)| {
+            // SAFETY: the positions are in bounds and distinct; hence, the references are valid and do not alias
+            unsafe { &mut *(*fragments.add(f)).as_mut_ptr().add(i) }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn first_last_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.first_mut().is_none());
+            assert!(vec.last_mut().is_none());
+
+            vec.push(42);
+            assert_eq!(vec.first_mut(), Some(&mut 42));
+            assert_eq!(vec.last_mut(), Some(&mut 42));
+
+            vec.extend(0..100);
+            *vec.first_mut().expect("is-some") += 1;
+            *vec.last_mut().expect("is-some") += 1;
+            assert_eq!(vec.first(), Some(&43));
+            assert_eq!(vec.last(), Some(&100));
+
+            vec.clear();
+            assert!(vec.first_mut().is_none());
+            assert!(vec.last_mut().is_none());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn get_many_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.get_many_mut([0]).is_none());
+            assert!(vec.get_many_mut([]).is_some());
+
+            let n = 333;
+            vec.extend(0..n);
+
+            let indices = [0, 1, 4, 5, 17, 100, 200, n - 1];
+            let refs = vec.get_many_mut(indices).expect("is-some");
+            for (r, i) in refs.into_iter().zip(indices) {
+                assert_eq!(*r, i);
+                *r += 1000;
+            }
+
+            let expected: Vec<_> = (0..n)
+                .map(|x| match indices.contains(&x) {
+                    true => x + 1000,
+                    false => x,
+                })
+                .collect();
+            assert_eq!(vec, expected);
+
+            assert!(vec.get_many_mut([3, n]).is_none());
+            assert!(vec.get_many_mut([3, 4, 3]).is_none());
+            assert!(vec.get_many_mut([7, 7]).is_none());
+        }
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Returns a mutable reference to the element at the given `index`.
+    ///
+    /// If the `index` is out of bounds, the vector is first extended with values created by the `fill`
+    /// function until its length becomes `index + 1`; and then, a mutable reference to the last element is returned.
+    ///
+    /// This is convenient for index-keyed storages which grow as new keys are observed.
+    /// Note that growth of the split vector never moves the existing elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// vec.push(7);
+    ///
+    /// *vec.get_or_extend_with(0, || 0) += 1;
+    /// assert_eq!(vec, &[8]);
+    ///
+    /// *vec.get_or_extend_with(3, || 0) = 42;
+    /// assert_eq!(vec, &[8, 0, 0, 42]);
+    /// ```
+    pub fn get_or_extend_with<F>(&mut self, index: usize, mut fill: F) -> &mut T
+    where
+        F: FnMut() -> T,
+    {
+        while self.len <= index {
+            self.push(fill());
+        }
+
+        // SAFETY: index < self.len is guaranteed by the loop above
+        unsafe { self.get_unchecked_mut(index) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn get_or_extend_with() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut num_calls = 0;
+            let mut fill = || {
+                num_calls += 1;
+                0
+            };
+
+            *vec.get_or_extend_with(0, &mut fill) = 10;
+            assert_eq!(vec, &[10]);
+
+            *vec.get_or_extend_with(0, &mut fill) += 1;
+            assert_eq!(vec, &[11]);
+
+            *vec.get_or_extend_with(99, &mut fill) = 99;
+            assert_eq!(vec.len(), 100);
+            assert_eq!(num_calls, 100);
+
+            let mut expected: Vec<_> = (0..100).map(|_| 0).collect();
+            expected[0] = 11;
+            expected[99] = 99;
+            assert_eq!(vec, expected);
+
+            for i in 0..100 {
+                *vec.get_or_extend_with(i, || unreachable!()) = i;
+            }
+            assert_eq!(vec, (0..100).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+}
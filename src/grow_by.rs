@@ -0,0 +1,195 @@
+use crate::{ConcurrentSplitVec, Growth, GrowthWithConstantTimeAccess, SplitVec};
+use core::mem::MaybeUninit;
+use orx_pinned_vec::ConcurrentPinnedVec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Makes sure that the vector has capacity for `n` more elements, and returns an iterator of pointers to the
+    /// positions `len..(len + n)` of the vector.
+    ///
+    /// The capacity is provided by [`SplitVec::reserve`]; hence, the positions are the spare capacity of the last
+    /// fragment followed by the reserved fragments, which are the ones that are returned by
+    /// [`SplitVec::spare_capacity_mut`]. This allows writing a batch of elements directly to their destinations
+    /// without checking the capacity for each element, after which the elements are marked as initialized by the
+    /// unsafe `set_len` method of the vector which adds the required reserved fragments to the vector.
+    ///
+    /// The pointers remain valid until the fragments are removed, or the reserved fragments are released; such as,
+    /// by removing elements or changing the growth strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend([0, 1, 2]);
+    ///
+    /// for (i, ptr) in vec.grow_by(5).enumerate() {
+    ///     unsafe { ptr.write(10 + i) };
+    /// }
+    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(vec.reserved_capacity(), 8);
+    ///
+    /// unsafe { vec.set_len(8) };
+    /// assert_eq!(vec, [0, 1, 2, 10, 11, 12, 13, 14]);
+    /// assert_eq!(vec.capacity(), 4 + 8);
+    /// ```
+    pub fn grow_by(&mut self, n: usize) -> impl ExactSizeIterator<Item = *mut T> + '_ {
+        self.reserve(n);
+        PtrIter {
+            slices: self.spare_capacity_mut(),
+            current: (core::ptr::null_mut(), 0, 0),
+            remaining: n,
+        }
+    }
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+    /// Makes sure that the vector has capacity for the positions `begin..(begin + n)`, and returns an iterator of
+    /// pointers to these positions.
+    ///
+    /// This is the concurrent counterpart of [`SplitVec::grow_by`]: a concurrent collection reserving a batch of
+    /// positions, such as by incrementing its length atomically, can write the elements of the batch directly
+    /// to the returned pointers without checking the capacity for each element.
+    ///
+    /// The vector is grown by [`ConcurrentPinnedVec::grow_to`]; hence, the same synchronization requirements apply.
+    /// Returns an error if `begin + n` exceeds the maximum capacity of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth_and_fragments_capacity(32);
+    /// vec.extend([0, 1, 2]);
+    /// let con_vec = vec.into_concurrent();
+    ///
+    /// for (i, ptr) in con_vec.grow_by(3, 6).unwrap().enumerate() {
+    ///     unsafe { ptr.write(10 + i) };
+    /// }
+    /// assert_eq!(con_vec.capacity(), 12);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(9) };
+    /// assert_eq!(vec, [0, 1, 2, 10, 11, 12, 13, 14, 15]);
+    /// ```
+    pub fn grow_by(
+        &self,
+        begin: usize,
+        n: usize,
+    ) -> Result<impl ExactSizeIterator<Item = *mut T> + '_, orx_pinned_vec::PinnedVecGrowthError>
+    {
+        let end = begin.saturating_add(n);
+        self.grow_to(end)?;
+        // SAFETY: the capacity covers `end` by the growth above
+        Ok(unsafe { self.ptr_iter_strided(begin, 1, end) })
+    }
+}
+
+/// Iterator over the pointers to consecutive positions within the spare capacity slices of fragments.
+struct PtrIter<'a, T: 'a, I: Iterator<Item = &'a mut [MaybeUninit<T>]>> {
+    slices: I,
+    current: (*mut T, usize, usize),
+    remaining: usize,
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a mut [MaybeUninit<T>]>> Iterator for PtrIter<'a, T, I> {
+    type Item = *mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        while self.current.1 == self.current.2 {
+            let slice = self.slices.next()?;
+            self.current = (slice.as_mut_ptr() as *mut T, 0, slice.len());
+        }
+
+        let (ptr, a, _) = &mut self.current;
+        // SAFETY: a is within the spare capacity slice of the fragment
+        let p = unsafe { ptr.add(*a) };
+        *a += 1;
+        self.remaining -= 1;
+        Some(p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a mut [MaybeUninit<T>]>> ExactSizeIterator
+    for PtrIter<'a, T, I>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn grow_by() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let mut expected = Vec::new();
+            for n in [0, 1, 3, 4, 17, 100, 1033] {
+                let len = vec.len();
+                let ptrs = vec.grow_by(n);
+                assert_eq!(ptrs.len(), n);
+                for (i, ptr) in ptrs.enumerate() {
+                    unsafe { ptr.write((len + i).to_string()) };
+                }
+                assert!(vec.capacity() + vec.reserved_capacity() >= len + n);
+                unsafe { vec.set_len(len + n) };
+
+                expected.extend((len..(len + n)).map(|x| x.to_string()));
+                assert_eq!(vec, expected);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn grow_by_uses_reserved_fragments() {
+        let mut vec: SplitVec<usize> = SplitVec::with_doubling_growth();
+        vec.reserve(30);
+        let reserved = vec.reserved_capacity();
+
+        let ptrs: Vec<_> = vec.grow_by(10).collect();
+        assert_eq!(vec.capacity() + vec.reserved_capacity(), 4 + reserved);
+        assert_eq!(ptrs[4], vec.reserved[0].as_ptr() as *mut usize);
+    }
+
+    #[test]
+    fn grow_by_keeps_fragments_full() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for n in [1, 3, 10, 100] {
+                vec.clear();
+                vec.extend(0..3);
+
+                // grown but not used
+                let _ = vec.grow_by(n);
+                for i in 3..(3 + 2 * n) {
+                    vec.push(i);
+                    assert_eq!(vec.get(i), Some(&i));
+                }
+                assert_eq!(vec, (0..(3 + 2 * n)).collect::<Vec<_>>());
+
+                // grown and used
+                let len = vec.len();
+                for (i, ptr) in vec.grow_by(n).enumerate() {
+                    unsafe { ptr.write(len + i) };
+                }
+                unsafe { vec.set_len(len + n) };
+                vec.push(len + n);
+                for i in 0..=(len + n) {
+                    assert_eq!(vec.get(i), Some(&i));
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
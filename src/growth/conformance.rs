@@ -0,0 +1,396 @@
+use crate::{Fragment, Growth, GrowthWithConstantTimeAccess};
+use alloc::{format, string::String, vec::Vec};
+
+/// Validates the consistency of the methods of the growth strategy `growth` for a split vector growing up to `upto` elements.
+///
+/// Returns an error describing the first inconsistency found, if any.
+///
+/// This utility is intended to be used in tests of custom [`Growth`] implementations;
+/// it allocates fragments with a total capacity of at least `upto` bytes and calls each method of the growth
+/// O(`upto`) times. Therefore, it runs in O(`upto`) time for growth strategies with constant time methods such as
+/// [`Doubling`], and in O(`upto` * F) time for strategies whose methods iterate over the F fragments such as
+/// [`Recursive`], or which rely on the default implementation of [`Growth::maximum_concurrent_capacity`].
+///
+/// [`Doubling`]: crate::Doubling
+/// [`Recursive`]: crate::Recursive
+///
+/// The following are validated:
+/// * all fragment capacities are positive and the first one equals `first_fragment_capacity`;
+/// * `new_fragment_capacity` agrees with `new_fragment_capacity_from`;
+/// * `maximum_concurrent_capacity` equals the total capacity of the fragments that would be allocated;
+/// * `required_fragments_len` returns the minimum number of fragments to reach the requested capacity;
+/// * `get_fragment_and_inner_indices` and `get_ptr` locate each element correctly.
+///
+/// See [`growth_with_constant_time_access_conformance_check`] to additionally validate the
+/// constant time access methods.
+///
+/// [`Doubling`]: crate::Doubling
+/// [`Recursive`]: crate::Recursive
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// assert_eq!(growth_conformance_check(&Recursive, 1000), Ok(()));
+///
+/// // a buggy growth claiming that fragments are preallocated
+/// #[derive(Clone)]
+/// struct Buggy;
+///
+/// impl PseudoDefault for Buggy {
+///     fn pseudo_default() -> Self {
+///         Buggy
+///     }
+/// }
+///
+/// impl Growth for Buggy {
+///     fn new_fragment_capacity_from(
+///         &self,
+///         _: impl ExactSizeIterator<Item = usize>,
+///     ) -> usize {
+///         4
+///     }
+///
+///     fn maximum_concurrent_capacity<T>(&self, _: &[Fragment<T>], fragments_capacity: usize) -> usize {
+///         8 * fragments_capacity
+///     }
+/// }
+///
+/// assert!(growth_conformance_check(&Buggy, 1000).is_err());
+/// ```
+pub fn growth_conformance_check<G: Growth>(growth: &G, upto: usize) -> Result<(), String> {
+    let fragments = full_fragments(growth, upto)?;
+    check_growth(growth, &fragments)
+}
+
+/// Validates the methods of the `growth` given the full `fragments` that it would allocate.
+fn check_growth<G: Growth>(growth: &G, fragments: &[Fragment<u8>]) -> Result<(), String> {
+    let cumulative_capacities: Vec<usize> = core::iter::once(0)
+        .chain(fragments.iter().scan(0, |sum, x| {
+            *sum += x.capacity();
+            Some(*sum)
+        }))
+        .collect();
+    let num_fragments_total = fragments.len();
+
+    // maximum_concurrent_capacity
+    let mut checks = Vec::with_capacity(3 * num_fragments_total + 1);
+    for num_fragments in 0..=num_fragments_total {
+        checks.push((num_fragments, num_fragments));
+        if num_fragments < num_fragments_total {
+            checks.push((num_fragments, num_fragments + 1));
+            checks.push((num_fragments, num_fragments_total));
+        }
+    }
+    for (num_fragments, fragments_capacity) in checks {
+        let expected_max_capacity = cumulative_capacities[fragments_capacity];
+        let max_capacity =
+            growth.maximum_concurrent_capacity(&fragments[..num_fragments], fragments_capacity);
+        if max_capacity != expected_max_capacity {
+            return Err(format!(
+                "maximum_concurrent_capacity with {} fragments and fragments capacity of {} is {} while the fragment capacities sum up to {}.",
+                num_fragments, fragments_capacity, max_capacity, expected_max_capacity
+            ));
+        }
+    }
+
+    // required_fragments_len
+    let mut expected = 0;
+    let len = cumulative_capacities[num_fragments_total];
+    for maximum_capacity in 0..=len {
+        while cumulative_capacities[expected] < maximum_capacity {
+            expected += 1;
+        }
+
+        let is_check_point = maximum_capacity <= 1
+            || cumulative_capacities[expected] - maximum_capacity <= 1
+            || maximum_capacity - cumulative_capacities[expected.saturating_sub(1)] <= 1;
+        if !is_check_point {
+            continue;
+        }
+
+        for num_fragments in [0, expected.saturating_sub(1), expected] {
+            let required =
+                growth.required_fragments_len(&fragments[..num_fragments], maximum_capacity)?;
+            if required != expected {
+                return Err(format!(
+                    "required_fragments_len for maximum capacity of {} with {} fragments is {} while {} fragments are required.",
+                    maximum_capacity, num_fragments, required, expected
+                ));
+            }
+        }
+    }
+
+    // get_fragment_and_inner_indices & get_ptr
+    let mut idx = 0;
+    for (f, fragment) in fragments.iter().enumerate() {
+        for i in 0..fragment.len() {
+            let indices = growth.get_fragment_and_inner_indices(len, fragments, idx);
+            if indices != Some((f, i)) {
+                return Err(format!(
+                    "get_fragment_and_inner_indices for index {} is {:?} while expected Some(({}, {})).",
+                    idx, indices, f, i
+                ));
+            }
+
+            let ptr = Growth::get_ptr(growth, fragments, idx);
+            if ptr != Some(unsafe { fragment.as_ptr().add(i) }) {
+                return Err(format!(
+                    "get_ptr for index {} points to a wrong position.",
+                    idx
+                ));
+            }
+
+            idx += 1;
+        }
+    }
+
+    let indices = growth.get_fragment_and_inner_indices(len, fragments, len);
+    if indices.is_some() {
+        return Err(format!(
+            "get_fragment_and_inner_indices for out of bounds index {} is {:?} while expected None.",
+            len, indices
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the consistency of the methods of the growth strategy `growth` which provides constant time access
+/// for a split vector growing up to `upto` elements.
+///
+/// In addition to the checks of [`growth_conformance_check`], validates that `fragment_capacity_of` and
+/// `get_fragment_and_inner_indices_unchecked` agree with the fragments that would be allocated.
+/// Since these methods are used without bounds checks by the concurrent split vector, inconsistencies here
+/// would otherwise lead to undefined behavior.
+///
+/// The time complexity is the same as that of [`growth_conformance_check`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// assert_eq!(growth_with_constant_time_access_conformance_check(&Doubling, 1000), Ok(()));
+/// assert_eq!(growth_with_constant_time_access_conformance_check(&Linear::new(4), 1000), Ok(()));
+/// ```
+pub fn growth_with_constant_time_access_conformance_check<G: GrowthWithConstantTimeAccess>(
+    growth: &G,
+    upto: usize,
+) -> Result<(), String> {
+    let fragments = full_fragments(growth, upto)?;
+    check_growth(growth, &fragments)?;
+
+    let mut idx = 0;
+    for (f, fragment) in fragments.iter().enumerate() {
+        let capacity = growth.fragment_capacity_of(f);
+        if capacity != fragment.capacity() {
+            return Err(format!(
+                "fragment_capacity_of fragment {} is {} while it is allocated with capacity {}.",
+                f,
+                capacity,
+                fragment.capacity()
+            ));
+        }
+
+        for i in 0..fragment.len() {
+            let indices = growth.get_fragment_and_inner_indices_unchecked(idx);
+            if indices != (f, i) {
+                return Err(format!(
+                    "get_fragment_and_inner_indices_unchecked for index {} is {:?} while expected ({}, {}).",
+                    idx, indices, f, i
+                ));
+            }
+
+            let ptr = GrowthWithConstantTimeAccess::get_ptr(growth, &fragments, idx);
+            if ptr != Some(unsafe { fragment.as_ptr().add(i) }) {
+                return Err(format!(
+                    "get_ptr for index {} points to a wrong position.",
+                    idx
+                ));
+            }
+
+            idx += 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the capacities of the `fragments` follow the `growth` and that none of the fragments but the last one
+/// has room; both are skipped if the growth accepts arbitrary fragment capacities.
+pub(crate) fn fragments_conformance_check<T, G: Growth>(
+    growth: &G,
+    fragments: &[Fragment<T>],
+) -> Result<(), String> {
+    if growth.accepts_arbitrary_fragment_capacities() {
+        return Ok(());
+    }
+
+    for (f, fragment) in fragments.iter().enumerate() {
+        let capacity =
+            growth.new_fragment_capacity_from(fragments[..f].iter().map(|x| x.capacity()));
+        if fragment.capacity() != capacity {
+            return Err(format!(
+                "capacity of fragment {} is {} while the growth would create it with capacity {}.",
+                f,
+                fragment.capacity(),
+                capacity
+            ));
+        }
+
+        if f + 1 < fragments.len() && fragment.room() > 0 {
+            return Err(format!(
+                "fragment {} has room while it is not the last fragment.",
+                f
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates full fragments as the growth would allocate until the total capacity reaches `upto`.
+fn full_fragments<G: Growth>(growth: &G, upto: usize) -> Result<Vec<Fragment<u8>>, String> {
+    let mut fragments: Vec<Fragment<u8>> = Vec::new();
+    let mut capacities: Vec<usize> = Vec::new();
+    let mut total_capacity = 0;
+
+    while total_capacity < upto.max(1) {
+        let capacity = growth.new_fragment_capacity_from(capacities.iter().copied());
+        if capacity == 0 {
+            return Err(format!("capacity of fragment {} is zero.", fragments.len()));
+        }
+
+        if fragments.is_empty() && capacity != growth.first_fragment_capacity() {
+            return Err(format!(
+                "first_fragment_capacity is {} while new_fragment_capacity_from returns {} for the first fragment.",
+                growth.first_fragment_capacity(),
+                capacity
+            ));
+        }
+
+        let from_fragments = growth.new_fragment_capacity(&fragments);
+        if from_fragments != capacity {
+            return Err(format!(
+                "new_fragment_capacity is {} while new_fragment_capacity_from is {} for fragment {}.",
+                from_fragments,
+                capacity,
+                fragments.len()
+            ));
+        }
+
+        let mut fragment = Fragment::new(capacity);
+        fragment.resize(capacity, 0);
+        fragments.push(fragment);
+        capacities.push(capacity);
+        total_capacity += capacity;
+    }
+
+    Ok(fragments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Doubling, Linear, Recursive};
+    use orx_pseudo_default::PseudoDefault;
+
+    #[test]
+    fn growths_of_crate_conform() {
+        for upto in [0, 1, 4, 5, 100, 257] {
+            assert_eq!(growth_conformance_check(&Recursive, upto), Ok(()));
+            assert_eq!(growth_conformance_check(&Doubling, upto), Ok(()));
+            assert_eq!(
+                growth_with_constant_time_access_conformance_check(&Doubling, upto),
+                Ok(())
+            );
+            for exponent in [1, 2, 5] {
+                assert_eq!(
+                    growth_with_constant_time_access_conformance_check(
+                        &Linear::new(exponent),
+                        upto
+                    ),
+                    Ok(())
+                );
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct WrongIndices;
+
+    impl PseudoDefault for WrongIndices {
+        fn pseudo_default() -> Self {
+            WrongIndices
+        }
+    }
+
+    impl Growth for WrongIndices {
+        fn new_fragment_capacity_from(&self, _: impl ExactSizeIterator<Item = usize>) -> usize {
+            4
+        }
+    }
+
+    impl GrowthWithConstantTimeAccess for WrongIndices {
+        fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+            (element_index / 8, element_index % 8)
+        }
+
+        fn fragment_capacity_of(&self, _: usize) -> usize {
+            4
+        }
+    }
+
+    #[test]
+    fn wrong_indices_detected() {
+        assert_eq!(growth_conformance_check(&WrongIndices, 100), Ok(()));
+
+        let result = growth_with_constant_time_access_conformance_check(&WrongIndices, 100);
+        assert!(result.is_err());
+    }
+
+    #[derive(Clone)]
+    struct WrongFirst;
+
+    impl PseudoDefault for WrongFirst {
+        fn pseudo_default() -> Self {
+            WrongFirst
+        }
+    }
+
+    impl Growth for WrongFirst {
+        fn first_fragment_capacity(&self) -> usize {
+            2
+        }
+
+        fn new_fragment_capacity_from(&self, _: impl ExactSizeIterator<Item = usize>) -> usize {
+            4
+        }
+    }
+
+    #[test]
+    fn wrong_first_fragment_capacity_detected() {
+        assert!(growth_conformance_check(&WrongFirst, 100).is_err());
+    }
+
+    #[test]
+    fn fragments_conformance() {
+        let mut fragments: Vec<Fragment<usize>> = Vec::new();
+        for capacity in [4, 8, 16] {
+            let mut fragment = Fragment::new(capacity);
+            fragment.resize(capacity, 0);
+            fragments.push(fragment);
+        }
+        assert_eq!(fragments_conformance_check(&Doubling, &fragments), Ok(()));
+        assert!(fragments_conformance_check(&Linear::new(2), &fragments).is_err());
+
+        fragments[1].pop();
+        assert!(fragments_conformance_check(&Doubling, &fragments).is_err());
+        assert_eq!(fragments_conformance_check(&Recursive, &fragments), Ok(()));
+
+        fragments[0] = Fragment::new(3);
+        assert!(fragments_conformance_check(&Doubling, &fragments).is_err());
+    }
+}
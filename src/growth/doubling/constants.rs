@@ -1,97 +1,106 @@
 pub(super) const FIRST_FRAGMENT_CAPACITY_POW: usize = 2;
-pub(super) const FIRST_FRAGMENT_CAPACITY: usize = usize::pow(2, FIRST_FRAGMENT_CAPACITY_POW as u32);
-pub(super) const SIZE_USIZE: usize = core::mem::size_of::<usize>() * 8;
+pub(super) const FIRST_FRAGMENT_CAPACITY: usize = 1 << FIRST_FRAGMENT_CAPACITY_POW;
+pub(super) const SIZE_USIZE: usize = usize::BITS as usize;
 pub(super) const OFFSET_FRAGMENT_IDX: usize = SIZE_USIZE - FIRST_FRAGMENT_CAPACITY_POW - 1;
 
+/// Number of fragments of the doubling growth on a target with the given pointer width in `bits`.
+///
+/// The total capacity of all fragments is `2^(len + 2) - 4`, which must not exceed `isize::MAX` of the target;
+/// hence, the length is bounded by `bits - 3`. It is further capped at 32 fragments on wider targets.
+const fn capacities_len_for(bits: usize) -> usize {
+    let len = bits.saturating_sub(FIRST_FRAGMENT_CAPACITY_POW + 1);
+    match len < 32 {
+        true => len,
+        false => 32,
+    }
+}
+
+const CAPACITIES_LEN: usize = capacities_len_for(SIZE_USIZE);
+
+const fn pow2(exponent: usize) -> usize {
+    match 2usize.checked_pow(exponent as u32) {
+        Some(x) => x,
+        None => usize::MAX,
+    }
+}
+
 const fn fragment_capacity(fragment_idx: usize) -> usize {
-    usize::pow(2, (fragment_idx + FIRST_FRAGMENT_CAPACITY_POW) as u32)
+    pow2(fragment_idx + FIRST_FRAGMENT_CAPACITY_POW)
 }
 
 const fn cumulative_capacity(fragment_idx: usize) -> usize {
-    usize::pow(2, (fragment_idx + FIRST_FRAGMENT_CAPACITY_POW + 1) as u32) - FIRST_FRAGMENT_CAPACITY
+    pow2(fragment_idx + FIRST_FRAGMENT_CAPACITY_POW + 1).saturating_sub(FIRST_FRAGMENT_CAPACITY)
 }
 
-const fn capacities_len() -> usize {
-    #[cfg(target_pointer_width = "32")]
-    return 30;
+const fn capacities() -> [usize; CAPACITIES_LEN] {
+    let mut capacities = [0; CAPACITIES_LEN];
+    let mut f = 0;
+    while f < CAPACITIES_LEN {
+        capacities[f] = fragment_capacity(f);
+        f += 1;
+    }
+    capacities
+}
 
-    #[cfg(target_pointer_width = "64")]
-    return 33;
+const fn cumulative_capacities() -> [usize; CAPACITIES_LEN + 1] {
+    let mut cumulative_capacities = [0; CAPACITIES_LEN + 1];
+    let mut f = 0;
+    while f < CAPACITIES_LEN {
+        cumulative_capacities[f + 1] = cumulative_capacity(f);
+        f += 1;
+    }
+    cumulative_capacities
 }
 
-pub(super) const CAPACITIES: [usize; capacities_len() - 1] = [
-    fragment_capacity(0),
-    fragment_capacity(1),
-    fragment_capacity(2),
-    fragment_capacity(3),
-    fragment_capacity(4),
-    fragment_capacity(5),
-    fragment_capacity(6),
-    fragment_capacity(7),
-    fragment_capacity(8),
-    fragment_capacity(9),
-    fragment_capacity(10),
-    fragment_capacity(11),
-    fragment_capacity(12),
-    fragment_capacity(13),
-    fragment_capacity(14),
-    fragment_capacity(15),
-    fragment_capacity(16),
-    fragment_capacity(17),
-    fragment_capacity(18),
-    fragment_capacity(19),
-    fragment_capacity(20),
-    fragment_capacity(21),
-    fragment_capacity(22),
-    fragment_capacity(23),
-    fragment_capacity(24),
-    fragment_capacity(25),
-    fragment_capacity(26),
-    fragment_capacity(27),
-    fragment_capacity(28),
-    #[cfg(target_pointer_width = "64")]
-    fragment_capacity(29),
-    #[cfg(target_pointer_width = "64")]
-    fragment_capacity(30),
-    #[cfg(target_pointer_width = "64")]
-    fragment_capacity(31),
-];
+/// Capacities of the fragments of the doubling growth; i.e., 4, 8, 16, etc.
+pub(super) const CAPACITIES: [usize; CAPACITIES_LEN] = capacities();
+
+/// Cumulative capacities of the fragments of the doubling growth, starting with 0; i.e., 0, 4, 12, 28, etc.
+pub(super) const CUMULATIVE_CAPACITIES: [usize; CAPACITIES_LEN + 1] = cumulative_capacities();
 
-pub(super) const CUMULATIVE_CAPACITIES: [usize; capacities_len()] = [
-    0,
-    cumulative_capacity(0),
-    cumulative_capacity(1),
-    cumulative_capacity(2),
-    cumulative_capacity(3),
-    cumulative_capacity(4),
-    cumulative_capacity(5),
-    cumulative_capacity(6),
-    cumulative_capacity(7),
-    cumulative_capacity(8),
-    cumulative_capacity(9),
-    cumulative_capacity(10),
-    cumulative_capacity(11),
-    cumulative_capacity(12),
-    cumulative_capacity(13),
-    cumulative_capacity(14),
-    cumulative_capacity(15),
-    cumulative_capacity(16),
-    cumulative_capacity(17),
-    cumulative_capacity(18),
-    cumulative_capacity(19),
-    cumulative_capacity(20),
-    cumulative_capacity(21),
-    cumulative_capacity(22),
-    cumulative_capacity(23),
-    cumulative_capacity(24),
-    cumulative_capacity(25),
-    cumulative_capacity(26),
-    cumulative_capacity(27),
-    cumulative_capacity(28),
-    #[cfg(target_pointer_width = "64")]
-    cumulative_capacity(29),
-    #[cfg(target_pointer_width = "64")]
-    cumulative_capacity(30),
-    #[cfg(target_pointer_width = "64")]
-    cumulative_capacity(31),
-];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacities_len_fits_in_target_pointer_width() {
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(CAPACITIES.len(), 32);
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(CAPACITIES.len(), 29);
+        #[cfg(target_pointer_width = "16")]
+        assert_eq!(CAPACITIES.len(), 13);
+
+        for (bits, expected_len) in [(16, 13), (32, 29), (64, 32), (128, 32)] {
+            let len = capacities_len_for(bits);
+            assert_eq!(len, expected_len);
+
+            let isize_max = (1u128 << (bits - 1)) - 1;
+            let total_capacity: u128 = (0..len).map(|f| 1u128 << (f + 2)).sum();
+            assert!(total_capacity <= isize_max);
+        }
+    }
+
+    #[test]
+    fn tables() {
+        let mut cumulative = 0;
+        assert_eq!(CUMULATIVE_CAPACITIES[0], 0);
+        for (f, capacity) in CAPACITIES.iter().enumerate() {
+            assert_eq!(*capacity, 4 << f);
+            cumulative += capacity;
+            assert_eq!(CUMULATIVE_CAPACITIES[f + 1], cumulative);
+        }
+        assert!(cumulative <= isize::MAX as usize);
+    }
+
+    #[test]
+    fn saturating_helpers() {
+        assert_eq!(pow2(SIZE_USIZE - 1), 1 << (SIZE_USIZE - 1));
+        assert_eq!(pow2(SIZE_USIZE), usize::MAX);
+        assert_eq!(fragment_capacity(SIZE_USIZE), usize::MAX);
+        assert_eq!(
+            cumulative_capacity(SIZE_USIZE),
+            usize::MAX - FIRST_FRAGMENT_CAPACITY
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::constants::*;
 use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
-use crate::{Fragment, SplitVec};
+use crate::{Fragment, GrowthError, SplitVec};
 use alloc::string::String;
 use orx_pseudo_default::PseudoDefault;
 
@@ -66,7 +66,10 @@ impl Growth for Doubling {
         &self,
         fragment_capacities: impl ExactSizeIterator<Item = usize>,
     ) -> usize {
-        fragment_capacities.last().map(|x| x * 2).unwrap_or(4)
+        fragment_capacities
+            .last()
+            .map(|x| x.saturating_mul(2))
+            .unwrap_or(4)
     }
 
     #[inline(always)]
@@ -134,7 +137,18 @@ impl Growth for Doubling {
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
 
-        CUMULATIVE_CAPACITIES[fragments_capacity]
+        // fragments beyond the capacity table can never be allocated
+        let f = fragments_capacity.min(CUMULATIVE_CAPACITIES.len() - 1);
+        CUMULATIVE_CAPACITIES[f]
+    }
+
+    /// Returns the number of fragments of the capacity table of the doubling growth.
+    ///
+    /// On 64-bit targets, there exist 32 fragments whose cumulative capacities are tabulated in 33 entries
+    /// starting with zero. On 32-bit targets, the table is limited to 29 fragments so that the total capacity
+    /// fits in `isize::MAX`.
+    fn max_fragments(&self) -> Option<usize> {
+        Some(CAPACITIES.len())
     }
 
     /// Returns the number of fragments with this growth strategy in order to be able to reach a capacity of `maximum_capacity` of elements.
@@ -163,14 +177,35 @@ impl Growth for Doubling {
 }
 
 impl GrowthWithConstantTimeAccess for Doubling {
+    /// ***O(1)*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment).
+    ///
+    /// # Panics
+    ///
+    /// Might panic if `element_index` is beyond the maximum capacity of the capacity table;
+    /// see [`GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_checked`] for the fallible variant.
     #[inline(always)]
     fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
-        let element_index_offset = element_index + FIRST_FRAGMENT_CAPACITY;
+        let element_index_offset = element_index.saturating_add(FIRST_FRAGMENT_CAPACITY);
         let leading_zeros = usize::leading_zeros(element_index_offset) as usize;
         let f = OFFSET_FRAGMENT_IDX - leading_zeros;
         (f, element_index - CUMULATIVE_CAPACITIES[f])
     }
 
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_checked(
+        &self,
+        element_index: usize,
+    ) -> Result<(usize, usize), GrowthError> {
+        let maximum_capacity = CUMULATIVE_CAPACITIES[CAPACITIES.len()];
+        match element_index < maximum_capacity {
+            true => Ok(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => Err(GrowthError::IndexBeyondMaximumCapacity {
+                element_index,
+                maximum_capacity,
+            }),
+        }
+    }
+
     fn fragment_capacity_of(&self, fragment_index: usize) -> usize {
         CAPACITIES[fragment_index]
     }
@@ -229,7 +264,7 @@ impl<T> SplitVec<T, Doubling> {
     /// ```
     pub fn with_doubling_growth() -> Self {
         let fragments = Fragment::new(FIRST_FRAGMENT_CAPACITY).into_fragments();
-        Self::from_raw_parts(0, fragments, Doubling)
+        Self::from_parts(0, fragments, Doubling)
     }
 
     /// Creates a new split vector with `Doubling` growth and initial `fragments_capacity`.
@@ -247,14 +282,14 @@ impl<T> SplitVec<T, Doubling> {
         assert!(fragments_capacity > 0);
         let fragments =
             Fragment::new(FIRST_FRAGMENT_CAPACITY).into_fragments_with_capacity(fragments_capacity);
-        Self::from_raw_parts(0, fragments, Doubling)
+        Self::from_parts(0, fragments, Doubling)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use orx_pinned_vec::PinnedVec;
+    use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec};
 
     #[test]
     fn get_fragment_and_inner_indices() {
@@ -282,6 +317,35 @@ mod tests {
         assert_eq!(None, get_none(12));
     }
 
+    #[test]
+    fn get_fragment_and_inner_indices_near_max() {
+        let last = CAPACITIES.len() - 1;
+        let total = CUMULATIVE_CAPACITIES[last + 1];
+
+        let (f, i) = Doubling.get_fragment_and_inner_indices_unchecked(total - 1);
+        assert_eq!((f, i), (last, CAPACITIES[last] - 1));
+        let (f, i) = Doubling.get_fragment_and_inner_indices_unchecked(CUMULATIVE_CAPACITIES[last]);
+        assert_eq!((f, i), (last, 0));
+
+        for index in [
+            total,
+            usize::MAX / 2 - 1,
+            usize::MAX / 2,
+            usize::MAX / 2 + 1,
+            usize::MAX,
+        ] {
+            assert!(Doubling
+                .get_fragment_and_inner_indices_checked(index)
+                .is_err());
+            assert_eq!(
+                GrowthWithConstantTimeAccess::get_ptr::<char>(&Doubling, &[], index),
+                None
+            );
+            let get = Doubling.get_fragment_and_inner_indices::<char>(index, &[], index);
+            assert_eq!(get, None);
+        }
+    }
+
     #[test]
     fn get_fragment_and_inner_indices_exhaustive() {
         let growth = Doubling;
@@ -399,4 +463,95 @@ mod tests {
             *CUMULATIVE_CAPACITIES.last().expect("is not empty") + 1;
         assert!(num_fragments(more_than_max_possible_capacity).is_err());
     }
+
+    #[test]
+    fn indices_near_usize_max() {
+        let growth = Doubling;
+        let mut fragments: alloc::vec::Vec<Fragment<char>> = alloc::vec![Fragment::new(4)];
+
+        let last_cumulative = CUMULATIVE_CAPACITIES[CUMULATIVE_CAPACITIES.len() - 1];
+        for index in [
+            last_cumulative,
+            usize::MAX / 2,
+            usize::MAX - 4,
+            usize::MAX - 1,
+            usize::MAX,
+        ] {
+            assert_eq!(
+                growth.get_fragment_and_inner_indices_checked(index),
+                Err(GrowthError::IndexBeyondMaximumCapacity {
+                    element_index: index,
+                    maximum_capacity: last_cumulative
+                })
+            );
+
+            assert!(<Doubling as Growth>::get_ptr(&growth, &fragments, index).is_none());
+            assert!(<Doubling as Growth>::get_ptr_mut(&growth, &mut fragments, index).is_none());
+            assert!(
+                <Doubling as Growth>::get_ptr_mut_and_indices(&growth, &mut fragments, index)
+                    .is_none()
+            );
+        }
+
+        let expected = (CAPACITIES.len() - 1, CAPACITIES[CAPACITIES.len() - 1] - 1);
+        assert_eq!(
+            growth.get_fragment_and_inner_indices_unchecked(last_cumulative - 1),
+            expected
+        );
+        assert_eq!(
+            growth.get_fragment_and_inner_indices_checked(last_cumulative - 1),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn capacities_near_table_limits() {
+        let growth = Doubling;
+        let fragments: alloc::vec::Vec<Fragment<char>> = alloc::vec![];
+        let last_cumulative = CUMULATIVE_CAPACITIES[CUMULATIVE_CAPACITIES.len() - 1];
+
+        let max_cap =
+            |fragments_capacity| growth.maximum_concurrent_capacity(&fragments, fragments_capacity);
+        assert_eq!(max_cap(CAPACITIES.len()), last_cumulative);
+        assert_eq!(max_cap(CAPACITIES.len() + 1), last_cumulative);
+        assert_eq!(max_cap(usize::MAX), last_cumulative);
+
+        assert_eq!(
+            growth.required_fragments_len(&fragments, last_cumulative),
+            Ok(CAPACITIES.len())
+        );
+        assert!(growth
+            .required_fragments_len(&fragments, last_cumulative + 1)
+            .is_err());
+        assert!(growth
+            .required_fragments_len(&fragments, usize::MAX)
+            .is_err());
+
+        assert_eq!(
+            growth.new_fragment_capacity_from([usize::MAX / 2 + 1].into_iter()),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn max_fragments() {
+        let growth = Doubling;
+        assert_eq!(growth.max_fragments(), Some(CAPACITIES.len()));
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(growth.max_fragments(), Some(32));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(growth.max_fragments(), Some(29));
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(CUMULATIVE_CAPACITIES.len(), 33);
+
+        let vec: SplitVec<char, _> = SplitVec::with_doubling_growth_and_fragments_capacity(100);
+        assert_eq!(vec.fragments.capacity(), 100);
+        let con_vec = vec.into_concurrent();
+        assert_eq!(
+            con_vec.max_capacity(),
+            CUMULATIVE_CAPACITIES[CAPACITIES.len()]
+        );
+    }
 }
@@ -1,10 +1,17 @@
-use super::constants::CUMULATIVE_CAPACITIES;
-use crate::{Doubling, Fragment, SplitVec};
+use super::constants::CAPACITIES;
+use crate::{Doubling, Fragment, Recursive, SplitVec};
 use alloc::vec::Vec;
 
-impl<T: Clone> From<Vec<T>> for SplitVec<T, Doubling> {
+impl<T> From<Vec<T>> for SplitVec<T, Doubling> {
     /// Converts a `Vec` into a `SplitVec`.
     ///
+    /// Since the `Doubling` growth requires fragments with exact capacities of 4, 8, 16, etc., the allocation of the vector
+    /// cannot be adopted. Instead, all fragments that are required to hold the elements are allocated up front,
+    /// and the elements are moved into the fragments with one bulk memory copy per fragment.
+    ///
+    /// If the allocation must be adopted without copying, the vector can be converted into a `SplitVec` with
+    /// `Recursive` or `Linear` growth instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -19,37 +26,135 @@ impl<T: Clone> From<Vec<T>> for SplitVec<T, Doubling> {
     /// assert_eq!(1, split_vec.fragments().len());
     /// assert!(vec_capacity <= split_vec.capacity());
     /// ```
-    fn from(value: Vec<T>) -> Self {
+    fn from(mut value: Vec<T>) -> Self {
         let len = value.len();
-        let f = CUMULATIVE_CAPACITIES
-            .iter()
-            .enumerate()
-            .find(|(_, cum_cap)| **cum_cap >= len)
-            .map(|(f, _)| f)
-            .expect("overflow");
-
-        let mut fragments = Vec::with_capacity(f + 1);
-        let mut original_idx = 0;
+        let mut fragments = Vec::new();
+
+        let mut begin = 0;
+        for capacity in CAPACITIES.iter().copied() {
+            let copy_len = capacity.min(len - begin);
+            let mut fragment = Fragment::new(capacity);
+            // SAFETY: the source range is within the initialized elements of `value`, and the target has capacity for copy_len elements;
+            // lengths are set right after, and the length of `value` is set to zero below so that the elements are moved exactly once
+            unsafe {
+                let src = value.as_ptr().add(begin);
+                core::ptr::copy_nonoverlapping(src, fragment.as_mut_ptr(), copy_len);
+                fragment.set_len(copy_len);
+            }
+            fragments.push(fragment);
+
+            begin += copy_len;
+            if begin == len {
+                break;
+            }
+        }
+
+        // SAFETY: all elements are moved into the fragments
+        unsafe { value.set_len(0) };
+
+        Self::from_parts(len, fragments, Doubling)
+    }
+}
+
+impl<T> From<SplitVec<T, Recursive>> for SplitVec<T, Doubling> {
+    /// Converts a `SplitVec<T, Recursive>` into a `SplitVec<T, Doubling>` by re-packing its fragments into
+    /// fragments with capacities of 4, 8, 16, etc.
+    ///
+    /// The leading fragments which already have the capacities of the doubling growth, and which are completely
+    /// filled (or hold the last elements of the vector), are adopted without moving their elements.
+    /// Elements of the remaining fragments are moved into newly allocated fragments, with one bulk memory copy
+    /// per pair of source and target fragments.
+    ///
+    /// Therefore, a split vector which is converted from `Doubling` into `Recursive` and is not appended to since
+    /// is converted back with no cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend(0..10);
+    /// vec.append(vec![10, 11, 12]);
+    /// assert_eq!(vec.fragments().len(), 3);
+    ///
+    /// let vec: SplitVec<_, Doubling> = vec.into();
+    /// assert_eq!(vec, (0..13).collect::<Vec<_>>());
+    /// assert_eq!(vec.fragments().len(), 3);
+    /// assert_eq!(vec.fragments()[0].capacity(), 4);
+    /// assert_eq!(vec.fragments()[1].capacity(), 8);
+    /// assert_eq!(vec.fragments()[2].capacity(), 16);
+    /// ```
+    fn from(mut value: SplitVec<T, Recursive>) -> Self {
+        let len = value.len;
+        let source = core::mem::take(&mut value.fragments);
+        let mut fragments = Vec::with_capacity(source.capacity());
         let mut remaining_len = len;
-        let mut curr_f = 1;
-        while remaining_len > 0 {
-            let capacity = &CUMULATIVE_CAPACITIES[curr_f];
-            let mut fragment = Fragment::new(*capacity);
+        let mut source = source.into_iter().peekable();
 
-            let copy_len = if capacity <= &remaining_len {
-                *capacity
-            } else {
-                remaining_len
-            };
+        while let Some(fragment) = source.peek() {
+            let capacity = CAPACITIES[fragments.len()];
+            let fragment_len = fragment.len();
+            let adoptable = fragment.capacity() == capacity
+                && fragment_len > 0
+                && (fragment_len == capacity || fragment_len == remaining_len);
 
-            fragment.extend_from_slice(&value[original_idx..(original_idx + copy_len)]);
+            match (fragment_len, adoptable) {
+                (0, _) if remaining_len > 0 => {
+                    source.next();
+                }
+                (_, true) => {
+                    fragments.extend(source.next());
+                    remaining_len -= fragment_len;
+                }
+                _ => break,
+            }
+        }
+
+        let mut current: Option<Fragment<T>> = None;
+        let mut begin = 0;
+        while remaining_len > 0 {
+            let capacity = CAPACITIES[fragments.len()];
+            let mut fragment: Fragment<T> = Fragment::new(capacity);
+
+            while fragment.len() < capacity && remaining_len > 0 {
+                match &mut current {
+                    Some(src) if begin < src.len() => {
+                        let copy_len = (src.len() - begin).min(capacity - fragment.len());
+                        // SAFETY: the source range is within the initialized elements of `src`, and the target has room for copy_len elements;
+                        // the length of `src` is set to zero once all of its elements are moved so that the elements are moved exactly once
+                        unsafe {
+                            let fragment_len = fragment.len();
+                            let dst = fragment.as_mut_ptr().add(fragment_len);
+                            core::ptr::copy_nonoverlapping(src.as_ptr().add(begin), dst, copy_len);
+                            fragment.set_len(fragment_len + copy_len);
+                        }
+                        begin += copy_len;
+                        remaining_len -= copy_len;
+                    }
+                    _ => {
+                        if let Some(src) = current.as_mut() {
+                            // SAFETY: all elements of the source fragment are moved
+                            unsafe { src.set_len(0) };
+                        }
+                        current = source.next();
+                        begin = 0;
+                    }
+                }
+            }
 
-            original_idx += copy_len;
-            remaining_len -= copy_len;
             fragments.push(fragment);
-            curr_f += 1;
         }
 
-        Self::from_raw_parts(len, fragments, Doubling)
+        if let Some(src) = current.as_mut() {
+            // SAFETY: all elements are moved; hence, all elements of the current source fragment are moved
+            unsafe { src.set_len(0) };
+        }
+
+        if fragments.is_empty() {
+            fragments.push(Fragment::new(CAPACITIES[0]));
+        }
+
+        Self::from_parts(len, fragments, Doubling)
     }
 }
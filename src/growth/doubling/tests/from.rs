@@ -1,9 +1,22 @@
 use crate::*;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-fn validate_clone(original: Vec<usize>, split_vec: SplitVec<usize, Doubling>) {
+fn validate_clone(original: Vec<usize>, mut split_vec: SplitVec<usize, Doubling>) {
     assert_eq!(split_vec, &original);
     assert!(original.capacity() <= split_vec.capacity());
+    for (f, fragment) in split_vec.fragments().iter().enumerate() {
+        assert_eq!(fragment.capacity(), 4 * usize::pow(2, f as u32));
+    }
+
+    let len = original.len();
+    for i in 0..100 {
+        split_vec.push(len + i);
+    }
+    for i in 0..(len + 100) {
+        let expected = original.get(i).copied().unwrap_or(i);
+        assert_eq!(split_vec.get(i), Some(&expected));
+    }
 }
 
 #[test]
@@ -14,3 +27,51 @@ fn from_vec_medium() {
         validate_clone(vec, split_vec);
     }
 }
+
+#[test]
+fn from_vec_moves_elements() {
+    use alloc::rc::Rc;
+
+    let rc = Rc::new(0);
+    let vec: Vec<_> = (0..77).map(|_| rc.clone()).collect();
+    let split_vec: SplitVec<_, Doubling> = vec.into();
+    assert_eq!(split_vec.len(), 77);
+    assert_eq!(Rc::strong_count(&rc), 78);
+
+    drop(split_vec);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn from_recursive() {
+    for lengths in [
+        alloc::vec![],
+        alloc::vec![0],
+        alloc::vec![3],
+        alloc::vec![4, 8, 16],
+        alloc::vec![4, 8, 5],
+        alloc::vec![4, 0, 8, 0, 3, 33, 1],
+        alloc::vec![1, 1, 1, 1, 1, 100],
+        alloc::vec![7, 13, 2],
+    ] {
+        let mut vec: SplitVec<String, Recursive> = SplitVec::with_recursive_growth();
+        let mut expected = Vec::new();
+        let mut next = 0;
+        for n in lengths {
+            let fragment: Vec<_> = (next..next + n).map(|x| x.to_string()).collect();
+            expected.extend(fragment.iter().cloned());
+            vec.append(fragment);
+            next += n;
+        }
+
+        let vec: SplitVec<_, Doubling> = vec.into();
+        assert_eq!(vec, expected);
+        assert!(!vec.fragments().is_empty());
+        for (f, fragment) in vec.fragments().iter().enumerate() {
+            assert_eq!(fragment.capacity(), 4 * usize::pow(2, f as u32));
+            if f + 1 < vec.fragments().len() {
+                assert_eq!(fragment.len(), fragment.capacity());
+            }
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Fragment, SplitVec};
+use alloc::string::String;
+use orx_pseudo_default::PseudoDefault;
+
+const FIRST_FRAGMENT_CAPACITY_POW: usize = 2;
+const FIRST_FRAGMENT_CAPACITY: usize = 1 << FIRST_FRAGMENT_CAPACITY_POW;
+const SIZE_USIZE: usize = usize::BITS as usize;
+
+/// Strategy which doubles the capacity of the fragments until a maximum fragment capacity is reached,
+/// and then grows linearly with fragments of the maximum capacity.
+///
+/// * The first fragment has a capacity of 4.
+/// * Every new fragment has double the capacity of the prior fragment until the capacity reaches
+///   `2 ^ max_fragment_capacity_exponent`.
+/// * All subsequent fragments have the capacity of `2 ^ max_fragment_capacity_exponent`.
+///
+/// Therefore, small vectors grow as with the [`Doubling`](crate::Doubling) strategy, while the memory
+/// allocated but not used by very large vectors is bounded by the maximum fragment capacity.
+/// Since all capacities are powers of two, element access is ***O(1)*** with a closed-form index computation.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_doubling_until_growth(5);
+/// vec.extend(0..200);
+///
+/// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+/// assert_eq!(capacities, [4, 8, 16, 32, 32, 32, 32, 32, 32]);
+///
+/// assert_eq!(vec[100], 100);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoublingUntil {
+    max_fragment_capacity_exponent: usize,
+    max_fragment_capacity: usize,
+    num_doubling_fragments: usize,
+    doubling_capacity: usize,
+}
+
+impl DoublingUntil {
+    /// Creates a growth strategy doubling the fragment capacities until `2 ^ max_fragment_capacity_exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_fragment_capacity_exponent` is not within:
+    /// * 2..63 for 64-bit platforms, or
+    /// * 2..31 for 32-bit platforms.
+    pub fn new(max_fragment_capacity_exponent: usize) -> Self {
+        assert!(
+            (FIRST_FRAGMENT_CAPACITY_POW..(SIZE_USIZE - 1)).contains(&max_fragment_capacity_exponent),
+            "max_fragment_capacity_exponent must be within 2..63 (2..31) for 64-bit (32-bit) platforms."
+        );
+
+        let num_doubling_fragments = max_fragment_capacity_exponent - FIRST_FRAGMENT_CAPACITY_POW;
+        Self {
+            max_fragment_capacity_exponent,
+            max_fragment_capacity: 1 << max_fragment_capacity_exponent,
+            num_doubling_fragments,
+            doubling_capacity: Self::doubling_cumulative_capacity(num_doubling_fragments),
+        }
+    }
+
+    /// Returns the maximum fragment capacity, which is the capacity of all fragments after the doubling phase.
+    pub fn max_fragment_capacity(&self) -> usize {
+        self.max_fragment_capacity
+    }
+
+    /// Total capacity of the first `num_fragments` fragments of the doubling phase.
+    fn doubling_cumulative_capacity(num_fragments: usize) -> usize {
+        (FIRST_FRAGMENT_CAPACITY << num_fragments) - FIRST_FRAGMENT_CAPACITY
+    }
+
+    /// Total capacity of the first `num_fragments` fragments.
+    fn cumulative_capacity(&self, num_fragments: usize) -> usize {
+        match num_fragments <= self.num_doubling_fragments {
+            true => Self::doubling_cumulative_capacity(num_fragments),
+            false => (num_fragments - self.num_doubling_fragments)
+                .saturating_mul(self.max_fragment_capacity)
+                .saturating_add(self.doubling_capacity),
+        }
+    }
+}
+
+impl PseudoDefault for DoublingUntil {
+    fn pseudo_default() -> Self {
+        Self::new(20)
+    }
+}
+
+impl Growth for DoublingUntil {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        fragment_capacities
+            .last()
+            .map(|x| x.saturating_mul(2).min(self.max_fragment_capacity))
+            .unwrap_or(FIRST_FRAGMENT_CAPACITY)
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T>(
+        &self,
+        vec_len: usize,
+        _fragments: &[Fragment<T>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => Some(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => None,
+        }
+    }
+
+    /// ***O(1)*** Returns a pointer to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    fn get_ptr<T>(&self, fragments: &[Fragment<T>], index: usize) -> Option<*const T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    fn get_ptr_mut_and_indices<T>(
+        &self,
+        fragments: &mut [Fragment<T>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T>(
+        &self,
+        fragments: &[Fragment<T>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        self.cumulative_capacity(fragments_capacity)
+    }
+
+    /// Returns the maximum number of fragments such that the total capacity does not exceed `usize::MAX`.
+    fn max_fragments(&self) -> Option<usize> {
+        let num_linear_fragments =
+            (usize::MAX - self.doubling_capacity) >> self.max_fragment_capacity_exponent;
+        Some(self.num_doubling_fragments + num_linear_fragments)
+    }
+
+    fn required_fragments_len<T>(
+        &self,
+        _: &[Fragment<T>],
+        maximum_capacity: usize,
+    ) -> Result<usize, String> {
+        match maximum_capacity <= self.doubling_capacity {
+            true => Ok((0..=self.num_doubling_fragments)
+                .find(|n| Self::doubling_cumulative_capacity(*n) >= maximum_capacity)
+                .unwrap_or(self.num_doubling_fragments)),
+            false => {
+                let remaining = maximum_capacity - self.doubling_capacity;
+                let num_linear_fragments = remaining.div_ceil(self.max_fragment_capacity);
+                Ok(self.num_doubling_fragments + num_linear_fragments)
+            }
+        }
+    }
+}
+
+impl GrowthWithConstantTimeAccess for DoublingUntil {
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        match element_index < self.doubling_capacity {
+            true => {
+                let offset = element_index + FIRST_FRAGMENT_CAPACITY;
+                let pow = SIZE_USIZE - 1 - offset.leading_zeros() as usize;
+                let f = pow - FIRST_FRAGMENT_CAPACITY_POW;
+                (f, offset - (1 << pow))
+            }
+            false => {
+                let index = element_index - self.doubling_capacity;
+                let f =
+                    self.num_doubling_fragments + (index >> self.max_fragment_capacity_exponent);
+                (f, index & (self.max_fragment_capacity - 1))
+            }
+        }
+    }
+
+    fn fragment_capacity_of(&self, fragment_index: usize) -> usize {
+        match fragment_index < self.num_doubling_fragments {
+            true => FIRST_FRAGMENT_CAPACITY << fragment_index,
+            false => self.max_fragment_capacity,
+        }
+    }
+}
+
+impl<T> SplitVec<T, DoublingUntil> {
+    /// Creates a split vector with the [`DoublingUntil`] growth strategy, which doubles the capacity of the fragments
+    /// until `2 ^ max_fragment_capacity_exponent`, and then grows linearly with fragments of this capacity.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_fragment_capacity_exponent` is not within:
+    /// * 2..63 for 64-bit platforms, or
+    /// * 2..31 for 32-bit platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_until_growth(4);
+    /// vec.extend(0..50);
+    ///
+    /// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+    /// assert_eq!(capacities, [4, 8, 16, 16, 16]);
+    /// assert_eq!(vec.growth().max_fragment_capacity(), 16);
+    /// ```
+    pub fn with_doubling_until_growth(max_fragment_capacity_exponent: usize) -> Self {
+        let growth = DoublingUntil::new(max_fragment_capacity_exponent);
+        let fragments = Fragment::new(growth.first_fragment_capacity()).into_fragments();
+        Self::from_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::growth_with_constant_time_access_conformance_check;
+    use alloc::vec::Vec;
+    use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec};
+
+    #[test]
+    fn capacities() {
+        let growth = DoublingUntil::new(6);
+        let capacities: Vec<_> = (0..8).map(|f| growth.fragment_capacity_of(f)).collect();
+        assert_eq!(capacities, [4, 8, 16, 32, 64, 64, 64, 64]);
+
+        let growth = DoublingUntil::new(2);
+        let capacities: Vec<_> = (0..4).map(|f| growth.fragment_capacity_of(f)).collect();
+        assert_eq!(capacities, [4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        for exponent in [2, 3, 5, 8] {
+            let growth = DoublingUntil::new(exponent);
+
+            let mut f = 0;
+            let mut prev_cumulative_capacity = 0;
+            let mut cumulative_capacity = growth.fragment_capacity_of(0);
+            for index in 0..51_111 {
+                if index == cumulative_capacity {
+                    f += 1;
+                    prev_cumulative_capacity = cumulative_capacity;
+                    cumulative_capacity += growth.fragment_capacity_of(f);
+                }
+
+                let expected = (f, index - prev_cumulative_capacity);
+                assert_eq!(
+                    growth.get_fragment_and_inner_indices_unchecked(index),
+                    expected
+                );
+                let get = growth.get_fragment_and_inner_indices::<char>(index + 1, &[], index);
+                assert_eq!(get, Some(expected));
+                let get = growth.get_fragment_and_inner_indices::<char>(index, &[], index);
+                assert_eq!(get, None);
+            }
+        }
+    }
+
+    #[test]
+    fn conformance() {
+        for exponent in [2, 3, 4, 7] {
+            let growth = DoublingUntil::new(exponent);
+            assert_eq!(
+                growth_with_constant_time_access_conformance_check(&growth, 1000),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn push_get() {
+        let mut vec = SplitVec::with_doubling_until_growth(4);
+        for i in 0..1000 {
+            vec.push(i);
+        }
+        for i in 0..1000 {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        assert!(vec.fragments().iter().all(|x| x.capacity() <= 16));
+
+        let con_vec = vec.into_concurrent();
+        let vec = unsafe { con_vec.into_inner(1000) };
+        assert_eq!(vec, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponent_too_small() {
+        let _ = DoublingUntil::new(1);
+    }
+}
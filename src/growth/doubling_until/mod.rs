@@ -0,0 +1,3 @@
+mod doubling_until_growth;
+
+pub use doubling_until_growth::DoublingUntil;
@@ -0,0 +1,234 @@
+use crate::{Fragment, Growth, SplitVec};
+use alloc::string::String;
+use orx_pseudo_default::PseudoDefault;
+
+/// Strategy which creates every new fragment with a capacity equal to the capacity of the prior fragment
+/// multiplied by a constant growth `factor`, such as 1.5.
+///
+/// Smaller factors lead to less memory being allocated but not used, at the cost of a larger number of fragments.
+///
+/// Since the fragment capacities are not powers of two, the fragment of an element is found by a linear search
+/// over the fragments; i.e., access by index is ***O(f)*** where `f` is the number of fragments.
+/// This is similar to the [`Recursive`](crate::Recursive) strategy.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_exponential_growth(4, 1.5);
+/// vec.extend(0..40);
+///
+/// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+/// assert_eq!(capacities, [4, 6, 9, 14, 21]);
+///
+/// assert_eq!(vec[30], 30);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exponential {
+    first_fragment_capacity: usize,
+    factor: f32,
+}
+
+impl Exponential {
+    /// Creates an exponential growth where the first fragment has a capacity of `first_fragment_capacity`,
+    /// and each subsequent fragment has `factor` times the capacity of the prior fragment.
+    ///
+    /// The capacities are rounded up, and each fragment is guaranteed to be at least as large as the prior fragment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first_fragment_capacity` is zero, or if `factor` is not a finite number greater than or equal to 1.0.
+    pub fn new(first_fragment_capacity: usize, factor: f32) -> Self {
+        assert!(
+            first_fragment_capacity > 0,
+            "first_fragment_capacity must be positive"
+        );
+        assert!(
+            factor.is_finite() && factor >= 1.0,
+            "factor must be a finite number greater than or equal to 1.0"
+        );
+
+        Self {
+            first_fragment_capacity,
+            factor,
+        }
+    }
+
+    /// Returns the capacity of the first fragment.
+    pub fn first_fragment_capacity(&self) -> usize {
+        self.first_fragment_capacity
+    }
+
+    /// Returns the growth factor of the fragment capacities.
+    pub fn factor(&self) -> f32 {
+        self.factor
+    }
+
+    fn next_capacity(&self, prior_capacity: usize) -> usize {
+        let capacity = prior_capacity as f64 * self.factor as f64;
+        match capacity >= usize::MAX as f64 {
+            true => usize::MAX,
+            false => {
+                // the representation error of the f32 factor is tolerated so that 10 * 1.2 is 12 rather than 13
+                let capacity = capacity - capacity * f32::EPSILON as f64;
+                // rounds up without the float methods which are not available in no-std
+                let floor = capacity as usize;
+                let ceil = floor + ((floor as f64) < capacity) as usize;
+                ceil.max(prior_capacity)
+            }
+        }
+    }
+
+    /// Returns an iterator over the capacities of the fragments that would be created after the `fragments`.
+    fn next_capacities<T>(&self, fragments: &[Fragment<T>]) -> impl Iterator<Item = usize> + '_ {
+        let first = self.new_fragment_capacity(fragments);
+        core::iter::successors(Some(first), |c| Some(self.next_capacity(*c)))
+    }
+}
+
+impl PseudoDefault for Exponential {
+    fn pseudo_default() -> Self {
+        Self::new(4, 1.5)
+    }
+}
+
+impl Growth for Exponential {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        fragment_capacities
+            .last()
+            .map(|x| self.next_capacity(x))
+            .unwrap_or(self.first_fragment_capacity)
+    }
+
+    fn maximum_concurrent_capacity<T>(
+        &self,
+        fragments: &[Fragment<T>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        let current_capacity = fragments
+            .iter()
+            .fold(0usize, |sum, x| sum.saturating_add(x.capacity()));
+
+        self.next_capacities(fragments)
+            .take(fragments_capacity - fragments.len())
+            .fold(current_capacity, |sum, x| sum.saturating_add(x))
+    }
+
+    fn required_fragments_len<T>(
+        &self,
+        fragments: &[Fragment<T>],
+        maximum_capacity: usize,
+    ) -> Result<usize, String> {
+        let mut total_capacity: usize = fragments.iter().map(|x| x.capacity()).sum();
+        let mut f = fragments.len();
+
+        let mut capacities = self.next_capacities(fragments);
+        while total_capacity < maximum_capacity {
+            let capacity = capacities.next().unwrap_or(usize::MAX);
+            total_capacity = total_capacity.checked_add(capacity).ok_or_else(|| {
+                alloc::format!(
+                    "Maximum cumulative capacity that can be reached by the Exponential strategy is {}.",
+                    usize::MAX
+                )
+            })?;
+            f += 1;
+        }
+
+        Ok(f)
+    }
+}
+
+impl<T> SplitVec<T, Exponential> {
+    /// Creates a split vector with the [`Exponential`] growth strategy where the first fragment has a capacity of
+    /// `first_fragment_capacity`, and each subsequent fragment has `factor` times the capacity of the prior fragment.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first_fragment_capacity` is zero, or if `factor` is not a finite number greater than or equal to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_exponential_growth(10, 1.2);
+    /// vec.extend(0..50);
+    ///
+    /// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+    /// assert_eq!(capacities, [10, 12, 15, 18]);
+    /// ```
+    pub fn with_exponential_growth(first_fragment_capacity: usize, factor: f32) -> Self {
+        let growth = Exponential::new(first_fragment_capacity, factor);
+        let fragments = Fragment::new(first_fragment_capacity).into_fragments();
+        Self::from_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::growth_conformance_check;
+    use alloc::vec::Vec;
+    use orx_pinned_vec::PinnedVec;
+
+    #[test]
+    fn capacities() {
+        let growth = Exponential::new(4, 1.5);
+        let capacities: Vec<_> = growth.next_capacities::<char>(&[]).take(6).collect();
+        assert_eq!(capacities, [4, 6, 9, 14, 21, 32]);
+
+        let growth = Exponential::new(3, 1.0);
+        let capacities: Vec<_> = growth.next_capacities::<char>(&[]).take(4).collect();
+        assert_eq!(capacities, [3, 3, 3, 3]);
+
+        let growth = Exponential::new(1, 1.1);
+        let capacities: Vec<_> = growth.next_capacities::<char>(&[]).take(4).collect();
+        assert_eq!(capacities, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn conformance() {
+        for (first, factor) in [(1, 1.0), (4, 1.5), (3, 1.25), (7, 2.0), (2, 3.3)] {
+            let growth = Exponential::new(first, factor);
+            assert_eq!(growth_conformance_check(&growth, 1000), Ok(()));
+        }
+    }
+
+    #[test]
+    fn push_get() {
+        let mut vec = SplitVec::with_exponential_growth(4, 1.5);
+        for i in 0..1000 {
+            vec.push(i);
+        }
+        for i in 0..1000 {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+
+        let capacities: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+        for w in capacities.windows(2) {
+            assert_eq!(w[1], (w[0] * 3).div_ceil(2));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn factor_less_than_one() {
+        let _ = Exponential::new(4, 0.9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_first_fragment_capacity() {
+        let _ = Exponential::new(0, 1.5);
+    }
+}
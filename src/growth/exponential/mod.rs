@@ -0,0 +1,3 @@
+mod exponential_growth;
+
+pub use exponential_growth::Exponential;
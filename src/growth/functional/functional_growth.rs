@@ -0,0 +1,174 @@
+use crate::{Growth, SplitVec};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use orx_pseudo_default::PseudoDefault;
+
+type CapacityFn = dyn Fn(&[usize]) -> usize + Send + Sync;
+
+/// Strategy where the capacity of every new fragment is computed by a user provided function
+/// of the capacities of the prior fragments.
+///
+/// The function receives the capacities of the existing fragments in order, which is empty
+/// when computing the capacity of the first fragment.
+///
+/// Since the fragment capacities are arbitrary, the fragment of an element is found by a linear search
+/// over the fragments; i.e., access by index is ***O(f)*** where `f` is the number of fragments.
+///
+/// # Panics
+///
+/// The split vector panics when it needs to create a new fragment and the function returns a capacity of zero.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // capacities of the fibonacci sequence
+/// let growth = FunctionalGrowth::new(|capacities: &[usize]| match capacities {
+///     [] => 2,
+///     [_] => 3,
+///     [.., a, b] => a + b,
+/// });
+///
+/// let mut vec = SplitVec::with_growth(growth);
+/// vec.extend(0..30);
+///
+/// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+/// assert_eq!(capacities, [2, 3, 5, 8, 13]);
+/// assert_eq!(vec[20], 20);
+/// ```
+#[derive(Clone)]
+pub struct FunctionalGrowth {
+    capacity_of_next: Arc<CapacityFn>,
+}
+
+impl FunctionalGrowth {
+    /// Creates a growth strategy where the capacity of the every new fragment is computed by `capacity_of_next`
+    /// given the capacities of the prior fragments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity of the first fragment, `capacity_of_next(&[])`, is zero.
+    pub fn new<F>(capacity_of_next: F) -> Self
+    where
+        F: Fn(&[usize]) -> usize + Send + Sync + 'static,
+    {
+        assert!(
+            capacity_of_next(&[]) > 0,
+            "capacity of the first fragment must be positive"
+        );
+
+        Self {
+            capacity_of_next: Arc::new(capacity_of_next),
+        }
+    }
+}
+
+impl core::fmt::Debug for FunctionalGrowth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FunctionalGrowth")
+            .field("first_fragment_capacity", &(self.capacity_of_next)(&[]))
+            .finish()
+    }
+}
+
+impl PseudoDefault for FunctionalGrowth {
+    fn pseudo_default() -> Self {
+        Self::new(|capacities| capacities.last().map(|x| x * 2).unwrap_or(4))
+    }
+}
+
+impl Growth for FunctionalGrowth {
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        let capacities: Vec<_> = fragment_capacities.collect();
+        let capacity = (self.capacity_of_next)(&capacities);
+        assert!(
+            capacity > 0,
+            "capacity of the fragment {} is computed as zero",
+            capacities.len()
+        );
+        capacity
+    }
+}
+
+impl<T> SplitVec<T, FunctionalGrowth> {
+    /// Creates a split vector with the [`FunctionalGrowth`] strategy where the capacity of every new fragment
+    /// is computed by `capacity_of_next` given the capacities of the prior fragments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity of the first fragment, `capacity_of_next(&[])`, is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // grows linearly by 10 elements for every new fragment
+    /// let mut vec = SplitVec::with_functional_growth(|c: &[usize]| 10 * (c.len() + 1));
+    /// vec.extend(0..100);
+    ///
+    /// let capacities: Vec<_> = vec.fragments().iter().map(|f| f.capacity()).collect();
+    /// assert_eq!(capacities, [10, 20, 30, 40]);
+    /// ```
+    pub fn with_functional_growth<F>(capacity_of_next: F) -> Self
+    where
+        F: Fn(&[usize]) -> usize + Send + Sync + 'static,
+    {
+        Self::with_growth(FunctionalGrowth::new(capacity_of_next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::growth_conformance_check;
+    use orx_pinned_vec::PinnedVec;
+
+    #[test]
+    fn conformance() {
+        let growth = FunctionalGrowth::new(|c| [3, 1, 7, 2].get(c.len()).copied().unwrap_or(5));
+        assert_eq!(growth_conformance_check(&growth, 1000), Ok(()));
+
+        assert_eq!(
+            growth_conformance_check(&FunctionalGrowth::pseudo_default(), 1000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn push_get() {
+        let mut vec = SplitVec::with_functional_growth(|c| c.len() + 1);
+        for i in 0..1000 {
+            vec.push(i);
+        }
+        for i in 0..1000 {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        for (f, fragment) in vec.fragments().iter().enumerate() {
+            assert_eq!(fragment.capacity(), f + 1);
+        }
+
+        let clone = vec.clone();
+        assert_eq!(clone, vec);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_first_capacity() {
+        let _ = FunctionalGrowth::new(|_| 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_later_capacity() {
+        let mut vec = SplitVec::with_functional_growth(|c| match c.len() {
+            0 => 2,
+            _ => 0,
+        });
+        vec.extend(0..3);
+    }
+}
@@ -0,0 +1,3 @@
+mod functional_growth;
+
+pub use functional_growth::FunctionalGrowth;
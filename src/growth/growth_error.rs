@@ -0,0 +1,31 @@
+/// Error observed while computing the location of an element by a growth strategy.
+///
+/// See [`GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_checked`].
+///
+/// [`GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_checked`]: crate::GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_checked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrowthError {
+    /// The element index is beyond the maximum capacity that can be reached by the growth strategy;
+    /// i.e., it cannot belong to any of the fragments that the strategy can allocate.
+    IndexBeyondMaximumCapacity {
+        /// Index of the element.
+        element_index: usize,
+        /// Maximum capacity that can be reached by the growth strategy.
+        maximum_capacity: usize,
+    },
+}
+
+impl core::fmt::Display for GrowthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IndexBeyondMaximumCapacity {
+                element_index,
+                maximum_capacity,
+            } => write!(
+                f,
+                "Element index {} is beyond the maximum capacity {} of the growth strategy.",
+                element_index, maximum_capacity
+            ),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Fragment;
+use crate::{Fragment, GrowthError};
 use alloc::{string::String, vec::Vec};
 use orx_pseudo_default::PseudoDefault;
 
@@ -143,6 +143,8 @@ pub trait Growth: Clone + PseudoDefault {
     /// However, it is almost always possible to provide a non-allocating implementation provided that the concurrency is relevant.
     /// `Doubling`, `Recursive` and `Linear` growth strategies introduced in this crate all override this method.
     ///
+    /// The returned capacity saturates at `usize::MAX` rather than overflowing.
+    ///
     /// # Panics
     ///
     /// Panics if `fragments.len() < fragments_capacity`, which must not hold.
@@ -154,7 +156,9 @@ pub trait Growth: Clone + PseudoDefault {
         assert!(fragments_capacity >= fragments.len());
 
         if fragments_capacity == fragments.len() {
-            fragments.iter().map(|x| x.capacity()).sum()
+            fragments
+                .iter()
+                .fold(0usize, |sum, x| sum.saturating_add(x.capacity()))
         } else {
             let mut cloned: Vec<Fragment<T>> = Vec::with_capacity(fragments_capacity);
             for fragment in fragments {
@@ -165,10 +169,38 @@ pub trait Growth: Clone + PseudoDefault {
                 let fragment = Vec::with_capacity(new_capacity).into();
                 cloned.push(fragment);
             }
-            cloned.iter().map(|x| x.capacity()).sum()
+            cloned
+                .iter()
+                .fold(0usize, |sum, x| sum.saturating_add(x.capacity()))
         }
     }
 
+    /// Returns the maximum number of fragments that a split vector with this growth strategy can have;
+    /// returns None if the number of fragments is not bounded by the growth strategy.
+    ///
+    /// Collections of fragments, such as the fragment pointers of the concurrent split vector, are never
+    /// pre-allocated beyond this limit.
+    ///
+    /// Default implementation returns None.
+    fn max_fragments(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns whether or not a split vector with this growth strategy accepts fragments of arbitrary capacities;
+    /// i.e., whether the locations of the elements are computed from the actual fragments of the vector,
+    /// rather than from the positions of the fragments.
+    ///
+    /// If so, fragments can be appended as they are, see [`SplitVec::append`], and leading fragments can be removed
+    /// without moving the remaining elements, see [`SplitVec::drop_front`].
+    ///
+    /// Default implementation returns false.
+    ///
+    /// [`SplitVec::append`]: crate::SplitVec::append
+    /// [`SplitVec::drop_front`]: crate::SplitVec::drop_front
+    fn accepts_arbitrary_fragment_capacities(&self) -> bool {
+        false
+    }
+
     /// Returns the number of fragments with this growth strategy in order to be able to reach a capacity of `maximum_capacity` of elements.
     /// Returns the error if it the growth strategy does not allow the required number of fragments.
     ///
@@ -212,6 +244,14 @@ pub trait Growth: Clone + PseudoDefault {
 }
 
 /// Growth strategy of a split vector which allows for constant time access to the elements.
+///
+/// Split vectors with such growth strategies can be converted into a [`ConcurrentSplitVec`], which relies on the
+/// methods of this trait without bounds checks. Therefore, custom implementations must pass the
+/// [`growth_with_constant_time_access_conformance_check`]; see the `custom_growth` example for a
+/// fixed-then-doubling growth strategy.
+///
+/// [`ConcurrentSplitVec`]: crate::ConcurrentSplitVec
+/// [`growth_with_constant_time_access_conformance_check`]: crate::growth_with_constant_time_access_conformance_check
 pub trait GrowthWithConstantTimeAccess: Growth {
     /// ***O(1)*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment).
     ///
@@ -221,6 +261,22 @@ pub trait GrowthWithConstantTimeAccess: Growth {
     /// * and hence, returns the expected fragment and within-fragment indices for any index computed by the constant access time function.
     fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize);
 
+    /// ***O(1)*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment),
+    /// exactly as [`GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_unchecked`] does.
+    ///
+    /// Returns [`GrowthError::IndexBeyondMaximumCapacity`] if the growth strategy can never reach `element_index`;
+    /// for instance, if the index is beyond the capacity table of the [`Doubling`] strategy.
+    ///
+    /// Default implementation never fails.
+    ///
+    /// [`Doubling`]: crate::Doubling
+    fn get_fragment_and_inner_indices_checked(
+        &self,
+        element_index: usize,
+    ) -> Result<(usize, usize), GrowthError> {
+        Ok(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
     /// ***O(1)*** Returns a pointer to the `index`-th element of the split vector of the `fragments`.
     ///
     /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
@@ -230,7 +286,7 @@ pub trait GrowthWithConstantTimeAccess: Growth {
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
     fn get_ptr<T>(&self, fragments: &[Fragment<T>], index: usize) -> Option<*const T> {
-        let (f, i) = self.get_fragment_and_inner_indices_unchecked(index);
+        let (f, i) = self.get_fragment_and_inner_indices_checked(index).ok()?;
         fragments
             .get(f)
             .map(|fragment| unsafe { fragment.as_ptr().add(i) })
@@ -245,7 +301,7 @@ pub trait GrowthWithConstantTimeAccess: Growth {
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
     fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
-        let (f, i) = self.get_fragment_and_inner_indices_unchecked(index);
+        let (f, i) = self.get_fragment_and_inner_indices_checked(index).ok()?;
         fragments
             .get_mut(f)
             .map(|fragment| unsafe { fragment.as_mut_ptr().add(i) })
@@ -266,7 +322,7 @@ pub trait GrowthWithConstantTimeAccess: Growth {
         fragments: &mut [Fragment<T>],
         index: usize,
     ) -> Option<(*mut T, usize, usize)> {
-        let (f, i) = self.get_fragment_and_inner_indices_unchecked(index);
+        let (f, i) = self.get_fragment_and_inner_indices_checked(index).ok()?;
         fragments
             .get_mut(f)
             .map(|fragment| (unsafe { fragment.as_mut_ptr().add(i) }, f, i))
@@ -1,49 +1,66 @@
-const fn fixed_capacity(const_size_power: usize) -> usize {
-    usize::pow(2, const_size_power as u32)
+/// Number of fixed capacities of the linear growth on a target with the given pointer width in `bits`.
+///
+/// The largest fixed capacity is `2^(len - 1)`; the length is bounded by `bits - 3` so that
+/// a few fragments of the largest capacity do not exceed `isize::MAX` of the target.
+/// It is further capped at 32 fixed capacities on wider targets.
+const fn capacities_len_for(bits: usize) -> usize {
+    let len = bits.saturating_sub(3);
+    match len < 32 {
+        true => len,
+        false => 32,
+    }
 }
 
-const fn capacities_len() -> usize {
-    #[cfg(target_pointer_width = "32")]
-    return 29;
+const CAPACITIES_LEN: usize = capacities_len_for(usize::BITS as usize);
 
-    #[cfg(target_pointer_width = "64")]
-    return 32;
+const fn fixed_capacity(const_size_power: usize) -> usize {
+    match 2usize.checked_pow(const_size_power as u32) {
+        Some(x) => x,
+        None => usize::MAX,
+    }
 }
 
-pub(super) const FIXED_CAPACITIES: [usize; capacities_len()] = [
-    fixed_capacity(0),
-    fixed_capacity(1),
-    fixed_capacity(2),
-    fixed_capacity(3),
-    fixed_capacity(4),
-    fixed_capacity(5),
-    fixed_capacity(6),
-    fixed_capacity(7),
-    fixed_capacity(8),
-    fixed_capacity(9),
-    fixed_capacity(10),
-    fixed_capacity(11),
-    fixed_capacity(12),
-    fixed_capacity(13),
-    fixed_capacity(14),
-    fixed_capacity(15),
-    fixed_capacity(16),
-    fixed_capacity(17),
-    fixed_capacity(18),
-    fixed_capacity(19),
-    fixed_capacity(20),
-    fixed_capacity(21),
-    fixed_capacity(22),
-    fixed_capacity(23),
-    fixed_capacity(24),
-    fixed_capacity(25),
-    fixed_capacity(26),
-    fixed_capacity(27),
-    fixed_capacity(28),
-    #[cfg(target_pointer_width = "64")]
-    fixed_capacity(29),
-    #[cfg(target_pointer_width = "64")]
-    fixed_capacity(30),
-    #[cfg(target_pointer_width = "64")]
-    fixed_capacity(31),
-];
+const fn fixed_capacities() -> [usize; CAPACITIES_LEN] {
+    let mut capacities = [0; CAPACITIES_LEN];
+    let mut p = 0;
+    while p < CAPACITIES_LEN {
+        capacities[p] = fixed_capacity(p);
+        p += 1;
+    }
+    capacities
+}
+
+/// Fixed capacities that the fragments of the linear growth can have; i.e., 1, 2, 4, 8, etc.
+pub(super) const FIXED_CAPACITIES: [usize; CAPACITIES_LEN] = fixed_capacities();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacities_len_fits_in_target_pointer_width() {
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(FIXED_CAPACITIES.len(), 32);
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(FIXED_CAPACITIES.len(), 29);
+        #[cfg(target_pointer_width = "16")]
+        assert_eq!(FIXED_CAPACITIES.len(), 13);
+
+        for (bits, expected_len) in [(16, 13), (32, 29), (64, 32), (128, 32)] {
+            let len = capacities_len_for(bits);
+            assert_eq!(len, expected_len);
+
+            let isize_max = (1u128 << (bits - 1)) - 1;
+            let largest_capacity = 1u128 << (len - 1);
+            assert!(4 * largest_capacity <= isize_max);
+        }
+    }
+
+    #[test]
+    fn tables() {
+        for (p, capacity) in FIXED_CAPACITIES.iter().enumerate() {
+            assert_eq!(*capacity, 1 << p);
+        }
+        assert_eq!(fixed_capacity(usize::BITS as usize), usize::MAX);
+    }
+}
@@ -1,5 +1,5 @@
 use super::constants::FIXED_CAPACITIES;
-use crate::{Linear, SplitVec};
+use crate::{Fragment, Linear, SplitVec};
 use alloc::vec::Vec;
 
 // into SplitVec
@@ -8,6 +8,12 @@ impl<T> From<Vec<T>> for SplitVec<T, Linear> {
     /// moving the vector into the split vector as the first fragment,
     /// without copying the data.
     ///
+    /// The first fragment must have exactly the constant fragment capacity of the resulting `Linear` growth:
+    /// * if the capacity of the vector is a power of two greater than its length, the allocation is adopted as it is
+    ///   and its capacity becomes the constant fragment capacity;
+    /// * otherwise, the constant fragment capacity is the smallest power of two which is greater than the length,
+    ///   and the allocation is resized to this capacity, which may move the data.
+    ///
     /// # Examples
     ///
     /// ```
@@ -21,17 +27,45 @@ impl<T> From<Vec<T>> for SplitVec<T, Linear> {
     /// assert_eq!(split_vec, &['a', 'b', 'c']);
     /// assert_eq!(1, split_vec.fragments().len());
     /// assert!(vec_capacity <= split_vec.capacity());
+    ///
+    /// // allocation of the vector is adopted
+    /// let mut vec = Vec::with_capacity(1024);
+    /// vec.extend(0..100);
+    /// let ptr = vec.as_ptr();
+    ///
+    /// let split_vec: SplitVec<_, Linear> = vec.into();
+    /// assert_eq!(split_vec.fragments()[0].as_ptr(), ptr);
+    /// assert_eq!(split_vec.capacity(), 1024);
     /// ```
-    fn from(value: Vec<T>) -> Self {
+    fn from(mut value: Vec<T>) -> Self {
         let len = value.len();
-        let f = FIXED_CAPACITIES
-            .iter()
-            .enumerate()
-            .find(|(_, fixed_cap)| **fixed_cap > len)
-            .map(|(f, _)| f)
-            .expect("overflow");
+        let f = match value.capacity() {
+            c if c > len && c.is_power_of_two() => c.trailing_zeros() as usize,
+            _ => FIXED_CAPACITIES
+                .iter()
+                .enumerate()
+                .find(|(_, fixed_cap)| **fixed_cap > len)
+                .map(|(f, _)| f)
+                .expect("overflow"),
+        };
+        let capacity = FIXED_CAPACITIES[f];
+
+        match value.capacity().cmp(&capacity) {
+            core::cmp::Ordering::Less => value.reserve_exact(capacity - len),
+            core::cmp::Ordering::Greater => value.shrink_to(capacity),
+            core::cmp::Ordering::Equal => {}
+        }
+
+        let fragment = match value.capacity() == capacity {
+            true => value.into(),
+            false => {
+                let mut fragment = Fragment::new(capacity);
+                fragment.append(&mut value);
+                fragment
+            }
+        };
+
         let growth = Linear::new(f);
-        let fragments = alloc::vec![value.into()];
-        Self::from_raw_parts(len, fragments, growth)
+        Self::from_parts(len, alloc::vec![fragment], growth)
     }
 }
@@ -134,7 +134,12 @@ impl Growth for Linear {
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
 
-        fragments_capacity * self.constant_fragment_capacity
+        fragments_capacity.saturating_mul(self.constant_fragment_capacity)
+    }
+
+    /// Returns the maximum number of fragments such that the total capacity does not exceed `usize::MAX`.
+    fn max_fragments(&self) -> Option<usize> {
+        Some(usize::MAX >> self.constant_fragment_capacity_exponent)
     }
 
     fn required_fragments_len<T>(
@@ -211,7 +216,7 @@ impl<T> SplitVec<T, Linear> {
         let constant_fragment_capacity = FIXED_CAPACITIES[constant_fragment_capacity_exponent];
         let fragments = Fragment::new(constant_fragment_capacity).into_fragments();
         let growth = Linear::new(constant_fragment_capacity_exponent);
-        Self::from_raw_parts(0, fragments, growth)
+        Self::from_parts(0, fragments, growth)
     }
 
     /// Creates a new split vector with `Linear` growth and initial `fragments_capacity`.
@@ -236,7 +241,7 @@ impl<T> SplitVec<T, Linear> {
         let fragments = Fragment::new(constant_fragment_capacity)
             .into_fragments_with_capacity(fragments_capacity);
         let growth = Linear::new(constant_fragment_capacity_exponent);
-        Self::from_raw_parts(0, fragments, growth)
+        Self::from_parts(0, fragments, growth)
     }
 }
 
@@ -271,6 +276,34 @@ mod tests {
         assert_eq!(None, get_none(16));
     }
 
+    #[test]
+    fn get_fragment_and_inner_indices_near_max() {
+        for exponent in [1, 5, FIXED_CAPACITIES.len() - 1] {
+            let growth = Linear::new(exponent);
+            let capacity = FIXED_CAPACITIES[exponent];
+
+            for index in [
+                usize::MAX / 2 - 1,
+                usize::MAX / 2,
+                usize::MAX / 2 + 1,
+                usize::MAX,
+            ] {
+                let (f, i) = growth.get_fragment_and_inner_indices_unchecked(index);
+                assert_eq!(f, index >> exponent);
+                assert_eq!(i, index & (capacity - 1));
+                assert_eq!(f * capacity + i, index);
+
+                assert_eq!(
+                    GrowthWithConstantTimeAccess::get_ptr::<char>(&growth, &[], index),
+                    None
+                );
+            }
+
+            let max_fragments = growth.max_fragments().expect("is-some");
+            assert!(max_fragments.checked_mul(capacity).is_some());
+        }
+    }
+
     #[test]
     fn get_fragment_and_inner_indices_exhaustive() {
         let growth = Linear::new(5);
@@ -357,4 +390,37 @@ mod tests {
         assert_eq!(num_fragments(32 * 7), Ok(7));
         assert_eq!(num_fragments(32 * 7 + 1), Ok(8));
     }
+
+    #[test]
+    fn capacities_near_usize_max() {
+        let growth = Linear::new(10);
+        let fragments: alloc::vec::Vec<Fragment<char>> = alloc::vec![];
+
+        let max_cap =
+            |fragments_capacity| growth.maximum_concurrent_capacity(&fragments, fragments_capacity);
+        assert_eq!(max_cap(usize::MAX >> 10), (usize::MAX >> 10) << 10);
+        assert_eq!(max_cap((usize::MAX >> 10) + 1), usize::MAX);
+        assert_eq!(max_cap(usize::MAX), usize::MAX);
+
+        assert_eq!(
+            growth.required_fragments_len(&fragments, usize::MAX),
+            Ok((usize::MAX >> 10) + 1)
+        );
+
+        assert_eq!(
+            growth.get_fragment_and_inner_indices_unchecked(usize::MAX),
+            (usize::MAX >> 10, 1023)
+        );
+        assert_eq!(
+            growth.get_fragment_and_inner_indices::<char>(usize::MAX, &fragments, usize::MAX),
+            None
+        );
+        assert!(<Linear as Growth>::get_ptr(&growth, &fragments, usize::MAX).is_none());
+    }
+
+    #[test]
+    fn max_fragments() {
+        assert_eq!(Linear::new(10).max_fragments(), Some(usize::MAX >> 10));
+        assert_eq!(Linear::new(1).max_fragments(), Some(usize::MAX >> 1));
+    }
 }
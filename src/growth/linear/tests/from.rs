@@ -1,16 +1,42 @@
 use crate::*;
 use alloc::vec::Vec;
 
-fn validate_clone(original: Vec<usize>, split_vec: SplitVec<usize, Doubling>) {
+fn validate(original: Vec<usize>, mut split_vec: SplitVec<usize, Linear>) {
     assert_eq!(split_vec, &original);
     assert!(original.capacity() <= split_vec.capacity());
+    for (i, x) in original.iter().enumerate() {
+        assert_eq!(split_vec.get(i), Some(x));
+    }
+
+    let len = original.len();
+    for i in 0..100 {
+        split_vec.push(len + i);
+    }
+    for i in 0..(len + 100) {
+        let expected = original.get(i).copied().unwrap_or(i);
+        assert_eq!(split_vec.get(i), Some(&expected));
+    }
 }
 
 #[test]
 fn from_vec_medium() {
     for len in 0..135 {
         let vec: Vec<_> = (0..len).collect();
-        let split_vec: SplitVec<_, Doubling> = vec.clone().into();
-        validate_clone(vec, split_vec);
+        let split_vec: SplitVec<_, Linear> = vec.clone().into();
+        validate(vec, split_vec);
+    }
+}
+
+#[test]
+fn from_vec_adopts_power_of_two_capacity() {
+    for (len, capacity) in [(0, 8), (3, 4), (100, 128), (100, 1024)] {
+        let mut vec = Vec::with_capacity(capacity);
+        vec.extend(0..len);
+        let ptr = vec.as_ptr();
+
+        let split_vec: SplitVec<_, Linear> = vec.into();
+        assert_eq!(split_vec.fragments()[0].as_ptr(), ptr);
+        assert_eq!(split_vec.capacity(), capacity);
+        validate((0..len).collect(), split_vec);
     }
 }
@@ -1,4 +1,10 @@
+pub(crate) mod conformance;
 pub(crate) mod doubling;
+pub(crate) mod doubling_until;
+pub(crate) mod exponential;
+pub(crate) mod functional;
+pub(crate) mod growth_error;
 pub(crate) mod growth_trait;
 pub(crate) mod linear;
+pub(crate) mod recommend;
 pub(crate) mod recursive;
@@ -0,0 +1,130 @@
+use crate::{Doubling, Linear, Recursive};
+
+/// The dominant way the elements of a split vector are expected to be accessed.
+///
+/// Used by [`recommend_growth`] to pick a growth strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessPattern {
+    /// Elements are mostly accessed by indices in arbitrary order.
+    RandomHeavy,
+    /// Elements are mostly iterated in order from the first to the last.
+    SequentialHeavy,
+    /// The vector is mostly built up by appending other vectors to it.
+    AppendHeavy,
+}
+
+/// A configured growth strategy recommended by [`recommend_growth`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrowthChoice {
+    /// Recommends `SplitVec<T, Doubling>`.
+    Doubling(Doubling),
+    /// Recommends `SplitVec<T, Linear>` with the given growth.
+    Linear(Linear),
+    /// Recommends `SplitVec<T, Recursive>`.
+    Recursive(Recursive),
+}
+
+/// Minimum exponent of the fragment capacity recommended for the linear growth.
+const MIN_LINEAR_EXPONENT: usize = 4;
+/// Maximum exponent of the fragment capacity recommended for the linear growth.
+const MAX_LINEAR_EXPONENT: usize = 20;
+/// Targeted number of fragments of the linear growth, bounding the unused capacity to a fraction of the length.
+const TARGET_NUM_LINEAR_FRAGMENTS: usize = 8;
+
+/// Recommends a growth strategy for a split vector which is expected to hold around `expected_len` elements
+/// and to be used with the given `access_pattern`.
+///
+/// The recommendation follows the benchmarks of the crate:
+/// * [`AccessPattern::RandomHeavy`] => `Doubling`, which provides the fastest constant time random access
+///   among the growth strategies.
+/// * [`AccessPattern::SequentialHeavy`] => `Linear` with a fragment capacity such that `expected_len` elements
+///   fit in a few fragments. Serial access performance is similar for all strategies, while linear growth
+///   keeps the unused capacity at a small fraction of the length.
+/// * [`AccessPattern::AppendHeavy`] => `Recursive`, which appends other vectors in constant time
+///   without copying their elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let choice = recommend_growth(1_000, AccessPattern::SequentialHeavy);
+/// match choice {
+///     GrowthChoice::Linear(growth) => {
+///         let mut vec: SplitVec<usize, Linear> = SplitVec::with_growth(growth);
+///         vec.extend(0..1_000);
+///         assert!(vec.fragments().len() <= 8);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert_eq!(
+///     recommend_growth(1_000, AccessPattern::RandomHeavy),
+///     GrowthChoice::Doubling(Doubling)
+/// );
+/// assert_eq!(
+///     recommend_growth(1_000, AccessPattern::AppendHeavy),
+///     GrowthChoice::Recursive(Recursive)
+/// );
+/// ```
+pub fn recommend_growth(expected_len: usize, access_pattern: AccessPattern) -> GrowthChoice {
+    match access_pattern {
+        AccessPattern::RandomHeavy => GrowthChoice::Doubling(Doubling),
+        AccessPattern::AppendHeavy => GrowthChoice::Recursive(Recursive),
+        AccessPattern::SequentialHeavy => {
+            let fragment_capacity = expected_len.div_ceil(TARGET_NUM_LINEAR_FRAGMENTS);
+            let exponent = fragment_capacity
+                .checked_next_power_of_two()
+                .map(|x| x.trailing_zeros() as usize)
+                .unwrap_or(MAX_LINEAR_EXPONENT)
+                .clamp(MIN_LINEAR_EXPONENT, MAX_LINEAR_EXPONENT);
+            GrowthChoice::Linear(Linear::new(exponent))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Growth;
+
+    fn linear_exponent(expected_len: usize) -> usize {
+        let choice = recommend_growth(expected_len, AccessPattern::SequentialHeavy);
+        assert!(matches!(choice, GrowthChoice::Linear(_)));
+        match choice {
+            GrowthChoice::Linear(linear) => {
+                linear.first_fragment_capacity().trailing_zeros() as usize
+            }
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn recommend_growth_random_and_append() {
+        for len in [0, 1, 100, 1 << 30, usize::MAX] {
+            assert_eq!(
+                recommend_growth(len, AccessPattern::RandomHeavy),
+                GrowthChoice::Doubling(Doubling)
+            );
+            assert_eq!(
+                recommend_growth(len, AccessPattern::AppendHeavy),
+                GrowthChoice::Recursive(Recursive)
+            );
+        }
+    }
+
+    #[test]
+    fn recommend_growth_sequential() {
+        assert_eq!(linear_exponent(0), MIN_LINEAR_EXPONENT);
+        assert_eq!(linear_exponent(100), MIN_LINEAR_EXPONENT);
+        assert_eq!(linear_exponent(1 << 10), 7);
+        assert_eq!(linear_exponent((1 << 10) + 1), 8);
+        assert_eq!(linear_exponent(1 << 30), MAX_LINEAR_EXPONENT);
+        assert_eq!(linear_exponent(usize::MAX), MAX_LINEAR_EXPONENT);
+
+        for len in [200, 1000, 4096, 100_000] {
+            let capacity = 1 << linear_exponent(len);
+            assert!(len.div_ceil(capacity) <= TARGET_NUM_LINEAR_FRAGMENTS);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Fragment, Recursive, SplitVec};
+use alloc::vec::Vec;
+
+impl SplitVec<usize, Recursive> {
+    /// Creates a split vector with `Recursive` growth having exactly the given sequence of fragment lengths.
+    ///
+    /// Each fragment is created with a capacity equal to its length; i.e., all fragments are full.
+    /// Elements of the vector are their flat indices; i.e., `vec[i] == i` for all `i`.
+    ///
+    /// This constructor is available with the **testing** feature and is meant to make it easy to reproduce
+    /// and regression-test cases which depend on specific fragment boundary positions.
+    ///
+    /// If `fragment_lens` is empty, an empty vector with default recursive growth is created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `fragment_lens` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = SplitVec::from_fragment_lens_for_tests(&[3, 1, 2]);
+    ///
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments().len(), 3);
+    /// assert_eq!(vec.fragments()[0], &[0, 1, 2]);
+    /// assert_eq!(vec.fragments()[1], &[3]);
+    /// assert_eq!(vec.fragments()[2], &[4, 5]);
+    /// ```
+    pub fn from_fragment_lens_for_tests(fragment_lens: &[usize]) -> Self {
+        if fragment_lens.is_empty() {
+            return Self::with_recursive_growth();
+        }
+
+        let mut len = 0;
+        let mut fragments = Vec::with_capacity(fragment_lens.len());
+        for &fragment_len in fragment_lens {
+            assert!(fragment_len > 0, "fragment lengths must be positive");
+            let mut fragment = Fragment::new(fragment_len);
+            fragment.extend(len..(len + fragment_len));
+            len += fragment_len;
+            fragments.push(fragment);
+        }
+
+        Self::from_parts(len, fragments, Recursive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orx_pinned_vec::PinnedVec;
+
+    #[test]
+    fn from_empty_fragment_lens() {
+        let vec = SplitVec::from_fragment_lens_for_tests(&[]);
+        assert!(vec.is_empty());
+        assert_eq!(vec.fragments().len(), 1);
+    }
+
+    #[test]
+    fn from_fragment_lens() {
+        let lens = [1, 7, 2, 2, 5];
+        let mut vec = SplitVec::from_fragment_lens_for_tests(&lens);
+
+        assert_eq!(vec.len(), 17);
+        assert_eq!(
+            vec.fragments().iter().map(|x| x.len()).collect::<Vec<_>>(),
+            lens
+        );
+        assert_eq!(
+            vec.fragments()
+                .iter()
+                .map(|x| x.capacity())
+                .collect::<Vec<_>>(),
+            lens
+        );
+        for i in 0..vec.len() {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+
+        vec.push(17);
+        assert_eq!(vec.fragments().len(), lens.len() + 1);
+        assert_eq!(vec, (0..18).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_fragment_lens_with_zero() {
+        let _ = SplitVec::from_fragment_lens_for_tests(&[2, 0, 3]);
+    }
+}
@@ -24,7 +24,7 @@ impl<T> From<SplitVec<T, Doubling>> for SplitVec<T, Recursive> {
     /// assert_eq!(split_vec_recursive, &['a', 'b', 'c']);
     /// ```
     fn from(value: SplitVec<T, Doubling>) -> Self {
-        Self::from_raw_parts(value.len, value.fragments, Recursive)
+        Self::from_parts(value.len, value.fragments, Recursive)
     }
 }
 
@@ -51,11 +51,11 @@ impl<T> From<SplitVec<T, Linear>> for SplitVec<T, Recursive> {
     /// assert_eq!(split_vec_recursive, &['a', 'b', 'c']);
     /// ```
     fn from(value: SplitVec<T, Linear>) -> Self {
-        Self::from_raw_parts(value.len, value.fragments, Recursive)
+        Self::from_parts(value.len, value.fragments, Recursive)
     }
 }
 
-impl<T: Clone> From<Vec<T>> for SplitVec<T, Recursive> {
+impl<T> From<Vec<T>> for SplitVec<T, Recursive> {
     /// Converts a `Vec` into a `SplitVec`.
     ///
     /// # Examples
@@ -73,7 +73,7 @@ impl<T: Clone> From<Vec<T>> for SplitVec<T, Recursive> {
     /// assert!(vec_capacity <= split_vec.capacity());
     /// ```
     fn from(value: Vec<T>) -> Self {
-        SplitVec::from_raw_parts(value.len(), alloc::vec![value.into()], Recursive)
+        SplitVec::from_parts(value.len(), alloc::vec![value.into()], Recursive)
     }
 }
 
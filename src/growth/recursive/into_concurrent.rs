@@ -0,0 +1,85 @@
+use crate::{ConcurrentSplitVec, Doubling, Recursive, SplitVec};
+
+impl<T> SplitVec<T, Recursive> {
+    /// Converts the vector into a concurrent vector with `Doubling` growth.
+    ///
+    /// `Recursive` growth does not provide constant time random access; hence, the vector cannot be directly
+    /// converted into a `ConcurrentSplitVec`. Instead, the fragments are first normalized into the fragments
+    /// of the `Doubling` growth (see `From<SplitVec<T, Recursive>> for SplitVec<T, Doubling>`):
+    /// leading fragments which already have the doubling capacities are adopted, and the elements are moved
+    /// only when fragment capacities do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend(0..10);
+    /// vec.append(vec![10, 11, 12]);
+    ///
+    /// let con_vec = vec.into_concurrent_normalized();
+    /// assert_eq!(con_vec.capacity(), 28);
+    ///
+    /// let vec = unsafe { con_vec.into_inner(13) };
+    /// assert_eq!(vec, (0..13).collect::<Vec<_>>());
+    /// ```
+    pub fn into_concurrent_normalized(self) -> ConcurrentSplitVec<T, Doubling> {
+        SplitVec::<T, Doubling>::from(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+
+    #[test]
+    fn into_concurrent_normalized() {
+        let mut vec: SplitVec<String, Recursive> = SplitVec::with_recursive_growth();
+        let mut expected = Vec::new();
+        for (i, n) in [0, 3, 17, 0, 1, 40, 8].into_iter().enumerate() {
+            let fragment: Vec<_> = (0..n).map(|x| (i * 100 + x).to_string()).collect();
+            expected.extend(fragment.iter().cloned());
+            vec.append(fragment);
+        }
+        vec.extend((1000..1100).map(|x| x.to_string()));
+        expected.extend((1000..1100).map(|x| x.to_string()));
+
+        let len = vec.len();
+        let con_vec = vec.into_concurrent_normalized();
+        assert!(con_vec.capacity() >= len);
+        con_vec.grow_to(len + 100).expect("within maximum capacity");
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        assert_eq!(vec, expected);
+        for (f, fragment) in vec.fragments().iter().enumerate() {
+            assert_eq!(fragment.capacity(), 4 * usize::pow(2, f as u32));
+        }
+    }
+
+    #[test]
+    fn into_concurrent_normalized_adopts_doubling_fragments() {
+        let doubling: SplitVec<_, Doubling> = (0..50).collect();
+        let pointers: Vec<_> = doubling.fragments().iter().map(|x| x.as_ptr()).collect();
+
+        let vec: SplitVec<_, Recursive> = doubling.into();
+        let con_vec = vec.into_concurrent_normalized();
+
+        let vec = unsafe { con_vec.into_inner(50) };
+        assert_eq!(vec, (0..50).collect::<Vec<_>>());
+        let new_pointers: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+        assert_eq!(pointers, new_pointers);
+    }
+
+    #[test]
+    fn into_concurrent_normalized_empty() {
+        let vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        let con_vec = vec.into_concurrent_normalized();
+        assert_eq!(con_vec.capacity(), 4);
+        let vec = unsafe { con_vec.into_inner(0) };
+        assert!(vec.is_empty());
+    }
+}
@@ -1,6 +1,9 @@
-mod append;
+#[cfg(feature = "testing")]
+mod for_tests;
 mod from;
+mod into_concurrent;
 mod recursive_growth;
+mod split_off;
 
 #[cfg(test)]
 mod tests;
@@ -58,6 +58,11 @@ impl Growth for Recursive {
         Doubling.new_fragment_capacity_from(fragment_capacities)
     }
 
+    /// Returns true since the `Recursive` growth locates the elements by the actual fragments of the vector.
+    fn accepts_arbitrary_fragment_capacities(&self) -> bool {
+        true
+    }
+
     fn maximum_concurrent_capacity<T>(
         &self,
         fragments: &[Fragment<T>],
@@ -65,14 +70,19 @@ impl Growth for Recursive {
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
 
-        let current_capacity = fragments.iter().map(|x| x.capacity()).sum();
+        let current_capacity = fragments
+            .iter()
+            .fold(0usize, |sum, x| sum.saturating_add(x.capacity()));
         let mut last_capacity = fragments.last().map(|x| x.capacity()).unwrap_or(2);
 
         let mut total_capacity = current_capacity;
 
         for _ in fragments.len()..fragments_capacity {
-            last_capacity *= 2;
-            total_capacity += last_capacity;
+            last_capacity = last_capacity.saturating_mul(2);
+            total_capacity = total_capacity.saturating_add(last_capacity);
+            if total_capacity == usize::MAX {
+                break;
+            }
         }
 
         total_capacity
@@ -394,4 +404,28 @@ mod tests {
         assert_eq!(num_fragments(154), Ok(5));
         assert_eq!(num_fragments(155), Ok(6));
     }
+
+    #[test]
+    fn capacities_near_usize_max() {
+        let growth = Recursive;
+        let fragments: alloc::vec::Vec<Fragment<char>> = alloc::vec![];
+
+        let max_cap =
+            |fragments_capacity| growth.maximum_concurrent_capacity(&fragments, fragments_capacity);
+        assert_eq!(max_cap(usize::BITS as usize + 1), usize::MAX);
+        assert_eq!(max_cap(usize::MAX), usize::MAX);
+
+        assert!(growth
+            .required_fragments_len(&fragments, usize::MAX)
+            .is_err());
+        assert_eq!(
+            growth.new_fragment_capacity_from([usize::MAX].into_iter()),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn max_fragments() {
+        assert_eq!(Recursive.max_fragments(), None);
+    }
 }
@@ -0,0 +1,129 @@
+use crate::{Fragment, Recursive, SplitVec};
+use alloc::vec::Vec;
+
+impl<T> SplitVec<T, Recursive> {
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range `[at, len)`;
+    /// after the call, this vector is left containing the elements `[0, at)`.
+    ///
+    /// Since `Recursive` growth allows fragments of arbitrary capacities, the fragments after `at` are transferred
+    /// to the returned vector without memory copies.
+    /// Only the elements of the fragment containing `at`, which are after `at`, are moved into a new fragment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend(0..12);
+    /// assert_eq!(vec.fragments().len(), 2);
+    ///
+    /// let ptr_of_last = vec.last().unwrap() as *const i32;
+    ///
+    /// let tail = vec.split_off(3);
+    /// assert_eq!(vec, [0, 1, 2]);
+    /// assert_eq!(tail, [3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    ///
+    /// // the second fragment is transferred as is
+    /// assert_eq!(tail.last().unwrap() as *const i32, ptr_of_last);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        self.invalidate_stable_indices();
+        assert!(at <= self.len, "split_off index is out of bounds");
+
+        let Some((f, i)) = self.get_fragment_and_inner_indices(at) else {
+            return self.split_off_by_moving(at);
+        };
+        if f == 0 && i == 0 {
+            return self.split_off_by_moving(at);
+        }
+
+        let mut fragments: Vec<Fragment<T>> = Vec::new();
+        if i > 0 {
+            let fragment = &mut self.fragments[f];
+            let count = fragment.len() - i;
+            let mut tail = Fragment::new(count);
+            // SAFETY: elements i..len of the fragment are moved into the tail fragment, and forgotten by the fragment
+            unsafe {
+                core::ptr::copy_nonoverlapping(fragment.as_ptr().add(i), tail.as_mut_ptr(), count);
+                tail.set_len(count);
+                fragment.set_len(i);
+            }
+            fragments.push(tail);
+        }
+
+        let first_transferred = match i {
+            0 => f,
+            _ => f + 1,
+        };
+        let num_transferred = self.fragments.len() - first_transferred;
+        let position = fragments.len();
+        for _ in 0..num_transferred {
+            if let Some(fragment) = self.pop_fragment() {
+                fragments.insert(position, fragment);
+            }
+        }
+
+        let mut other = SplitVec::from_parts(self.len - at, fragments, Recursive);
+        other.set_memory_policy(self.memory_policy);
+        self.len = at;
+        other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn split_off() {
+        let n = 97;
+        for at in [0, 1, 3, 4, 12, 60, 96, 97] {
+            let mut vec: SplitVec<String, Recursive> = SplitVec::with_recursive_growth();
+            vec.extend((0..n).map(|x| x.to_string()));
+            let mut expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+            let mut tail = vec.split_off(at);
+            let mut expected_tail = expected.split_off(at);
+            assert_eq!(vec, expected);
+            assert_eq!(tail, expected_tail);
+            assert_eq!(
+                vec.capacity(),
+                vec.fragments().iter().map(|x| x.capacity()).sum()
+            );
+            assert_eq!(
+                tail.capacity(),
+                tail.fragments().iter().map(|x| x.capacity()).sum()
+            );
+
+            vec.push("x".to_string());
+            expected.push("x".to_string());
+            tail.push("y".to_string());
+            expected_tail.push("y".to_string());
+            assert_eq!(vec, expected);
+            assert_eq!(tail, expected_tail);
+        }
+    }
+
+    #[test]
+    fn split_off_transfers_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(alloc::vec![0, 1, 2]);
+        vec.append(alloc::vec![3, 4]);
+        vec.append(alloc::vec![5, 6, 7]);
+        let ptr = vec.fragments()[vec.fragments().len() - 1].as_ptr();
+
+        let tail = vec.split_off(3);
+        assert_eq!(vec, [0, 1, 2]);
+        assert_eq!(tail, [3, 4, 5, 6, 7]);
+        assert_eq!(tail.fragments()[1].as_ptr(), ptr);
+    }
+}
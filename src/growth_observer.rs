@@ -0,0 +1,161 @@
+use crate::{Growth, SplitVec};
+
+/// An event on the fragments of a split vector reported to its growth observer.
+///
+/// See [`SplitVec::set_growth_observer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrowthEvent {
+    /// A fragment is added to the vector, either by allocating a new fragment or by using a fragment allocated ahead.
+    FragmentAdded {
+        /// Index of the added fragment.
+        fragment_index: usize,
+        /// Capacity of the added fragment.
+        capacity: usize,
+    },
+    /// A fragment is removed from the vector; it is either dropped or kept for reuse depending on the memory policy.
+    FragmentRemoved {
+        /// Index of the fragment before it is removed.
+        fragment_index: usize,
+        /// Capacity of the removed fragment.
+        capacity: usize,
+    },
+}
+
+/// Observer callback stored by the split vector.
+#[cfg(feature = "growth_observer")]
+pub(crate) type GrowthObserver = alloc::boxed::Box<dyn Fn(GrowthEvent) + Send + Sync>;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Reports the `event` to the growth observer, if any; no-op unless the **growth_observer** feature is enabled.
+    #[inline(always)]
+    pub(crate) fn on_growth_event(&self, event: GrowthEvent) {
+        #[cfg(feature = "growth_observer")]
+        if let Some(observer) = &self.growth_observer {
+            observer(event);
+        }
+        #[cfg(not(feature = "growth_observer"))]
+        let _ = event;
+    }
+
+    /// Sets the `observer` which is invoked whenever a fragment is added to or removed from the vector,
+    /// with the index and capacity of the fragment; replaces the previous observer if any.
+    ///
+    /// This is useful to log or trace the memory growth of vectors in long-running applications.
+    ///
+    /// The observer is not cloned together with the vector.
+    ///
+    /// This method is available with the **growth_observer** feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let events = Arc::new(Mutex::new(Vec::new()));
+    /// let events_of_observer = events.clone();
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.set_growth_observer(move |event| events_of_observer.lock().unwrap().push(event));
+    ///
+    /// vec.extend(0..6);
+    /// vec.truncate(3);
+    ///
+    /// assert_eq!(
+    ///     events.lock().unwrap().as_slice(),
+    ///     [
+    ///         GrowthEvent::FragmentAdded { fragment_index: 1, capacity: 4 },
+    ///         GrowthEvent::FragmentRemoved { fragment_index: 1, capacity: 4 },
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "growth_observer")]
+    pub fn set_growth_observer<F>(&mut self, observer: F)
+    where
+        F: Fn(GrowthEvent) + Send + Sync + 'static,
+    {
+        self.growth_observer = Some(alloc::boxed::Box::new(observer));
+    }
+
+    /// Removes the growth observer of the vector, if any.
+    ///
+    /// This method is available with the **growth_observer** feature.
+    #[cfg(feature = "growth_observer")]
+    pub fn clear_growth_observer(&mut self) {
+        self.growth_observer = None;
+    }
+}
+
+#[cfg(all(test, feature = "growth_observer"))]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use std::sync::{Arc, Mutex};
+
+    fn observe<G: Growth>(vec: &mut SplitVec<usize, G>) -> Arc<Mutex<Vec<GrowthEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_of_observer = events.clone();
+        vec.set_growth_observer(move |e| events_of_observer.lock().expect("is-ok").push(e));
+        events
+    }
+
+    #[test]
+    fn growth_observer() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let events = observe(&mut vec);
+            let initial: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+
+            vec.extend(0..142);
+            let expected: Vec<_> = vec
+                .fragments()
+                .iter()
+                .enumerate()
+                .skip(initial.len())
+                .map(|(f, x)| GrowthEvent::FragmentAdded {
+                    fragment_index: f,
+                    capacity: x.capacity(),
+                })
+                .collect();
+            assert_eq!(events.lock().expect("is-ok").as_slice(), expected);
+
+            events.lock().expect("is-ok").clear();
+            let capacities: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+            vec.clear();
+            let expected: Vec<_> = capacities
+                .iter()
+                .enumerate()
+                .skip(1)
+                .rev()
+                .map(|(f, c)| GrowthEvent::FragmentRemoved {
+                    fragment_index: f,
+                    capacity: *c,
+                })
+                .collect();
+            assert_eq!(events.lock().expect("is-ok").as_slice(), expected);
+
+            vec.clear_growth_observer();
+            vec.extend(0..142);
+            assert_eq!(events.lock().expect("is-ok").len(), expected.len());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn growth_observer_on_bulk_changes() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(0..10);
+        let events = observe(&mut vec);
+
+        vec.clear_keeping_capacity();
+        let removed = |f| GrowthEvent::FragmentRemoved {
+            fragment_index: f,
+            capacity: 4,
+        };
+        assert_eq!(
+            events.lock().expect("is-ok").as_slice(),
+            [removed(2), removed(1)]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Inserts clones of the elements of the `slice` at position `index`, shifting all elements after it to the right.
+    ///
+    /// Rather than repeated single-element inserts, a gap of the length of the slice is opened once:
+    /// the fragments required for the new length are allocated up front, and the tail of the vector
+    /// is shifted across the fragments a single time, piecewise by memory moves.
+    ///
+    /// Note that the elements after `index` are moved; therefore, this method does not keep these elements pinned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([0, 1, 2, 3, 4]);
+    ///
+    /// vec.insert_slice(2, &[7, 8, 9]);
+    /// assert_eq!(vec, [0, 1, 7, 8, 9, 2, 3, 4]);
+    ///
+    /// vec.insert_slice(8, &[5]);
+    /// assert_eq!(vec, [0, 1, 7, 8, 9, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_slice(&mut self, index: usize, slice: &[T])
+    where
+        T: Clone,
+    {
+        let mut values = slice.iter();
+        self.insert_with(index, slice.len(), |gap| {
+            for (slot, value) in gap.iter_mut().zip(values.by_ref()) {
+                slot.write(value.clone());
+            }
+        });
+    }
+
+    /// Inserts the elements yielded by `iter` at position `index`, shifting all elements after it to the right.
+    ///
+    /// The items are first collected, and then, similar to [`SplitVec::insert_slice`], a gap of the number of items
+    /// is opened once by shifting the tail of the vector across the fragments a single time.
+    ///
+    /// Note that the elements after `index` are moved; therefore, this method does not keep these elements pinned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend(['a', 'e']);
+    ///
+    /// vec.insert_many(1, ['b', 'c', 'd']);
+    /// assert_eq!(vec, ['a', 'b', 'c', 'd', 'e']);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, index: usize, iter: I) {
+        let mut values: Vec<T> = iter.into_iter().collect();
+        let mut begin = 0;
+        let src = values.as_ptr();
+        self.insert_with(index, values.len(), |gap| {
+            let dst = gap.as_mut_ptr() as *mut T;
+            // SAFETY: each value is moved out exactly once; `values` forgets them below
+            unsafe { core::ptr::copy_nonoverlapping(src.add(begin), dst, gap.len()) };
+            begin += gap.len();
+        });
+        unsafe { values.set_len(0) };
+    }
+
+    /// Opens a gap of `n` positions at `index`, and calls `fill` on the consecutive pieces of the gap
+    /// which are required to initialize all positions of the given pieces.
+    ///
+    /// During the operation, the vector logically holds only the elements before `index`; hence, if `fill` panics,
+    /// the elements after `index` and the values written into the gap are leaked rather than dropped twice.
+    fn insert_with<F>(&mut self, index: usize, n: usize, mut fill: F)
+    where
+        F: FnMut(&mut [MaybeUninit<T>]),
+    {
+        self.invalidate_stable_indices();
+        assert!(index <= self.len, "insertion index is out of bounds");
+        if n == 0 {
+            return;
+        }
+
+        let old_len = self.len;
+        let new_len = old_len + n;
+
+        // fragments before the last one are used up to their lengths; the rest up to their capacities
+        if self.fragments.is_empty() {
+            self.add_fragment();
+        }
+        let last = self.fragments.len() - 1;
+        let mut slots: Vec<usize> = self.fragments.iter().map(|x| x.len()).collect();
+        slots[last] = self.fragments[last].capacity();
+        let mut num_slots: usize = slots.iter().sum();
+        while num_slots < new_len {
+            let capacity = self.add_fragment();
+            slots.push(capacity);
+            num_slots += capacity;
+        }
+
+        // hide the tail from the vector until the gap is filled
+        let (fi, ii) = position_in_slots(&slots, index);
+        for f in fi..self.fragments.len() {
+            let len = match f == fi {
+                true => ii,
+                false => 0,
+            };
+            unsafe { self.fragments[f].set_len(len) };
+        }
+        self.len = index;
+
+        // move the tail to the right, back to front
+        let mut src = position_in_slots(&slots, old_len);
+        let mut dst = position_in_slots(&slots, new_len);
+        let mut remaining = old_len - index;
+        while remaining > 0 {
+            while src.1 == 0 {
+                src = (src.0 - 1, slots[src.0 - 1]);
+            }
+            while dst.1 == 0 {
+                dst = (dst.0 - 1, slots[dst.0 - 1]);
+            }
+
+            let count = src.1.min(dst.1).min(remaining);
+            src.1 -= count;
+            dst.1 -= count;
+            let src_ptr = unsafe { self.fragments[src.0].as_ptr().add(src.1) };
+            let dst_ptr = unsafe { self.fragments[dst.0].as_mut_ptr().add(dst.1) };
+            // SAFETY: both ranges are within the capacities of the fragments; ptr::copy allows overlapping regions
+            unsafe { core::ptr::copy(src_ptr, dst_ptr, count) };
+            remaining -= count;
+        }
+
+        // fill the gap, front to back
+        // positions of the gap are at the ends of the fragments; i.e., the beginnings of their spare capacities
+        let (mut f, mut i) = (fi, ii);
+        let mut remaining = n;
+        while remaining > 0 {
+            let count = (slots[f] - i).min(remaining);
+            let gap = &mut self.fragments[f].spare_capacity_mut()[..count];
+            fill(gap);
+            remaining -= count;
+            (f, i) = (f + 1, 0);
+        }
+
+        // reveal the gap and the tail
+        let mut to_place = new_len - index + ii;
+        for (f, slot_len) in slots.iter().enumerate().skip(fi) {
+            let len = (*slot_len).min(to_place);
+            // SAFETY: the first `len` positions of the fragment are initialized by the moves and fill
+            unsafe { self.fragments[f].set_len(len) };
+            to_place -= len;
+        }
+        self.len = new_len;
+    }
+}
+
+/// Returns the fragment and inner indices of the `index`-th slot with respect to the given numbers of `slots` of the
+/// fragments; `index` equal to the total number of slots maps to the end of the last fragment.
+fn position_in_slots(slots: &[usize], index: usize) -> (usize, usize) {
+    let mut begin = 0;
+    for (f, num_slots) in slots.iter().enumerate() {
+        let end = begin + num_slots;
+        if index < end {
+            return (f, index - begin);
+        }
+        begin = end;
+    }
+    let f = slots.len() - 1;
+    (f, slots[f])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn insert_slice() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 97;
+            for index in [0, 1, 3, 4, 12, 60, 96, 97] {
+                for num_insert in [0, 1, 5, 100] {
+                    vec.clear();
+                    vec.extend((0..n).map(|x| x.to_string()));
+                    let mut expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+                    let values: Vec<_> = (0..num_insert).map(|x| (1000 + x).to_string()).collect();
+                    vec.insert_slice(index, &values);
+                    expected.splice(index..index, values);
+                    assert_eq!(vec, expected);
+
+                    vec.push("x".to_string());
+                    expected.push("x".to_string());
+                    assert_eq!(vec, expected);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn insert_many() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let mut expected = Vec::new();
+            vec.insert_many(0, ["a".to_string()]);
+            expected.insert(0, "a".to_string());
+
+            for i in 0..40 {
+                let index = (i * 7) % (vec.len() + 1);
+                let values = (0..(i % 9)).map(|x| (i * 100 + x).to_string());
+                vec.insert_many(index, values.clone());
+                expected.splice(index..index, values);
+                assert_eq!(vec, expected);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn insert_many_with_empty_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.append((0..3).collect::<Vec<_>>());
+        vec.append(Vec::<usize>::new());
+        vec.append((3..7).collect::<Vec<_>>());
+
+        vec.insert_many(2, [10, 11, 12]);
+        assert_eq!(vec, [0, 1, 10, 11, 12, 2, 3, 4, 5, 6]);
+        vec.insert_slice(10, &[20]);
+        assert_eq!(vec, [0, 1, 10, 11, 12, 2, 3, 4, 5, 6, 20]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_slice_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.insert_slice(11, &[1]);
+    }
+}
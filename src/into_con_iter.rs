@@ -0,0 +1,366 @@
+use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Consumes the vector and creates a concurrent iterator yielding its elements by value.
+    ///
+    /// The concurrent iterator can be shared among threads; each thread pulls either single elements by
+    /// [`IntoConIter::next`] or ranges of consecutive elements by [`IntoConIter::next_chunk`].
+    /// Every element is yielded exactly once, and the elements which are not pulled are dropped together
+    /// with the iterator.
+    ///
+    /// The elements are not moved into a new allocation; they are read directly from the fragments of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend((0..100).map(|x| x.to_string()));
+    /// vec.append(vec!["a".to_string(), "b".to_string()]);
+    ///
+    /// let con_iter = vec.into_con_iter();
+    /// let num_chars = std::sync::atomic::AtomicUsize::new(0);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| {
+    ///             while let Some(chunk) = con_iter.next_chunk(8) {
+    ///                 let n: usize = chunk.map(|x| x.len()).sum();
+    ///                 num_chars.fetch_add(n, std::sync::atomic::Ordering::Relaxed);
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(num_chars.into_inner(), 10 + 2 * 90 + 2);
+    /// ```
+    pub fn into_con_iter(self) -> IntoConIter<T> {
+        IntoConIter::new(self.fragments)
+    }
+}
+
+/// A concurrent iterator that moves out of a vector.
+///
+/// This struct is created by the [`SplitVec::into_con_iter`] method.
+///
+/// Positions of the elements are handed out by atomically advancing a shared counter; therefore, the iterator
+/// can be pulled from multiple threads through a shared reference.
+pub struct IntoConIter<T> {
+    /// Fragments with lengths set to zero, which only own the allocations; the elements are read through `ptrs`.
+    fragments: Vec<Fragment<T>>,
+    ptrs: Vec<*const T>,
+    /// Position of the first element of each non-empty fragment, followed by the total length.
+    begins: Vec<usize>,
+    counter: AtomicUsize,
+}
+
+// SAFETY: each element is read out exactly once by the thread which reserved its position; hence, sharing the
+// iterator is equivalent to sending the elements to the threads pulling them.
+unsafe impl<T: Send> Sync for IntoConIter<T> {}
+
+// SAFETY: the iterator owns the fragments and the elements.
+unsafe impl<T: Send> Send for IntoConIter<T> {}
+
+impl<T> IntoConIter<T> {
+    fn new(source: Vec<Fragment<T>>) -> Self {
+        let mut fragments = Vec::with_capacity(source.len());
+        let mut ptrs = Vec::with_capacity(source.len());
+        let mut begins = Vec::with_capacity(source.len() + 1);
+        let mut len = 0;
+
+        for mut fragment in source.into_iter().filter(|x| !x.is_empty()) {
+            begins.push(len);
+            len += fragment.len();
+            ptrs.push(fragment.as_ptr());
+            // SAFETY: elements are owned by the iterator from now on; they are either yielded or dropped by the iterator
+            unsafe { fragment.set_len(0) };
+            fragments.push(fragment);
+        }
+        begins.push(len);
+
+        Self {
+            fragments,
+            ptrs,
+            begins,
+            counter: 0.into(),
+        }
+    }
+
+    /// Returns the total number of elements of the vector the iterator is created from.
+    fn total_len(&self) -> usize {
+        self.begins[self.begins.len() - 1]
+    }
+
+    /// Returns the number of elements which are not yet pulled from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..10).collect();
+    /// let con_iter = vec.into_con_iter();
+    /// assert_eq!(con_iter.remaining(), 10);
+    ///
+    /// _ = con_iter.next_chunk(4);
+    /// assert_eq!(con_iter.remaining(), 6);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.total_len() - self.counter.load(Ordering::Acquire)
+    }
+
+    /// Pulls the next element; returns None if all elements are already pulled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = ['a', 'b'].into_iter().collect();
+    /// let con_iter = vec.into_con_iter();
+    ///
+    /// assert_eq!(con_iter.next(), Some('a'));
+    /// assert_eq!(con_iter.next(), Some('b'));
+    /// assert_eq!(con_iter.next(), None);
+    /// ```
+    pub fn next(&self) -> Option<T> {
+        self.reserve(1).map(|(idx, _)| {
+            let f = self.fragment_of(idx);
+            // SAFETY: idx is within the length and reserved exclusively by this call; hence, it is read exactly once
+            unsafe { self.ptrs[f].add(idx - self.begins[f]).read() }
+        })
+    }
+
+    /// Pulls the next at most `chunk_size` consecutive elements; returns None if all elements are already pulled.
+    ///
+    /// The returned chunk is an iterator yielding the elements by value. Elements of the chunk which are not
+    /// consumed are dropped together with the chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..10).collect();
+    /// let con_iter = vec.into_con_iter();
+    ///
+    /// let chunk = con_iter.next_chunk(4).unwrap();
+    /// assert_eq!(chunk.begin_idx(), 0);
+    /// assert_eq!(chunk.collect::<Vec<_>>(), [0, 1, 2, 3]);
+    ///
+    /// let chunk = con_iter.next_chunk(8).unwrap();
+    /// assert_eq!(chunk.begin_idx(), 4);
+    /// assert_eq!(chunk.collect::<Vec<_>>(), [4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert!(con_iter.next_chunk(4).is_none());
+    /// ```
+    pub fn next_chunk(&self, chunk_size: usize) -> Option<IntoConIterChunk<'_, T>> {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        self.reserve(chunk_size)
+            .map(|(begin, end)| IntoConIterChunk {
+                con_iter: self,
+                begin,
+                current: begin,
+                end,
+                f: self.fragment_of(begin),
+            })
+    }
+
+    /// Reserves the next at most `n` positions and returns the reserved range.
+    ///
+    /// The counter never exceeds the total length; hence, it cannot overflow regardless of the number of calls.
+    fn reserve(&self, n: usize) -> Option<(usize, usize)> {
+        let len = self.total_len();
+        let mut begin = self.counter.load(Ordering::Acquire);
+        loop {
+            if begin >= len {
+                return None;
+            }
+
+            let end = begin.saturating_add(n).min(len);
+            match self
+                .counter
+                .compare_exchange(begin, end, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return Some((begin, end)),
+                Err(current) => begin = current,
+            }
+        }
+    }
+
+    fn fragment_of(&self, idx: usize) -> usize {
+        self.begins.partition_point(|x| *x <= idx) - 1
+    }
+}
+
+impl<T> Drop for IntoConIter<T> {
+    fn drop(&mut self) {
+        let begin = *self.counter.get_mut();
+        let len = self.total_len();
+        if begin < len {
+            let mut f = self.fragment_of(begin);
+            for idx in begin..len {
+                while idx >= self.begins[f + 1] {
+                    f += 1;
+                }
+                // SAFETY: positions at and after the counter are not pulled; hence, they are dropped exactly once here
+                unsafe { drop(self.ptrs[f].add(idx - self.begins[f]).read()) };
+            }
+        }
+        // fragments with zero lengths are dropped afterwards, releasing only the allocations
+        self.fragments.clear();
+    }
+}
+
+/// A chunk of consecutive elements pulled from an [`IntoConIter`].
+///
+/// The chunk yields the elements by value; the elements which are not consumed are dropped together with the chunk.
+pub struct IntoConIterChunk<'a, T> {
+    con_iter: &'a IntoConIter<T>,
+    begin: usize,
+    current: usize,
+    end: usize,
+    f: usize,
+}
+
+impl<T> IntoConIterChunk<'_, T> {
+    /// Returns the position of the first element of the chunk in the vector the iterator is created from.
+    pub fn begin_idx(&self) -> usize {
+        self.begin
+    }
+}
+
+impl<T> Iterator for IntoConIterChunk<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current < self.end {
+            true => {
+                let begins = &self.con_iter.begins;
+                while self.current >= begins[self.f + 1] {
+                    self.f += 1;
+                }
+                let ptr = unsafe { self.con_iter.ptrs[self.f].add(self.current - begins[self.f]) };
+                self.current += 1;
+                // SAFETY: the position is within the range reserved exclusively for this chunk, and it is read exactly once
+                Some(unsafe { ptr.read() })
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoConIterChunk<'_, T> {}
+
+impl<T> FusedIterator for IntoConIterChunk<'_, T> {}
+
+impl<T> Drop for IntoConIterChunk<'_, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn into_con_iter_next() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 157;
+            vec.extend((0..n).map(|x| x.to_string()));
+
+            let con_iter = vec.into_con_iter();
+            assert_eq!(con_iter.remaining(), n);
+
+            let mut collected = Vec::new();
+            while let Some(x) = con_iter.next() {
+                collected.push(x);
+            }
+            assert_eq!(collected, (0..n).map(|x| x.to_string()).collect::<Vec<_>>());
+            assert_eq!(con_iter.remaining(), 0);
+            assert!(con_iter.next_chunk(3).is_none());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn into_con_iter_chunks() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 157;
+            vec.extend((0..n).map(|x| x.to_string()));
+
+            let con_iter = vec.into_con_iter();
+            let mut collected = Vec::new();
+            let mut chunk_size = 1;
+            while let Some(chunk) = con_iter.next_chunk(chunk_size) {
+                assert_eq!(chunk.begin_idx(), collected.len());
+                assert_eq!(chunk.len(), chunk_size.min(n - collected.len()));
+                collected.extend(chunk);
+                chunk_size += 1;
+            }
+            assert_eq!(collected, (0..n).map(|x| x.to_string()).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn into_con_iter_with_empty_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.append((0..3).collect::<Vec<_>>());
+        vec.append(Vec::<usize>::new());
+        vec.append((3..7).collect::<Vec<_>>());
+
+        let con_iter = vec.into_con_iter();
+        let chunk = con_iter.next_chunk(5).expect("is-some");
+        assert_eq!(chunk.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+        assert_eq!(con_iter.next(), Some(5));
+        assert_eq!(con_iter.next(), Some(6));
+        assert_eq!(con_iter.next(), None);
+    }
+
+    #[test]
+    fn into_con_iter_drops_unpulled_elements() {
+        fn test<G: Growth>(mut vec: SplitVec<Rc<usize>, G>) {
+            let value = Rc::new(42);
+            let n = 157;
+            vec.extend((0..n).map(|_| value.clone()));
+            assert_eq!(Rc::strong_count(&value), n + 1);
+
+            let con_iter = vec.into_con_iter();
+            let x = con_iter.next();
+            let mut chunk = con_iter.next_chunk(20).expect("is-some");
+            let y = chunk.next();
+            drop(chunk);
+            assert_eq!(Rc::strong_count(&value), n + 1 - 19);
+
+            drop(con_iter);
+            assert_eq!(Rc::strong_count(&value), 3);
+
+            drop((x, y));
+            assert_eq!(Rc::strong_count(&value), 1);
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+use std::io::{BufRead, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+impl<G: Growth> Write for SplitVec<u8, G> {
+    /// Appends the bytes of `buf` to the end of the vector.
+    ///
+    /// The bytes first fill the remaining capacity of the last fragment; new fragments are allocated
+    /// only for the rest. Already written bytes are never moved.
+    ///
+    /// This implementation is available with the **std** feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use std::io::Write;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// write!(vec, "hello {}", 42).unwrap();
+    /// vec.write_all(b"!").unwrap();
+    ///
+    /// assert_eq!(vec, b"hello 42!");
+    /// assert!(vec.fragments().iter().all(|f| f.capacity() == 4));
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<G: Growth> SplitVec<u8, G> {
+    /// Creates a [`SplitVecReader`] over the bytes of the vector, positioned at the beginning.
+    ///
+    /// The reader implements `Read`, `BufRead` and `Seek`, reading directly from the fragments without copying them
+    /// into a contiguous buffer.
+    ///
+    /// This method is available with the **std** feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use std::io::{BufRead, Read, Seek, SeekFrom};
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(b"first line\nsecond line\n");
+    ///
+    /// let mut reader = vec.reader();
+    /// let lines: Vec<_> = reader.by_ref().lines().map(|x| x.unwrap()).collect();
+    /// assert_eq!(lines, ["first line", "second line"]);
+    ///
+    /// reader.seek(SeekFrom::Start(6)).unwrap();
+    /// let mut word = [0u8; 4];
+    /// reader.read_exact(&mut word).unwrap();
+    /// assert_eq!(&word, b"line");
+    /// ```
+    pub fn reader(&self) -> SplitVecReader<'_, G> {
+        SplitVecReader {
+            vec: self,
+            position: 0,
+            f: 0,
+            i: 0,
+        }
+    }
+}
+
+/// A cursor over the bytes of a `SplitVec<u8, G>` implementing `Read`, `BufRead` and `Seek`.
+///
+/// Created by the [`SplitVec::reader`] method.
+///
+/// Similar to `std::io::Cursor`, the position might be seeked beyond the end of the vector,
+/// in which case reads return zero bytes.
+///
+/// This type is available with the **std** feature.
+pub struct SplitVecReader<'a, G: Growth> {
+    vec: &'a SplitVec<u8, G>,
+    position: u64,
+    /// Fragment of the current position; equals the number of fragments when the position is at or beyond the end.
+    f: usize,
+    /// Position within the `f`-th fragment.
+    i: usize,
+}
+
+impl<G: Growth> SplitVecReader<'_, G> {
+    /// Returns the current position of the reader.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Sets the position of the reader.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+        let located = usize::try_from(position).ok().and_then(|idx| {
+            self.vec
+                .growth
+                .get_fragment_and_inner_indices(self.vec.len, &self.vec.fragments, idx)
+        });
+        (self.f, self.i) = located.unwrap_or((self.vec.fragments.len(), 0));
+    }
+
+    /// Returns the number of bytes remaining to be read.
+    pub fn remaining(&self) -> usize {
+        match usize::try_from(self.position) {
+            Ok(position) => self.vec.len.saturating_sub(position),
+            Err(_) => 0,
+        }
+    }
+}
+
+impl<G: Growth> BufRead for SplitVecReader<'_, G> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        let fragments = &self.vec.fragments;
+        while self.f < fragments.len() && self.i == fragments[self.f].len() {
+            self.f += 1;
+            self.i = 0;
+        }
+        Ok(match fragments.get(self.f) {
+            Some(fragment) => &fragment[self.i..],
+            None => &[],
+        })
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let mut amt = amt.min(self.remaining());
+        self.position += amt as u64;
+        let fragments = &self.vec.fragments;
+        while amt > 0 {
+            let available = fragments[self.f].len() - self.i;
+            match amt < available {
+                true => {
+                    self.i += amt;
+                    amt = 0;
+                }
+                false => {
+                    amt -= available;
+                    self.f += 1;
+                    self.i = 0;
+                }
+            }
+        }
+    }
+}
+
+impl<G: Growth> Read for SplitVecReader<'_, G> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut num_read = 0;
+        while num_read < buf.len() {
+            let source = self.fill_buf()?;
+            if source.is_empty() {
+                break;
+            }
+            let n = source.len().min(buf.len() - num_read);
+            buf[num_read..(num_read + n)].copy_from_slice(&source[..n]);
+            self.consume(n);
+            num_read += n;
+        }
+        Ok(num_read)
+    }
+}
+
+impl<G: Growth> Seek for SplitVecReader<'_, G> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(position) => (position, 0),
+            SeekFrom::End(offset) => (self.vec.len as u64, offset),
+            SeekFrom::Current(offset) => (self.position, offset),
+        };
+        match base.checked_add_signed(offset) {
+            Some(position) => {
+                self.set_position(position);
+                Ok(position)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+
+    #[test]
+    fn write() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            let mut expected = Vec::new();
+            for i in 0..100u8 {
+                let bytes: Vec<_> = (0..(i % 7)).map(|x| x.wrapping_mul(i)).collect();
+                vec.write_all(&bytes).expect("is-ok");
+                expected.extend_from_slice(&bytes);
+            }
+            vec.flush().expect("is-ok");
+            assert_eq!(vec, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn read() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            let expected: Vec<u8> = (0..300).map(|x| (x % 251) as u8).collect();
+            vec.extend_from_slice(&expected);
+
+            let mut all = Vec::new();
+            vec.reader().read_to_end(&mut all).expect("is-ok");
+            assert_eq!(all, expected);
+
+            let mut reader = vec.reader();
+            let mut buf = [0u8; 7];
+            let mut read = Vec::new();
+            loop {
+                let n = reader.read(&mut buf).expect("is-ok");
+                if n == 0 {
+                    break;
+                }
+                read.extend_from_slice(&buf[..n]);
+            }
+            assert_eq!(read, expected);
+            assert_eq!(reader.remaining(), 0);
+            assert_eq!(reader.position(), 300);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn buf_read() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            for i in 0..40 {
+                writeln!(vec, "line-{}", i).expect("is-ok");
+            }
+
+            let lines: Vec<_> = vec.reader().lines().map(|x| x.expect("is-ok")).collect();
+            let expected: Vec<_> = (0..40).map(|i| std::format!("line-{}", i)).collect();
+            assert_eq!(lines, expected);
+
+            let mut reader = vec.reader();
+            let first = reader.fill_buf().expect("is-ok");
+            assert_eq!(first, &vec.fragments()[0][..]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn seek() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            let expected: Vec<u8> = (0..200).map(|x| x as u8).collect();
+            vec.extend_from_slice(&expected);
+            let mut reader = vec.reader();
+            let mut buf = [0u8; 3];
+
+            for position in [0, 1, 3, 4, 12, 100, 197] {
+                reader.seek(SeekFrom::Start(position)).expect("is-ok");
+                reader.read_exact(&mut buf).expect("is-ok");
+                let p = position as usize;
+                assert_eq!(&buf, &expected[p..(p + 3)]);
+            }
+
+            assert_eq!(reader.seek(SeekFrom::End(-10)).expect("is-ok"), 190);
+            assert_eq!(reader.seek(SeekFrom::Current(-5)).expect("is-ok"), 185);
+            reader.read_exact(&mut buf).expect("is-ok");
+            assert_eq!(&buf, &[185, 186, 187]);
+            assert_eq!(reader.stream_position().expect("is-ok"), 188);
+
+            assert!(reader.seek(SeekFrom::Current(-1000)).is_err());
+            assert_eq!(reader.position(), 188);
+
+            assert_eq!(reader.seek(SeekFrom::End(10)).expect("is-ok"), 210);
+            assert_eq!(reader.read(&mut buf).expect("is-ok"), 0);
+            assert_eq!(reader.remaining(), 0);
+        }
+
+        test_all_growth_types!(test);
+    }
+}
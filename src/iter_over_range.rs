@@ -0,0 +1,235 @@
+use crate::{Growth, SplitVec};
+use core::ops::RangeBounds;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns an iterator over the elements of the vector within the given `range`.
+    ///
+    /// The iterator directly jumps to the fragment containing the start of the range, and then, iterates
+    /// over the slices of the fragments belonging to the range; hence, it avoids the cost of `vec.iter().skip(a).take(b - a)`.
+    ///
+    /// Similar to `slices`, the iterator is empty if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// assert_eq!(vec.iter_over_range(3..7).copied().collect::<Vec<_>>(), [3, 4, 5, 6]);
+    /// assert_eq!(vec.iter_over_range(8..).copied().collect::<Vec<_>>(), [8, 9]);
+    /// assert_eq!(vec.iter_over_range(5..12).count(), 0);
+    /// ```
+    pub fn iter_over_range<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.slices(range).into_iter().flat_map(|x| x.iter())
+    }
+
+    /// Returns a mutable iterator over the elements of the vector within the given `range`.
+    ///
+    /// The iterator directly jumps to the fragment containing the start of the range, and then, iterates
+    /// over the slices of the fragments belonging to the range; hence, it avoids the cost of `vec.iter_mut().skip(a).take(b - a)`.
+    ///
+    /// Similar to `slices_mut`, the iterator is empty if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// for x in vec.iter_mut_over_range(3..7) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(vec, [0, 1, 2, 30, 40, 50, 60, 7, 8, 9]);
+    ///
+    /// for x in vec.iter_mut_over_range(8..) {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(vec, [0, 1, 2, 30, 40, 50, 60, 7, 9, 10]);
+    ///
+    /// assert_eq!(vec.iter_mut_over_range(5..12).count(), 0);
+    /// ```
+    pub fn iter_mut_over_range<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        self.slices_mut(range).flat_map(|x| x.iter_mut())
+    }
+
+    /// Returns an iterator over the elements of the vector starting from the element at `start_index`.
+    ///
+    /// The iterator is positioned by directly jumping to the fragment containing `start_index`, rather than skipping
+    /// the elements before it; this is convenient to resume a scan from a saved position.
+    ///
+    /// The iterator is empty if `start_index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// assert_eq!(vec.iter_from(6).copied().collect::<Vec<_>>(), [6, 7, 8, 9]);
+    /// assert_eq!(vec.iter_from(10).count(), 0);
+    /// ```
+    pub fn iter_from(&self, start_index: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.iter_over_range(start_index..)
+    }
+
+    /// Returns a mutable iterator over the elements of the vector starting from the element at `start_index`.
+    ///
+    /// The iterator is positioned by directly jumping to the fragment containing `start_index`, rather than skipping
+    /// the elements before it.
+    ///
+    /// The iterator is empty if `start_index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// for x in vec.iter_mut_from(4) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(vec, [0, 1, 2, 3, 40, 50]);
+    /// ```
+    pub fn iter_mut_from(
+        &mut self,
+        start_index: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        self.iter_mut_over_range(start_index..)
+    }
+
+    /// Returns an iterator over the elements of the vector starting from the element at `start_index`,
+    /// together with their indices in the vector.
+    ///
+    /// This is equivalent to `vec.iter().enumerate().skip(start_index)`; however, the iterator is positioned
+    /// by directly jumping to the fragment containing `start_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(['a', 'b', 'c', 'd', 'e', 'f']);
+    ///
+    /// // resume the scan from the saved position
+    /// let saved = 3;
+    /// let found = vec.enumerate_from(saved).find(|(_, x)| **x >= 'e');
+    /// assert_eq!(found, Some((4, &'e')));
+    /// ```
+    pub fn enumerate_from(&self, start_index: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.iter_from(start_index)
+            .enumerate()
+            .map(move |(i, x)| (start_index + i, x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iter_over_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 157;
+            vec.extend(0..n);
+
+            for a in [0, 1, 4, 12, 60, n - 1, n] {
+                for b in [a, a + 1, a + 7, a + 50, n] {
+                    let b = b.min(n);
+                    let values: Vec<_> = vec.iter_over_range(a..b).copied().collect();
+                    assert_eq!(values, (a..b).collect::<Vec<_>>());
+
+                    let values: Vec<_> = vec.iter_over_range(a..b).rev().copied().collect();
+                    assert_eq!(values, (a..b).rev().collect::<Vec<_>>());
+                }
+            }
+
+            assert_eq!(vec.iter_over_range(..).count(), n);
+            assert_eq!(vec.iter_over_range(10..(n + 1)).count(), 0);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn iter_mut_over_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 157;
+            for a in [0, 1, 4, 12, 60, n - 1, n] {
+                for b in [a, a + 1, a + 7, a + 50, n] {
+                    let b = b.min(n);
+                    vec.clear();
+                    vec.extend(0..n);
+
+                    for x in vec.iter_mut_over_range(a..b) {
+                        *x += 1000;
+                    }
+
+                    let expected: Vec<_> = (0..n)
+                        .map(|x| match (a..b).contains(&x) {
+                            true => x + 1000,
+                            false => x,
+                        })
+                        .collect();
+                    assert_eq!(vec, expected);
+                }
+            }
+
+            assert_eq!(vec.iter_mut_over_range(10..(n + 1)).count(), 0);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn iter_from() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 157;
+            vec.extend(0..n);
+
+            for start in [0, 1, 4, 12, 60, n - 1, n, n + 1] {
+                let expected: Vec<_> = vec.iter().skip(start).copied().collect();
+                assert_eq!(vec.iter_from(start).copied().collect::<Vec<_>>(), expected);
+                assert_eq!(
+                    vec.iter_from(start).rev().copied().collect::<Vec<_>>(),
+                    expected.iter().rev().copied().collect::<Vec<_>>()
+                );
+
+                let expected_enumerated: Vec<_> = vec.iter().enumerate().skip(start).collect();
+                assert_eq!(
+                    vec.enumerate_from(start).collect::<Vec<_>>(),
+                    expected_enumerated
+                );
+
+                for x in vec.iter_mut_from(start) {
+                    *x += 1000;
+                }
+                for (i, x) in vec.iter().enumerate() {
+                    assert_eq!(*x >= 1000, i >= start);
+                }
+                for x in vec.iter_mut() {
+                    *x %= 1000;
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
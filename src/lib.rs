@@ -12,46 +12,131 @@
 )]
 #![no_std]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
 extern crate alloc;
 
+mod adopt;
 mod algorithms;
+#[cfg(feature = "bytes")]
+mod buf;
+mod cached_accessor;
+#[cfg(feature = "async")]
+mod chunk_stream;
 mod common_traits;
+mod compact_capacity;
+mod concurrent_metrics;
 mod concurrent_pinned_vec;
+mod concurrent_prefix_suffix;
+mod drain;
+mod drop_front;
+mod fill;
+mod flattened_slices;
 mod fragment;
+mod get_many_mut;
+mod get_or_extend;
+mod grow_by;
 mod growth;
+mod growth_observer;
+mod insert_many;
+mod into_con_iter;
 mod into_concurrent_pinned_vec;
+#[cfg(feature = "std")]
+mod io;
+mod iter_over_range;
+mod make_contiguous;
+mod memory_policy;
+mod memory_stats;
 mod new_split_vec;
+mod offset_from_start;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod pinned_vec;
 mod pointers;
 mod range_helpers;
-mod resize_multiple;
+mod reductions;
+mod relocation;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+mod remove_duplicates;
+mod reserve;
+mod retain;
+mod rotate;
+mod search;
 mod slice;
+mod sort;
+mod spare_capacity;
+mod splice;
+mod split_deque;
+mod split_heap;
+mod split_off;
+mod split_slab;
+mod split_string;
 mod split_vec;
+mod stable_index;
+mod visit_mut;
+mod windows;
+mod zeroable;
 
 #[cfg(test)]
 pub(crate) mod test;
 
 /// Common relevant traits, structs, enums.
+///
+/// All types and traits required to use the split vector and its concurrent counterpart are
+/// importable with a single `use orx_split_vec::prelude::*`.
 pub mod prelude;
 
+#[cfg(feature = "bytes")]
+pub use buf::SplitVecBuf;
+pub use cached_accessor::CachedAccessor;
+#[cfg(feature = "async")]
+pub use chunk_stream::ChunkStream;
 pub use common_traits::iterator::{
-    into_iter::IntoIter, iter::Iter, iter_mut::IterMut, iter_mut_rev::IterMutRev, iter_rev::IterRev,
+    into_iter::IntoIter, iter::Iter, iter_mut::IterMut, iter_mut_rev::IterMutRev,
+    iter_rev::IterRev, slice_mut_iter::SliceMutIter,
 };
+pub use concurrent_metrics::ConcurrentSplitVecMetrics;
 pub use concurrent_pinned_vec::ConcurrentSplitVec;
+pub use concurrent_prefix_suffix::{ConcurrentSplitVecPrefix, ConcurrentSplitVecSuffix};
+pub use drain::Drain;
+pub use flattened_slices::FlattenedSlices;
 pub use fragment::fragment_struct::Fragment;
 pub use fragment::into_fragments::IntoFragments;
 pub use growth::{
+    conformance::{growth_conformance_check, growth_with_constant_time_access_conformance_check},
     doubling::Doubling,
+    doubling_until::DoublingUntil,
+    exponential::Exponential,
+    functional::FunctionalGrowth,
+    growth_error::GrowthError,
     growth_trait::{Growth, GrowthWithConstantTimeAccess},
     linear::Linear,
+    recommend::{recommend_growth, AccessPattern, GrowthChoice},
     recursive::Recursive,
 };
+#[cfg(feature = "growth_observer")]
+pub use growth_observer::GrowthEvent;
+pub use into_con_iter::{IntoConIter, IntoConIterChunk};
+#[cfg(feature = "std")]
+pub use io::SplitVecReader;
+pub use memory_policy::MemoryPolicy;
+pub use memory_stats::{FragmentStats, MemoryStats};
+pub use new_split_vec::SplitVecUninit;
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
 };
 pub use orx_pseudo_default::PseudoDefault;
-pub use slice::SplitVecSlice;
+#[cfg(feature = "rayon")]
+pub use parallel::{IntoParIter, ParIter, ParIterMut};
+pub use relocation::{ObservedDrain, ObservedSplitVec, RelocationObserver};
+pub use slice::{SplitVecSlice, SplitVecView};
+pub use splice::Splice;
+pub use split_deque::SplitDeque;
+pub use split_heap::SplitHeap;
+pub use split_slab::SplitSlab;
+pub use split_string::SplitString;
 pub use split_vec::SplitVec;
+pub use stable_index::StableIndex;
+pub use windows::{Window, Windows};
+pub use zeroable::Zeroable;
@@ -0,0 +1,117 @@
+use crate::{Growth, Recursive, SplitVec};
+use alloc::vec::Vec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Moves all elements into a single fragment with the given `capacity`.
+    fn move_into_single_fragment(&mut self, capacity: usize) {
+        debug_assert!(capacity >= self.len);
+        let mut data = Vec::with_capacity(capacity);
+        let fragments: Vec<_> = core::iter::from_fn(|| self.pop_fragment()).collect();
+        for fragment in fragments.into_iter().rev() {
+            data.extend(Vec::from(fragment));
+        }
+        self.push_fragment(data.into());
+    }
+}
+
+impl<T> SplitVec<T, Recursive> {
+    /// Makes sure that all elements of the vector are stored in a single fragment,
+    /// and returns the entire vector as a contiguous mutable slice.
+    ///
+    /// If the vector spans multiple fragments, the elements are moved into a new fragment with an
+    /// exact capacity of the length of the vector.
+    /// Otherwise, no allocation or copy takes place.
+    ///
+    /// This is useful when the vector is built with pinned element guarantees, and then, a contiguous view
+    /// is required, such as for an FFI call.
+    ///
+    /// **Note that this method breaks the pinned element guarantees; memory locations of the elements change
+    /// whenever the vector spans multiple fragments.**
+    ///
+    /// The method is provided only for the `Recursive` growth, which allows fragments of arbitrary capacities.
+    /// Constant time random access of the `Doubling` and `Linear` growth strategies relies on the fixed capacity
+    /// of each fragment; hence, their elements cannot be moved into a single fragment without changing the growth.
+    /// Such a vector can first be converted into a vector with `Recursive` growth, which moves the fragments
+    /// without copying the elements, as demonstrated below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments().len(), 2);
+    ///
+    /// let slice = vec.make_contiguous();
+    /// assert_eq!(slice, &[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments().len(), 1);
+    ///
+    /// vec.push(6);
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// // vectors with Doubling or Linear growth are converted first
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mut vec: SplitVec<_, Recursive> = vec.into();
+    /// assert_eq!(vec.make_contiguous(), &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.fragments.len() > 1 {
+            self.invalidate_stable_indices();
+            self.move_into_single_fragment(self.len.max(1));
+        }
+        self.fragments
+            .first_mut()
+            .map(|x| x.as_mut_slice())
+            .unwrap_or(&mut [])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn make_contiguous_recursive() {
+        for len in [0, 1, 4, 5, 100, 1033] {
+            let mut vec = SplitVec::with_recursive_growth();
+            vec.extend(0..len);
+
+            let slice = vec.make_contiguous();
+            assert_eq!(slice, (0..len).collect::<Vec<_>>());
+            slice.iter_mut().for_each(|x| *x += 1);
+
+            assert!(vec.fragments().len() <= 1);
+            assert_eq!(vec, (1..(len + 1)).collect::<Vec<_>>());
+
+            vec.extend((len + 1)..(len + 100));
+            assert_eq!(vec, (1..(len + 100)).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn make_contiguous_after_converting_into_recursive() {
+        for len in [0, 1, 4, 5, 100, 1033] {
+            let mut linear = SplitVec::with_linear_growth(2);
+            linear.extend(0..len);
+            let mut doubling = SplitVec::with_doubling_growth();
+            doubling.extend(0..len);
+
+            for vec in [linear.into(), doubling.into()] {
+                let mut vec: SplitVec<_, Recursive> = vec;
+                let slice = vec.make_contiguous();
+                assert_eq!(slice, (0..len).collect::<Vec<_>>());
+                slice.iter_mut().for_each(|x| *x += 1);
+
+                assert!(vec.fragments().len() <= 1);
+                assert_eq!(vec, (1..(len + 1)).collect::<Vec<_>>());
+
+                vec.extend((len + 1)..(len + 100));
+                assert_eq!(vec, (1..(len + 100)).collect::<Vec<_>>());
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Fragment, Growth, SplitVec};
+
+/// Policy determining what happens to the fragments of a [`SplitVec`] which are emptied by removing elements,
+/// such as by `pop`, `truncate`, `remove` or `clear`.
+///
+/// Kept fragments are moved to the reserved fragments (see [`SplitVec::reserved_capacity`]), and they are
+/// reused by the subsequent pushes instead of allocating new fragments.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_doubling_growth();
+/// vec.set_memory_policy(MemoryPolicy::KeepAll);
+///
+/// vec.extend(0..5);
+/// assert_eq!(vec.fragments().len(), 2);
+/// let second_fragment = vec.fragments()[1].as_ptr();
+///
+/// // emptied fragment is kept rather than being released
+/// vec.pop();
+/// assert_eq!(vec.fragments().len(), 1);
+/// assert_eq!(vec.reserved_capacity(), 8);
+///
+/// // and it is reused by the next push
+/// vec.push(4);
+/// assert_eq!(vec.fragments()[1].as_ptr(), second_fragment);
+/// assert_eq!(vec.reserved_capacity(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MemoryPolicy {
+    /// Emptied fragments are released immediately.
+    ///
+    /// This is the default policy.
+    #[default]
+    EagerShrink,
+    /// All emptied fragments are kept for reuse.
+    KeepAll,
+    /// Emptied fragments are kept for reuse as long as the number of reserved fragments does not exceed the given number.
+    ///
+    /// Fragments closer to the end of the vector are preferred; i.e., the fragments to be reused the earliest are kept.
+    KeepAtMost(usize),
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns the memory policy of the vector determining what happens to the fragments emptied by removing elements.
+    ///
+    /// See [`MemoryPolicy`] for details.
+    pub fn memory_policy(&self) -> MemoryPolicy {
+        self.memory_policy
+    }
+
+    /// Sets the memory policy of the vector determining what happens to the fragments emptied by removing elements.
+    ///
+    /// The policy applies to the fragments emptied afterwards; fragments which are already reserved are not affected.
+    /// See [`MemoryPolicy`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// assert_eq!(vec.memory_policy(), MemoryPolicy::EagerShrink);
+    ///
+    /// vec.set_memory_policy(MemoryPolicy::KeepAtMost(2));
+    /// vec.extend(0..20);
+    ///
+    /// vec.truncate(3);
+    /// assert_eq!(vec.fragments().len(), 1);
+    /// assert_eq!(vec.reserved_capacity(), 2 * 4);
+    /// ```
+    pub fn set_memory_policy(&mut self, memory_policy: MemoryPolicy) {
+        self.memory_policy = memory_policy;
+    }
+
+    /// Pops the fragments until there remain `num_fragments` fragments, and releases the popped fragments
+    /// following the memory policy.
+    pub(crate) fn release_fragments_after(&mut self, num_fragments: usize) {
+        while self.fragments.len() > num_fragments {
+            if let Some(fragment) = self.pop_fragment() {
+                self.release_fragment(fragment);
+            }
+        }
+    }
+
+    /// Releases the `fragment` which is removed from the end of the fragments following the memory policy;
+    /// the fragment is cleared and moved to the front of the reserved fragments if it is kept.
+    fn release_fragment(&mut self, mut fragment: Fragment<T>) {
+        match self.memory_policy {
+            MemoryPolicy::EagerShrink => {}
+            MemoryPolicy::KeepAll => {
+                fragment.clear();
+                self.reserved.push_front(fragment);
+            }
+            MemoryPolicy::KeepAtMost(max_fragments) => {
+                fragment.clear();
+                self.reserved.push_front(fragment);
+                self.reserved.truncate(max_fragments);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    fn fragment_ptrs<G: Growth>(vec: &SplitVec<usize, G>) -> Vec<*const usize> {
+        vec.fragments().iter().map(|x| x.as_ptr()).collect()
+    }
+
+    #[test]
+    fn eager_shrink() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.memory_policy(), MemoryPolicy::EagerShrink);
+            vec.extend(0..157);
+            let reserved_capacity = vec.reserved_capacity();
+
+            while vec.pop().is_some() {}
+            vec.extend(0..157);
+            vec.truncate(3);
+            vec.remove(0);
+            vec.clear();
+            assert_eq!(vec.reserved_capacity(), reserved_capacity);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn keep_all() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.set_memory_policy(MemoryPolicy::KeepAll);
+            let n = 157;
+
+            vec.extend(0..n);
+            let fragments = fragment_ptrs(&vec);
+            let total_capacity = vec.capacity() + vec.reserved_capacity();
+
+            while vec.pop().is_some() {
+                assert_eq!(vec.capacity() + vec.reserved_capacity(), total_capacity);
+            }
+            vec.extend(0..n);
+            assert_eq!(fragment_ptrs(&vec), fragments);
+
+            vec.truncate(3);
+            assert_eq!(vec.capacity() + vec.reserved_capacity(), total_capacity);
+            vec.extend(3..n);
+            assert_eq!(fragment_ptrs(&vec), fragments);
+
+            for i in (0..n).rev() {
+                assert_eq!(vec.remove(i), i);
+            }
+            assert_eq!(vec.capacity() + vec.reserved_capacity(), total_capacity);
+            vec.extend(0..n);
+            assert_eq!(fragment_ptrs(&vec), fragments);
+
+            vec.clear();
+            assert_eq!(vec.capacity() + vec.reserved_capacity(), total_capacity);
+            vec.extend(0..n);
+            assert_eq!(fragment_ptrs(&vec), fragments);
+            assert_eq!(vec, (0..n).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn keep_at_most() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.set_memory_policy(MemoryPolicy::KeepAtMost(2));
+            let n = 157;
+
+            vec.extend(0..n);
+            let fragments = fragment_ptrs(&vec);
+
+            vec.truncate(1);
+            assert_eq!(vec.fragments().len(), 1);
+            assert!(vec.reserved.len() <= 2);
+
+            vec.extend(1..n);
+            let refilled = fragment_ptrs(&vec);
+            let num_reused = 1 + fragments.len().saturating_sub(1).min(2);
+            assert_eq!(refilled[..num_reused], fragments[..num_reused]);
+            assert_eq!(vec, (0..n).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn reserve_then_spare_capacity() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for policy in [
+                MemoryPolicy::EagerShrink,
+                MemoryPolicy::KeepAll,
+                MemoryPolicy::KeepAtMost(1),
+            ] {
+                vec.clear();
+                vec.set_memory_policy(policy);
+                vec.extend(0..20);
+                vec.reserve(100);
+                vec.truncate(2);
+
+                vec.reserve(22);
+                let spare: usize = vec.spare_capacity_mut().map(|x| x.len()).sum();
+                assert!(spare >= 22);
+
+                let mut value = 2;
+                for slice in vec.spare_capacity_mut() {
+                    for x in slice.iter_mut().take(24 - value) {
+                        x.write(value);
+                        value += 1;
+                    }
+                }
+                unsafe { vec.set_len(24) };
+                assert_eq!(vec, (0..24).collect::<Vec<_>>());
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result};
+
+/// Length and capacity of a fragment of a split vector.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FragmentStats {
+    /// Number of elements in the fragment.
+    pub len: usize,
+    /// Capacity of the fragment.
+    pub capacity: usize,
+}
+
+/// A snapshot of the memory layout of a [`SplitVec`], created by the [`SplitVec::memory_stats`] method.
+///
+/// The `Display` implementation prints a summary followed by a line for each fragment,
+/// which is convenient while tuning the growth strategy.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Number of elements in the vector.
+    pub len: usize,
+    /// Number of fragments of the vector.
+    pub num_fragments: usize,
+    /// Length and capacity of each of the fragments.
+    pub fragments: Vec<FragmentStats>,
+    /// Sum of capacities of the fragments.
+    pub capacity: usize,
+    /// Sum of capacities of the fragments allocated ahead, which are not yet part of the vector's fragments.
+    pub reserved_capacity: usize,
+    /// Capacity which is allocated but not used; i.e., `capacity - len + reserved_capacity`.
+    pub wasted_capacity: usize,
+    /// Size of an element in bytes.
+    pub element_size: usize,
+    /// Total number of bytes allocated for the elements, including the fragments allocated ahead.
+    pub allocated_bytes: usize,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(
+            f,
+            "SplitVec {{ len: {}, capacity: {}, reserved_capacity: {}, wasted_capacity: {}, allocated_bytes: {}, num_fragments: {} }}",
+            self.len,
+            self.capacity,
+            self.reserved_capacity,
+            self.wasted_capacity,
+            self.allocated_bytes,
+            self.num_fragments
+        )?;
+        for (i, fragment) in self.fragments.iter().enumerate() {
+            writeln!(
+                f,
+                "  fragment {}: {}/{}",
+                i, fragment.len, fragment.capacity
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns a snapshot of the memory layout of the vector such as its fragments, allocated bytes and wasted capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<u32, _> = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// let stats = vec.memory_stats();
+    /// assert_eq!(stats.num_fragments, 2);
+    /// assert_eq!(stats.fragments[1], FragmentStats { len: 2, capacity: 4 });
+    /// assert_eq!(stats.capacity, 8);
+    /// assert_eq!(stats.wasted_capacity, 2);
+    /// assert_eq!(stats.allocated_bytes, 8 * 4);
+    ///
+    /// let report = stats.to_string();
+    /// assert!(report.contains("fragment 1: 2/4"));
+    /// ```
+    pub fn memory_stats(&self) -> MemoryStats {
+        let fragments: Vec<_> = self
+            .fragments
+            .iter()
+            .map(|x| FragmentStats {
+                len: x.len(),
+                capacity: x.capacity(),
+            })
+            .collect();
+        let capacity = fragments.iter().map(|x| x.capacity).sum();
+        let reserved_capacity = self.reserved_capacity();
+        let element_size = core::mem::size_of::<T>();
+
+        MemoryStats {
+            len: self.len,
+            num_fragments: fragments.len(),
+            fragments,
+            capacity,
+            reserved_capacity,
+            wasted_capacity: capacity - self.len + reserved_capacity,
+            element_size,
+            allocated_bytes: (capacity + reserved_capacity) * element_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::ToString;
+    use orx_pinned_vec::PinnedVec;
+
+    #[test]
+    fn memory_stats() {
+        fn test<G: Growth>(mut vec: SplitVec<u64, G>) {
+            for i in 0..142 {
+                vec.push(i);
+                let stats = vec.memory_stats();
+                assert_eq!(stats.len, vec.len());
+                assert_eq!(stats.num_fragments, vec.fragments().len());
+                assert_eq!(stats.capacity, vec.capacity());
+                assert_eq!(stats.wasted_capacity, vec.capacity() - vec.len());
+                assert_eq!(stats.allocated_bytes, vec.capacity() * 8);
+                for (f, fragment) in stats.fragments.iter().enumerate() {
+                    assert_eq!(fragment.len, vec.fragments()[f].len());
+                    assert_eq!(fragment.capacity, vec.fragments()[f].capacity());
+                }
+            }
+
+            vec.reserve(1000);
+            let stats = vec.memory_stats();
+            assert_eq!(stats.reserved_capacity, vec.reserved_capacity());
+            assert_eq!(
+                stats.allocated_bytes,
+                (vec.capacity() + vec.reserved_capacity()) * 8
+            );
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn display() {
+        let mut vec = SplitVec::with_linear_growth(1);
+        vec.extend(['a', 'b', 'c']);
+        let report = vec.memory_stats().to_string();
+        let lines: alloc::vec::Vec<_> = report.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("len: 3, capacity: 4"));
+        assert_eq!(lines[1], "  fragment 0: 2/2");
+        assert_eq!(lines[2], "  fragment 1: 1/2");
+    }
+}
@@ -0,0 +1,88 @@
+use super::fragment_capacities_for;
+use crate::{Doubling, Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+
+impl<T> SplitVec<T, Doubling> {
+    /// Creates a split vector with the default `Doubling` growth containing `len` elements,
+    /// where the element at position `i` is `f(i)`.
+    ///
+    /// See [`SplitVec::from_fn_with_growth`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = SplitVec::from_fn(6, |i| i * i);
+    /// assert_eq!(vec, [0, 1, 4, 9, 16, 25]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Self {
+        Self::from_fn_with_growth(len, Doubling, f)
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Creates a split vector with the given `growth` strategy containing `len` elements,
+    /// where the element at position `i` is `f(i)`.
+    ///
+    /// The exact fragments required for `len` elements are allocated up front; then, each fragment is filled
+    /// by writing the elements directly to its memory. Compared to collecting with repeated pushes, this avoids
+    /// the capacity check per element.
+    ///
+    /// If `f` panics, the elements already created in completed fragments are dropped, while those in the
+    /// fragment being filled are leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = SplitVec::from_fn_with_growth(7, Linear::new(2), |i| i * 10);
+    /// assert_eq!(vec, [0, 10, 20, 30, 40, 50, 60]);
+    /// assert_eq!(vec.fragments().len(), 2);
+    /// ```
+    pub fn from_fn_with_growth<F: FnMut(usize) -> T>(len: usize, growth: G, mut f: F) -> Self {
+        let mut begin = 0;
+        let mut fragments = Vec::new();
+        for capacity in fragment_capacities_for(&growth, len) {
+            let mut fragment: Fragment<T> = Fragment::new(capacity);
+            let fragment_len = capacity.min(len - begin);
+            let ptr = fragment.as_mut_ptr();
+            for i in 0..fragment_len {
+                // SAFETY: i is within the capacity of the fragment
+                unsafe { ptr.add(i).write(f(begin + i)) };
+            }
+            // SAFETY: the first `fragment_len` positions are written above
+            unsafe { fragment.set_len(fragment_len) };
+            begin += fragment_len;
+            fragments.push(fragment);
+        }
+        Self::from_parts(len, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn from_fn() {
+        fn test<G: Growth>(vec: SplitVec<String, G>) {
+            for len in [0, 1, 4, 5, 100, 1033] {
+                let mut built =
+                    SplitVec::from_fn_with_growth(len, vec.growth().clone(), |i| i.to_string());
+                let mut expected: Vec<_> = (0..len).map(|i| i.to_string()).collect();
+                assert_eq!(built, expected);
+
+                built.push("x".to_string());
+                expected.push("x".to_string());
+                assert_eq!(built, expected);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
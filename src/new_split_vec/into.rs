@@ -1,6 +1,5 @@
 use crate::{Growth, SplitVec};
 use alloc::vec::Vec;
-use orx_pinned_vec::PinnedVec;
 
 // std::vec::vec
 impl<T, G> From<SplitVec<T, G>> for Vec<T>
@@ -35,22 +34,8 @@ where
     /// let vec: Vec<_> = split_vec.into();
     /// assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], vec.as_slice());
     /// ```
-    fn from(mut value: SplitVec<T, G>) -> Self {
-        if value.fragments().len() == 1 {
-            value
-                .fragments
-                .into_iter()
-                .map(|x| x.data)
-                .next()
-                .expect("There exists exactly one fragment")
-        } else {
-            let mut vec = Vec::with_capacity(value.len());
-            vec.reserve(value.len());
-            for f in &mut value.fragments {
-                vec.append(&mut f.data);
-            }
-            vec
-        }
+    fn from(value: SplitVec<T, G>) -> Self {
+        value.into_vec()
     }
 }
 
@@ -85,13 +70,60 @@ where
     /// assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], vec.as_slice());
     /// ```
     pub fn to_vec(self) -> Vec<T> {
-        self.into()
+        self.into_vec()
+    }
+
+    /// Converts the `SplitVec` into a standard `Vec` with a contiguous memory layout.
+    ///
+    /// * If the split vector is composed of only one fragment, the fragment is immediately returned as a `Vec` without any cost.
+    /// * Otherwise, a single `Vec` with a capacity equal to the length of the split vector is allocated, and the elements
+    ///   of each fragment are moved into it with one bulk memory copy per fragment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut split_vec = SplitVec::with_doubling_growth();
+    /// split_vec.extend(0..100);
+    /// assert_eq!(5, split_vec.fragments().len());
+    ///
+    /// let vec = split_vec.into_vec();
+    /// assert_eq!(vec, (0..100).collect::<Vec<_>>());
+    /// assert_eq!(vec.capacity(), 100);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        if self.fragments.len() == 1 {
+            self.len = 0;
+            self.capacity = 0;
+            return core::mem::take(&mut self.fragments[0].data);
+        }
+
+        let mut vec: Vec<T> = Vec::with_capacity(self.len);
+        let mut len = 0;
+        for fragment in &mut self.fragments {
+            let fragment_len = fragment.len();
+            // SAFETY: vec has capacity for all elements of all fragments; the length of the fragment is set to zero
+            // right after so that each element is moved exactly once
+            unsafe {
+                let dst = vec.as_mut_ptr().add(len);
+                core::ptr::copy_nonoverlapping(fragment.as_ptr(), dst, fragment_len);
+                fragment.set_len(0);
+            }
+            len += fragment_len;
+        }
+        // SAFETY: first len positions are initialized by the copies above
+        unsafe { vec.set_len(len) };
+        vec
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_all_growth_types;
     use crate::*;
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
     use alloc::vec::Vec;
 
     #[test]
@@ -120,4 +152,37 @@ mod tests {
             assert_eq!(i, *val);
         }
     }
+
+    #[test]
+    fn into_vec() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            for n in [0, 1, 4, 5, 33, 100, 1000] {
+                vec.clear();
+                vec.extend((0..n).map(|x| x.to_string()));
+
+                let flat = vec.clone().into_vec();
+                assert_eq!(flat, (0..n).map(|x| x.to_string()).collect::<Vec<_>>());
+                if vec.fragments().len() > 1 {
+                    assert_eq!(flat.capacity(), n);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn into_vec_moves_elements() {
+        let rc = Rc::new(0);
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend((0..50).map(|_| rc.clone()));
+        assert_eq!(Rc::strong_count(&rc), 51);
+
+        let flat = vec.into_vec();
+        assert_eq!(flat.len(), 50);
+        assert_eq!(Rc::strong_count(&rc), 51);
+
+        drop(flat);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
 }
@@ -1,3 +1,23 @@
 mod default;
+mod from_fn;
 mod into;
 mod new;
+mod zeroed;
+
+use crate::Growth;
+use alloc::vec::Vec;
+
+pub use zeroed::SplitVecUninit;
+
+/// Returns the capacities of the fragments that the `growth` creates until their total capacity reaches `len`;
+/// at least one fragment is created.
+pub(crate) fn fragment_capacities_for<G: Growth>(growth: &G, len: usize) -> Vec<usize> {
+    let mut capacities = alloc::vec![growth.first_fragment_capacity()];
+    let mut total = capacities[0];
+    while total < len {
+        let capacity = growth.new_fragment_capacity_from(capacities.iter().copied());
+        capacities.push(capacity);
+        total += capacity;
+    }
+    capacities
+}
@@ -1,4 +1,4 @@
-use crate::{Fragment, Growth, SplitVec};
+use crate::{Doubling, Fragment, Growth, SplitVec};
 
 impl<T> SplitVec<T> {
     /// Creates an empty split vector with default growth strategy.
@@ -18,6 +18,26 @@ impl<T> SplitVec<T> {
     pub fn new() -> Self {
         Self::with_doubling_growth()
     }
+
+    /// Creates an empty split vector with default growth strategy, which can hold at least `capacity` elements
+    /// without allocating.
+    ///
+    /// See [`SplitVec::with_capacity_and_growth`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<usize> = SplitVec::with_capacity(100);
+    /// assert!(vec.capacity() + vec.reserved_capacity() >= 100);
+    ///
+    /// vec.extend(0..100);
+    /// assert_eq!(vec.reserved_capacity(), 0);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_growth(capacity, Doubling)
+    }
 }
 
 impl<T, G> SplitVec<T, G>
@@ -27,6 +47,7 @@ where
     /// Creates an empty split vector with the given `growth` strategy.
     ///
     /// This constructor is especially useful to define custom growth strategies.
+    /// The first fragment is allocated with the capacity of `growth.first_fragment_capacity()`.
     ///
     /// # Examples
     ///
@@ -75,10 +96,43 @@ where
     /// assert_eq!(1, vec.fragments()[2].len());
     /// ```
     pub fn with_growth(growth: G) -> Self {
-        let capacity = Growth::new_fragment_capacity::<T>(&growth, &[]);
+        let capacity = growth.first_fragment_capacity();
         let fragment = Fragment::new(capacity);
         let fragments = alloc::vec![fragment];
-        SplitVec::from_raw_parts(0, fragments, growth)
+        SplitVec::from_parts(0, fragments, growth)
+    }
+
+    /// Creates an empty split vector with the given `growth` strategy, which can hold at least `capacity` elements
+    /// without allocating.
+    ///
+    /// In addition to the first fragment, the vector allocates ahead the subsequent fragments that the growth strategy
+    /// would create, until their total capacity reaches `capacity`.
+    /// The fragments allocated ahead are then used as the vector grows, rather than allocating new fragments.
+    ///
+    /// Note that [`capacity`] only reports the capacity of the fragments in use, which are accessible by [`fragments`];
+    /// while the capacity allocated ahead is reported by [`reserved_capacity`].
+    ///
+    /// [`capacity`]: orx_pinned_vec::PinnedVec::capacity
+    /// [`fragments`]: SplitVec::fragments
+    /// [`reserved_capacity`]: SplitVec::reserved_capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<usize, _> = SplitVec::with_capacity_and_growth(20, Linear::new(3));
+    /// assert_eq!(vec.capacity(), 8);
+    /// assert_eq!(vec.reserved_capacity(), 16);
+    ///
+    /// vec.extend(0..20);
+    /// assert_eq!(vec.capacity(), 24);
+    /// assert_eq!(vec.reserved_capacity(), 0);
+    /// ```
+    pub fn with_capacity_and_growth(capacity: usize, growth: G) -> Self {
+        let mut vec = Self::with_growth(growth);
+        vec.reserve_exact(capacity);
+        vec
     }
 }
 
@@ -86,6 +140,8 @@ where
 mod tests {
     use super::*;
     use crate::{Doubling, Linear};
+    use orx_pinned_vec::PinnedVec;
+    use orx_pseudo_default::PseudoDefault;
 
     #[test]
     fn new() {
@@ -105,6 +161,50 @@ mod tests {
         assert_eq!(4, vec.fragments()[0].capacity());
     }
 
+    #[test]
+    fn with_capacity() {
+        fn test<G: Growth>(growth: G) {
+            for capacity in [0, 1, 4, 5, 100, 1000] {
+                let mut vec: SplitVec<usize, G> =
+                    SplitVec::with_capacity_and_growth(capacity, growth.clone());
+                assert!(vec.capacity() + vec.reserved_capacity() >= capacity);
+                assert_eq!(vec.fragments().len(), 1);
+
+                let reserved: alloc::vec::Vec<_> =
+                    vec.reserved.iter().map(|x| x.as_ptr()).collect();
+                vec.extend(0..capacity);
+
+                let used: alloc::vec::Vec<_> =
+                    vec.fragments()[1..].iter().map(|x| x.as_ptr()).collect();
+                assert_eq!(used, reserved[..used.len()]);
+                for i in 0..capacity {
+                    assert_eq!(vec.get(i), Some(&i));
+                }
+            }
+        }
+
+        test(Doubling);
+        test(Linear::new(3));
+        test(crate::Recursive);
+    }
+
+    #[test]
+    fn with_capacity_after_removing_fragments() {
+        let mut vec: SplitVec<usize> = SplitVec::with_capacity(100);
+        vec.extend(0..12);
+        assert_eq!(vec.fragments().len(), 2);
+
+        vec.truncate(2);
+        vec.extend(2..50);
+        assert_eq!(vec.reserved_capacity(), 0);
+
+        let capacities: alloc::vec::Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+        assert_eq!(capacities, [4, 8, 16, 32]);
+        for i in 0..50 {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+    }
+
     #[test]
     fn with_growth() {
         let vec: SplitVec<char, Linear> = SplitVec::with_growth(Linear::new(3));
@@ -115,4 +215,45 @@ mod tests {
         assert_eq!(1, vec.fragments().len());
         assert_eq!(4, vec.fragments()[0].capacity());
     }
+
+    #[test]
+    fn with_growth_custom_first_fragment_capacity() {
+        #[derive(Clone)]
+        struct Custom;
+
+        impl PseudoDefault for Custom {
+            fn pseudo_default() -> Self {
+                Custom
+            }
+        }
+
+        impl Growth for Custom {
+            fn first_fragment_capacity(&self) -> usize {
+                3
+            }
+
+            fn new_fragment_capacity_from(
+                &self,
+                fragment_capacities: impl ExactSizeIterator<Item = usize>,
+            ) -> usize {
+                fragment_capacities.last().map(|x| x + 1).unwrap_or(8)
+            }
+        }
+
+        let mut vec: SplitVec<usize, Custom> = SplitVec::with_growth(Custom);
+        assert_eq!(1, vec.fragments().len());
+        assert_eq!(3, vec.fragments()[0].capacity());
+
+        for i in 0..10 {
+            vec.push(i);
+        }
+        assert_eq!(vec, (0..10).collect::<alloc::vec::Vec<_>>());
+        assert_eq!(
+            vec.fragments()
+                .iter()
+                .map(|x| x.capacity())
+                .collect::<alloc::vec::Vec<_>>(),
+            [3, 4, 5]
+        );
+    }
 }
@@ -0,0 +1,253 @@
+use super::fragment_capacities_for;
+use crate::{Doubling, Fragment, Growth, SplitVec, Zeroable};
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+impl<T: Zeroable> SplitVec<T, Doubling> {
+    /// Creates a split vector with the default `Doubling` growth containing `len` zero elements.
+    ///
+    /// See [`SplitVec::zeroed_with_growth`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<f64> = SplitVec::zeroed(100);
+    /// assert_eq!(vec.len(), 100);
+    /// assert!(vec.iter().all(|x| *x == 0.0));
+    /// ```
+    pub fn zeroed(len: usize) -> Self {
+        Self::zeroed_with_growth(len, Doubling)
+    }
+}
+
+impl<T: Zeroable, G: Growth> SplitVec<T, G> {
+    /// Creates a split vector with the given `growth` strategy containing `len` zero elements.
+    ///
+    /// All fragments are allocated in one pass with `alloc_zeroed`, rather than writing the elements one by one;
+    /// this makes it a fast way to create large numeric buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<u32, _> = SplitVec::zeroed_with_growth(10, Linear::new(2));
+    /// assert_eq!(vec, [0; 10]);
+    /// assert_eq!(vec.fragments().len(), 3);
+    ///
+    /// vec[7] = 7;
+    /// vec.push(11);
+    /// assert_eq!(vec, [0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 11]);
+    /// ```
+    pub fn zeroed_with_growth(len: usize, growth: G) -> Self {
+        let mut remaining = len;
+        let fragments: Vec<_> = fragment_capacities_for(&growth, len)
+            .into_iter()
+            .map(|capacity| {
+                let mut fragment = Fragment::new_zeroed(capacity);
+                let fragment_len = capacity.min(remaining);
+                // SAFETY: the length is only decreased
+                unsafe { fragment.set_len(fragment_len) };
+                remaining -= fragment_len;
+                fragment
+            })
+            .collect();
+        Self::from_parts(len, fragments, growth)
+    }
+}
+
+impl<T> SplitVec<T, Doubling> {
+    /// Creates a split vector builder with the default `Doubling` growth, with `len` uninitialized elements.
+    ///
+    /// See [`SplitVec::with_uninit_capacity_and_growth`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<usize> = SplitVec::with_uninit_capacity(50).init_with(|i| i * 2);
+    /// assert_eq!(vec.len(), 50);
+    /// assert_eq!(vec[49], 98);
+    /// ```
+    pub fn with_uninit_capacity(len: usize) -> SplitVecUninit<T, Doubling> {
+        Self::with_uninit_capacity_and_growth(len, Doubling)
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Creates a split vector builder with the given `growth` strategy, with `len` uninitialized elements.
+    ///
+    /// All fragments required for `len` elements are allocated in one pass.
+    /// The positions can then be written slice by slice through [`SplitVecUninit::slices_mut`], and the builder is converted
+    /// into the split vector by the unsafe [`SplitVecUninit::assume_init`]; or alternatively, by the safe
+    /// [`SplitVecUninit::init_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut uninit = SplitVec::with_uninit_capacity_and_growth(10, Linear::new(2));
+    ///
+    /// let mut value = 0;
+    /// for slice in uninit.slices_mut() {
+    ///     for x in slice {
+    ///         x.write(value);
+    ///         value += 1;
+    ///     }
+    /// }
+    ///
+    /// // SAFETY: all positions are written above
+    /// let vec = unsafe { uninit.assume_init() };
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn with_uninit_capacity_and_growth(len: usize, growth: G) -> SplitVecUninit<T, G> {
+        let mut remaining = len;
+        let fragments: Vec<_> = fragment_capacities_for(&growth, len)
+            .into_iter()
+            .map(|capacity| {
+                let mut fragment: Fragment<MaybeUninit<T>> = Fragment::new(capacity);
+                let fragment_len = capacity.min(remaining);
+                // SAFETY: uninitialized memory is a valid MaybeUninit
+                unsafe { fragment.set_len(fragment_len) };
+                remaining -= fragment_len;
+                fragment
+            })
+            .collect();
+        SplitVecUninit {
+            len,
+            fragments,
+            growth,
+        }
+    }
+}
+
+/// A builder of a split vector with uninitialized elements, whose fragments are all allocated up front.
+///
+/// Created by [`SplitVec::with_uninit_capacity`] or [`SplitVec::with_uninit_capacity_and_growth`].
+pub struct SplitVecUninit<T, G: Growth> {
+    len: usize,
+    fragments: Vec<Fragment<MaybeUninit<T>>>,
+    growth: G,
+}
+
+impl<T, G: Growth> SplitVecUninit<T, G> {
+    /// Returns the number of elements of the split vector to be built.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the split vector to be built is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the positions of the elements to be built as consecutive mutable slices, one for each fragment.
+    pub fn slices_mut(&mut self) -> impl Iterator<Item = &mut [MaybeUninit<T>]> {
+        self.fragments
+            .iter_mut()
+            .map(|x| x.data.as_mut_slice())
+            .filter(|x| !x.is_empty())
+    }
+
+    /// Converts the builder into the split vector, assuming that all positions are initialized.
+    ///
+    /// # Safety
+    ///
+    /// All positions returned by [`SplitVecUninit::slices_mut`] must be initialized.
+    pub unsafe fn assume_init(self) -> SplitVec<T, G> {
+        let fragments: Vec<_> = self
+            .fragments
+            .into_iter()
+            .map(|fragment| {
+                let mut data = core::mem::ManuallyDrop::new(fragment.data);
+                let (ptr, len, capacity) = (data.as_mut_ptr(), data.len(), data.capacity());
+                // SAFETY: MaybeUninit<T> has the same layout as T, and the elements are initialized
+                Fragment::from(unsafe { Vec::from_raw_parts(ptr as *mut T, len, capacity) })
+            })
+            .collect();
+        SplitVec::from_parts(self.len, fragments, self.growth)
+    }
+
+    /// Initializes each position `i` with `f(i)` and converts the builder into the split vector.
+    pub fn init_with<F: FnMut(usize) -> T>(mut self, mut f: F) -> SplitVec<T, G> {
+        let mut i = 0;
+        for slice in self.slices_mut() {
+            for x in slice {
+                x.write(f(i));
+                i += 1;
+            }
+        }
+        // SAFETY: all positions are written above
+        unsafe { self.assume_init() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn zeroed() {
+        fn test<G: Growth>(vec: SplitVec<u64, G>) {
+            for len in [0, 1, 4, 5, 100, 1033] {
+                let mut zeroed = SplitVec::zeroed_with_growth(len, vec.growth().clone());
+                assert_eq!(zeroed, alloc::vec![0u64; len]);
+                assert_eq!(
+                    zeroed.capacity(),
+                    zeroed
+                        .fragments()
+                        .iter()
+                        .map(|x| x.capacity())
+                        .sum::<usize>()
+                );
+
+                zeroed.extend(0..10);
+                let mut expected = alloc::vec![0u64; len];
+                expected.extend(0..10);
+                assert_eq!(zeroed, expected);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn zeroed_follows_growth() {
+        let zeroed: SplitVec<[u8; 3], _> = SplitVec::zeroed_with_growth(100, Doubling);
+        let mut pushed = SplitVec::with_doubling_growth();
+        pushed.extend((0..100).map(|_| [0u8; 3]));
+        let capacities = |v: &SplitVec<[u8; 3], Doubling>| -> Vec<_> {
+            v.fragments()
+                .iter()
+                .map(|x| (x.len(), x.capacity()))
+                .collect()
+        };
+        assert_eq!(capacities(&zeroed), capacities(&pushed));
+    }
+
+    #[test]
+    fn uninit() {
+        fn test<G: Growth>(vec: SplitVec<String, G>) {
+            for len in [0, 1, 4, 5, 100, 1033] {
+                let uninit = SplitVec::with_uninit_capacity_and_growth(len, vec.growth().clone());
+                assert_eq!(uninit.len(), len);
+                let mut built = uninit.init_with(|i| i.to_string());
+
+                let expected: Vec<_> = (0..len).map(|i| i.to_string()).collect();
+                assert_eq!(built, expected);
+
+                built.push("x".to_string());
+                assert_eq!(built.len(), len + 1);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
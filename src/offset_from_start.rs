@@ -0,0 +1,165 @@
+use crate::{ConcurrentSplitVec, Growth, GrowthWithConstantTimeAccess, SplitVec};
+use orx_pinned_vec::ConcurrentPinnedVec;
+
+/// Returns the position of the element that `ptr` points to within the `len` elements starting at `begin`;
+/// returns None if the pointer is out of bounds or not aligned to an element.
+fn offset_in<T>(begin: *const T, len: usize, ptr: *const T) -> Option<usize> {
+    let size = core::mem::size_of::<T>();
+    let (begin, ptr) = (begin as usize, ptr as usize);
+    match size > 0 && ptr >= begin {
+        true => {
+            let diff = ptr - begin;
+            let offset = diff / size;
+            (offset < len && diff % size == 0).then_some(offset)
+        }
+        false => None,
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns the index of the element that the given `ptr` points to;
+    /// returns None if the pointer does not point to an element of the vector.
+    ///
+    /// The pointer is located by comparing addresses against the memory range of each fragment,
+    /// without iterating over the elements; hence, it has ***O(f)*** time complexity where f is the number of fragments.
+    ///
+    /// Since the vector keeps its elements pinned, a pointer obtained from the vector remains valid
+    /// as long as the element is not removed; this allows pointer-based bookkeeping which can be
+    /// converted back to indices whenever required.
+    ///
+    /// Note that pointers to elements of zero-sized types do not identify the elements; hence,
+    /// this method always returns None for zero-sized types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let ptr = &vec[5] as *const i32;
+    /// vec.push(6);
+    /// assert_eq!(vec.offset_from_start(ptr), Some(5));
+    ///
+    /// let other = 5;
+    /// assert_eq!(vec.offset_from_start(&other), None);
+    /// ```
+    pub fn offset_from_start(&self, ptr: *const T) -> Option<usize> {
+        let mut begin = 0;
+        for fragment in &self.fragments {
+            if let Some(offset) = offset_in(fragment.as_ptr(), fragment.len(), ptr) {
+                return Some(begin + offset);
+            }
+            begin += fragment.len();
+        }
+        None
+    }
+}
+
+impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+    /// Returns the index of the element that the given `ptr` points to, given that the vector has `len` elements;
+    /// returns None if the pointer does not point to any of the first `len` positions of the vector.
+    ///
+    /// The pointer is located by comparing addresses against the memory range of each fragment;
+    /// hence, it has ***O(f)*** time complexity where f is the number of fragments.
+    ///
+    /// Note that pointers to elements of zero-sized types do not identify the elements; hence,
+    /// this method always returns None for zero-sized types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the capacity of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..20).collect();
+    /// let con_vec = vec.into_concurrent();
+    ///
+    /// let ptr = unsafe { con_vec.get_ptr_mut(13) } as *const i32;
+    /// assert_eq!(con_vec.offset_from_start(ptr, 20), Some(13));
+    /// assert_eq!(con_vec.offset_from_start(ptr, 13), None);
+    /// ```
+    pub fn offset_from_start(&self, ptr: *const T, len: usize) -> Option<usize> {
+        assert!(len <= self.capacity(), "len cannot exceed the capacity");
+
+        let mut begin = 0;
+        let mut f = 0;
+        while begin < len {
+            let capacity = self.capacity_of(f);
+            let fragment_len = capacity.min(len - begin);
+            // SAFETY: fragment f is allocated since begin < len <= capacity
+            let fragment_ptr = unsafe { self.get_raw_mut_unchecked_fi(f, 0) };
+            if let Some(offset) = offset_in(fragment_ptr as *const T, fragment_len, ptr) {
+                return Some(begin + offset);
+            }
+            begin += capacity;
+            f += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn offset_from_start() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.clear();
+            let other = 42;
+            assert_eq!(vec.offset_from_start(&other), None);
+
+            let n = 564;
+            vec.extend(0..n);
+            let ptrs: Vec<_> = (0..n).map(|i| &vec[i] as *const usize).collect();
+
+            vec.extend(n..(2 * n));
+            for (i, ptr) in ptrs.iter().enumerate() {
+                assert_eq!(vec.offset_from_start(*ptr), Some(i));
+                assert_eq!(vec.index_of_ptr(*ptr), Some(i));
+            }
+
+            assert_eq!(vec.offset_from_start(&other), None);
+            let misaligned = (ptrs[3] as *const u8).wrapping_add(1) as *const usize;
+            assert_eq!(vec.offset_from_start(misaligned), None);
+
+            vec.truncate(10);
+            assert_eq!(vec.offset_from_start(ptrs[9]), Some(9));
+            assert_eq!(vec.offset_from_start(ptrs[10]), None);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn offset_from_start_zero_sized() {
+        let mut vec = SplitVec::new();
+        vec.push(());
+        assert_eq!(vec.offset_from_start(&vec[0]), None);
+    }
+
+    #[test]
+    fn con_offset_from_start() {
+        let mut vec = SplitVec::with_linear_growth(3);
+        vec.extend(0..100usize);
+        let con_vec = vec.into_concurrent();
+
+        for i in 0..100 {
+            let ptr = unsafe { con_vec.get_ptr_mut(i) } as *const usize;
+            assert_eq!(con_vec.offset_from_start(ptr, 100), Some(i));
+            assert_eq!(con_vec.offset_from_start(ptr, i + 1), Some(i));
+            assert_eq!(con_vec.offset_from_start(ptr, i), None);
+        }
+
+        let other = 42;
+        assert_eq!(con_vec.offset_from_start(&other, 100), None);
+        assert_eq!(con_vec.offset_from_start(&other, 0), None);
+    }
+}
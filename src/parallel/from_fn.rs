@@ -0,0 +1,80 @@
+use crate::new_split_vec::fragment_capacities_for;
+use crate::{Doubling, Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+impl<T: Send> SplitVec<T, Doubling> {
+    /// Creates a split vector with the default `Doubling` growth containing `len` elements,
+    /// where the element at position `i` is `f(i)`; the elements are created in parallel.
+    ///
+    /// Available with the **parallel** feature, or equivalently, the **rayon** feature.
+    ///
+    /// See [`SplitVec::par_from_fn_with_growth`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = SplitVec::par_from_fn(1000, |i| i * 2);
+    /// assert_eq!(vec.len(), 1000);
+    /// assert_eq!(vec[999], 1998);
+    /// ```
+    pub fn par_from_fn<F: Fn(usize) -> T + Sync>(len: usize, f: F) -> Self {
+        Self::par_from_fn_with_growth(len, Doubling, f)
+    }
+}
+
+impl<T: Send, G: Growth> SplitVec<T, G> {
+    /// Creates a split vector with the given `growth` strategy containing `len` elements,
+    /// where the element at position `i` is `f(i)`; the elements are created in parallel.
+    ///
+    /// This is the parallel counterpart of [`SplitVec::from_fn_with_growth`]: the exact fragments are allocated
+    /// up front, and the positions of all fragments are then written concurrently.
+    ///
+    /// If `f` panics, the elements already created are leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec = SplitVec::par_from_fn_with_growth(7, Linear::new(2), |i| i * 10);
+    /// assert_eq!(vec, [0, 10, 20, 30, 40, 50, 60]);
+    /// ```
+    pub fn par_from_fn_with_growth<F: Fn(usize) -> T + Sync>(len: usize, growth: G, f: F) -> Self {
+        let mut fragments: Vec<_> = fragment_capacities_for(&growth, len)
+            .into_iter()
+            .map(Fragment::new)
+            .collect();
+
+        let mut begin = 0;
+        let mut slices = Vec::with_capacity(fragments.len());
+        for fragment in fragments.iter_mut() {
+            let fragment_len = fragment.capacity().min(len - begin);
+            slices.push((begin, &mut fragment.spare_capacity_mut()[..fragment_len]));
+            begin += fragment_len;
+        }
+
+        slices
+            .into_par_iter()
+            .flat_map(|(begin, slice)| {
+                slice
+                    .par_iter_mut()
+                    .enumerate()
+                    .map(move |(i, x)| (begin + i, x))
+            })
+            .for_each(|(i, x)| {
+                x.write(f(i));
+            });
+
+        let mut remaining = len;
+        for fragment in fragments.iter_mut() {
+            let fragment_len = fragment.capacity().min(remaining);
+            // SAFETY: all positions within `fragment_len` are written above
+            unsafe { fragment.set_len(fragment_len) };
+            remaining -= fragment_len;
+        }
+        Self::from_parts(len, fragments, growth)
+    }
+}
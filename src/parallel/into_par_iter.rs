@@ -0,0 +1,128 @@
+use super::parts::{Parts, SplitSlice};
+use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::mem::take;
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Parallel iterator that moves out of the `SplitVec`.
+///
+/// This struct is created by `into_par_iter` method on `SplitVec` (provided by the `IntoParallelIterator` trait).
+#[derive(Debug)]
+pub struct IntoParIter<T: Send> {
+    fragments: Vec<Fragment<T>>,
+    len: usize,
+}
+
+impl<T: Send, G: Growth> IntoParallelIterator for SplitVec<T, G> {
+    type Iter = IntoParIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter {
+            fragments: self.fragments,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Send> ParallelIterator for IntoParIter<T> {
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for IntoParIter<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        let mut slices = Vec::with_capacity(self.fragments.len());
+        for fragment in self.fragments.iter_mut() {
+            let (ptr, len) = (fragment.as_mut_ptr(), fragment.len());
+            // SAFETY: ownership of the elements is transferred to the drained slices which drop the elements not yielded;
+            // the fragments only deallocate their memory afterwards.
+            unsafe { fragment.set_len(0) };
+            slices.push(Drained(unsafe {
+                core::slice::from_raw_parts_mut(ptr, len)
+            }));
+        }
+        self.len = 0;
+
+        callback.callback(Parts::new(&mut slices))
+    }
+}
+
+/// A slice of elements owned by the parallel iterator; elements are moved out when iterated and
+/// the remaining elements are dropped in place when dropped.
+struct Drained<'a, T>(&'a mut [T]);
+
+impl<T> Default for Drained<'_, T> {
+    fn default() -> Self {
+        Self(&mut [])
+    }
+}
+
+impl<T> Drop for Drained<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: elements of the slice are owned and not yet moved out
+        unsafe { core::ptr::drop_in_place(take(&mut self.0) as *mut [T]) };
+    }
+}
+
+impl<T: Send> SplitSlice for Drained<'_, T> {
+    type Iter = Self;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let (a, b) = take(&mut self.0).split_at_mut(index);
+        (Drained(a), Drained(b))
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self
+    }
+}
+
+impl<T> Iterator for Drained<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = take(&mut self.0).split_first_mut()?;
+        self.0 = rest;
+        // SAFETY: the element is removed from the slice; hence, it is moved out exactly once
+        Some(unsafe { core::ptr::read(first) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drained<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = take(&mut self.0).split_last_mut()?;
+        self.0 = rest;
+        // SAFETY: the element is removed from the slice; hence, it is moved out exactly once
+        Some(unsafe { core::ptr::read(last) })
+    }
+}
+
+impl<T> ExactSizeIterator for Drained<'_, T> {}
+
+impl<T> FusedIterator for Drained<'_, T> {}
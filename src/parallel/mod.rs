@@ -0,0 +1,18 @@
+//! Parallel iterators over the split vector, available with the `rayon` feature.
+//!
+//! The iterators drive rayon directly from the fragment slices; hence, the vector is not collected into
+//! a contiguous `Vec` to be parallelized. Splitting a range of the vector only requires splitting the
+//! fragment containing the split point, which maps naturally to rayon's split model.
+
+mod from_fn;
+mod into_par_iter;
+mod par_iter;
+mod par_iter_mut;
+mod parts;
+
+pub use into_par_iter::IntoParIter;
+pub use par_iter::ParIter;
+pub use par_iter_mut::ParIterMut;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,52 @@
+use super::parts::Parts;
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Parallel iterator over references to the elements of the `SplitVec`.
+///
+/// This struct is created by `SplitVec::par_iter()` method, or by `into_par_iter` on a shared reference of the vector.
+#[derive(Debug)]
+pub struct ParIter<'a, T: Sync> {
+    slices: Vec<&'a [T]>,
+    len: usize,
+}
+
+impl<'a, T: Sync + 'a, G: Growth> IntoParallelIterator for &'a SplitVec<T, G> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            slices: self.fragments.iter().map(|x| x.as_slice()).collect(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T: Sync + 'a> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'a, T: Sync + 'a> IndexedParallelIterator for ParIter<'a, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        callback.callback(Parts::new(&mut self.slices))
+    }
+}
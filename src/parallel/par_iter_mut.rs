@@ -0,0 +1,56 @@
+use super::parts::Parts;
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+/// Parallel iterator over mutable references to the elements of the `SplitVec`.
+///
+/// This struct is created by `SplitVec::par_iter_mut()` method, or by `into_par_iter` on a mutable reference of the vector.
+#[derive(Debug)]
+pub struct ParIterMut<'a, T: Send> {
+    slices: Vec<&'a mut [T]>,
+    len: usize,
+}
+
+impl<'a, T: Send + 'a, G: Growth> IntoParallelIterator for &'a mut SplitVec<T, G> {
+    type Iter = ParIterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            slices: self
+                .fragments
+                .iter_mut()
+                .map(|x| x.as_mut_slice())
+                .collect(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T: Send + 'a> ParallelIterator for ParIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'a, T: Send + 'a> IndexedParallelIterator for ParIterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        callback.callback(Parts::new(&mut self.slices))
+    }
+}
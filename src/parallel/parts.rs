@@ -0,0 +1,189 @@
+use core::iter::FusedIterator;
+use core::mem::take;
+use rayon::iter::plumbing::Producer;
+
+/// A contiguous piece of the vector which can be split into two pieces and iterated sequentially.
+pub(crate) trait SplitSlice: Default + Send + Sized {
+    type Iter: DoubleEndedIterator + ExactSizeIterator;
+
+    fn len(&self) -> usize;
+
+    fn split_at(self, index: usize) -> (Self, Self);
+
+    fn into_iter(self) -> Self::Iter;
+}
+
+impl<'a, T: Sync> SplitSlice for &'a [T] {
+    type Iter = core::slice::Iter<'a, T>;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        <[T]>::split_at(self, index)
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Send> SplitSlice for &'a mut [T] {
+    type Iter = core::slice::IterMut<'a, T>;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at_mut(index)
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self.iter_mut()
+    }
+}
+
+/// A range of the vector composed of the end of a slice, the `middle` slices and the beginning of the `last` slice.
+///
+/// The `middle` slices are taken out of the collection as they are split or iterated, leaving empty slices behind.
+pub(crate) struct Parts<'b, S: SplitSlice> {
+    first: S,
+    middle: &'b mut [S],
+    last: S,
+    len: usize,
+}
+
+impl<'b, S: SplitSlice> Parts<'b, S> {
+    pub(crate) fn new(slices: &'b mut [S]) -> Self {
+        let len = slices.iter().map(|x| x.len()).sum();
+        Self::from_parts(S::default(), slices, S::default(), len)
+    }
+
+    fn from_parts(first: S, middle: &'b mut [S], last: S, len: usize) -> Self {
+        Self {
+            first,
+            middle,
+            last,
+            len,
+        }
+    }
+}
+
+impl<'b, S: SplitSlice> Producer for Parts<'b, S> {
+    type Item = <S::Iter as Iterator>::Item;
+    type IntoIter = PartsIter<'b, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PartsIter {
+            first: self.first.into_iter(),
+            middle: self.middle.iter_mut(),
+            last: self.last.into_iter(),
+            len: self.len,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let Self {
+            first,
+            middle,
+            last,
+            len,
+        } = self;
+        let right_len = len - index;
+
+        if index <= first.len() {
+            let (a, b) = first.split_at(index);
+            let left = Self::from_parts(a, &mut [], S::default(), index);
+            return (left, Self::from_parts(b, middle, last, right_len));
+        }
+
+        let mut position = index - first.len();
+        let mut split = None;
+        for (k, slice) in middle.iter().enumerate() {
+            if position <= slice.len() {
+                split = Some(k);
+                break;
+            }
+            position -= slice.len();
+        }
+
+        match split {
+            Some(k) => {
+                let (left_middle, right_middle) = middle.split_at_mut(k);
+                let (fragment, right_middle) = right_middle.split_at_mut(1);
+                let (a, b) = take(&mut fragment[0]).split_at(position);
+                let left = Self::from_parts(first, left_middle, a, index);
+                (left, Self::from_parts(b, right_middle, last, right_len))
+            }
+            None => {
+                let (a, b) = last.split_at(position);
+                let left = Self::from_parts(first, middle, a, index);
+                (left, Self::from_parts(b, &mut [], S::default(), right_len))
+            }
+        }
+    }
+}
+
+/// Sequential iterator over the elements of [`Parts`].
+pub(crate) struct PartsIter<'b, S: SplitSlice> {
+    first: S::Iter,
+    middle: core::slice::IterMut<'b, S>,
+    last: S::Iter,
+    len: usize,
+}
+
+impl<S: SplitSlice> Iterator for PartsIter<'_, S> {
+    type Item = <S::Iter as Iterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.first.next() {
+                self.len -= 1;
+                return Some(x);
+            }
+            match self.middle.next() {
+                Some(slice) => self.first = take(slice).into_iter(),
+                None => break,
+            }
+        }
+        let x = self.last.next();
+        if x.is_some() {
+            self.len -= 1;
+        }
+        x
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<S: SplitSlice> DoubleEndedIterator for PartsIter<'_, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.last.next_back() {
+                self.len -= 1;
+                return Some(x);
+            }
+            match self.middle.next_back() {
+                Some(slice) => self.last = take(slice).into_iter(),
+                None => break,
+            }
+        }
+        let x = self.first.next_back();
+        if x.is_some() {
+            self.len -= 1;
+        }
+        x
+    }
+}
+
+impl<S: SplitSlice> ExactSizeIterator for PartsIter<'_, S> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<S: SplitSlice> FusedIterator for PartsIter<'_, S> {}
@@ -0,0 +1,119 @@
+use crate::test_all_growth_types;
+use crate::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+#[test]
+fn par_iter() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        assert_eq!(vec.par_iter().count(), 0);
+
+        let n = 5678;
+        vec.extend(0..n);
+
+        assert_eq!(vec.par_iter().len(), n);
+        assert_eq!(vec.par_iter().sum::<usize>(), (0..n).sum());
+
+        let collected: Vec<_> = vec.par_iter().map(|x| x * 2).collect();
+        assert_eq!(collected, (0..n).map(|x| x * 2).collect::<Vec<_>>());
+
+        let rev: Vec<_> = vec.par_iter().rev().copied().collect();
+        assert_eq!(rev, (0..n).rev().collect::<Vec<_>>());
+
+        let zipped = vec.par_iter().zip(vec.par_iter().skip(1));
+        assert!(zipped.all(|(a, b)| a + 1 == *b));
+
+        for min_len in [1, 3, 100] {
+            let collected: Vec<_> = vec.par_iter().with_min_len(min_len).copied().collect();
+            assert_eq!(collected, (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn par_iter_mut() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let n = 5678;
+        vec.extend(0..n);
+
+        vec.par_iter_mut().for_each(|x| *x += 10);
+        assert_eq!(vec, (10..(n + 10)).collect::<Vec<_>>());
+
+        vec.par_iter_mut().enumerate().for_each(|(i, x)| *x = i * 3);
+        assert_eq!(vec, (0..n).map(|x| x * 3).collect::<Vec<_>>());
+    }
+
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn into_par_iter() {
+    fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+        let n = 5678;
+        vec.extend((0..n).map(|x| x.to_string()));
+
+        let collected: Vec<_> = vec.clone().into_par_iter().collect();
+        assert_eq!(collected, (0..n).map(|x| x.to_string()).collect::<Vec<_>>());
+
+        let lens: usize = vec.clone().into_par_iter().map(|x| x.len()).sum();
+        assert_eq!(lens, (0..n).map(|x| x.to_string().len()).sum());
+
+        // partially consumed; remaining elements are dropped
+        let first: Vec<_> = vec.into_par_iter().take(100).collect();
+        assert_eq!(first, (0..100).map(|x| x.to_string()).collect::<Vec<_>>());
+    }
+
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn into_par_iter_with_appended_fragments() {
+    let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+    vec.append(Vec::<usize>::new());
+    vec.append((0..3).collect::<Vec<_>>());
+    vec.append(Vec::<usize>::new());
+    vec.append((3..1000).collect::<Vec<_>>());
+
+    assert_eq!(vec.par_iter().len(), 1000);
+    let collected: Vec<_> = vec.into_par_iter().with_max_len(7).collect();
+    assert_eq!(collected, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn into_par_iter_drops_remaining() {
+    fn test<G: Growth>(mut vec: SplitVec<alloc::sync::Arc<usize>, G>) {
+        let n = 2345;
+        let arc = alloc::sync::Arc::new(0);
+        vec.extend((0..n).map(|_| arc.clone()));
+
+        let taken: Vec<_> = vec.into_par_iter().skip(10).take(1000).collect();
+        assert_eq!(taken.len(), 1000);
+        assert_eq!(alloc::sync::Arc::strong_count(&arc), 1000 + 1);
+
+        drop(taken);
+        assert_eq!(alloc::sync::Arc::strong_count(&arc), 1);
+    }
+
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn par_from_fn() {
+    fn test<G: Growth>(vec: SplitVec<String, G>) {
+        for len in [0, 1, 4, 5, 100, 5678] {
+            let mut built =
+                SplitVec::par_from_fn_with_growth(len, vec.growth().clone(), |i| i.to_string());
+            let mut expected: Vec<_> = (0..len).map(|i| i.to_string()).collect();
+            assert_eq!(built, expected);
+
+            built.push("x".to_string());
+            expected.push("x".to_string());
+            assert_eq!(built, expected);
+        }
+    }
+
+    test_all_growth_types!(test);
+}
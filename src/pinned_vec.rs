@@ -1,5 +1,6 @@
 use crate::common_traits::iterator::iter_ptr::IterPtr;
 use crate::common_traits::iterator::iter_ptr_bwd::IterPtrBackward;
+use crate::common_traits::iterator::slice_mut_iter::SliceMutIter;
 use crate::fragment::fragment_struct::set_fragments_len;
 use crate::range_helpers::{range_end, range_start};
 use crate::{algorithms, Fragment, Growth, SplitVec};
@@ -15,7 +16,7 @@ impl<T, G: Growth> PseudoDefault for SplitVec<T, G> {
         let growth = G::pseudo_default();
         let capacity = growth.first_fragment_capacity();
         let fragments = alloc::vec![Fragment::new(capacity)];
-        Self::from_raw_parts(0, fragments, growth)
+        Self::from_parts(0, fragments, growth)
     }
 }
 
@@ -46,7 +47,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         T: 'a,
         Self: 'a;
     type SliceMutIter<'a>
-        = Vec<&'a mut [T]>
+        = SliceMutIter<'a, T>
     where
         T: 'a,
         Self: 'a;
@@ -121,16 +122,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// Therefore, it is possible and safe to compare an element's reference
     /// to find its position in the vector.
     fn index_of_ptr(&self, element_ptr: *const T) -> Option<usize> {
-        // TODO! # examples in docs
-        let mut count = 0;
-        for fragment in &self.fragments {
-            if let Some(index) = slice::index_of_ptr(&fragment.data, element_ptr) {
-                return Some(count + index);
-            } else {
-                count += fragment.len()
-            }
-        }
-        None
+        self.offset_from_start(element_ptr)
     }
 
     fn push_get_ptr(&mut self, value: T) -> *const T {
@@ -249,7 +241,11 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     ///
     /// ```
     fn capacity(&self) -> usize {
-        self.fragments.iter().map(|f| f.capacity()).sum()
+        debug_assert_eq!(
+            self.capacity,
+            self.fragments.iter().map(|f| f.capacity()).sum::<usize>()
+        );
+        self.capacity
     }
 
     fn capacity_state(&self) -> CapacityState {
@@ -280,8 +276,9 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// assert!(vec.is_empty());
     /// ```
     fn clear(&mut self) {
+        self.invalidate_stable_indices();
         if !self.fragments.is_empty() {
-            self.fragments.truncate(1);
+            self.release_fragments_after(1);
             self.fragments[0].clear();
         }
         self.len = 0;
@@ -459,6 +456,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         if index == self.len {
             self.push(value);
         } else {
+            self.invalidate_stable_indices();
             // make room for one
             if !self.has_capacity_for_one() {
                 self.add_fragment();
@@ -527,6 +525,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     fn pop(&mut self) -> Option<T> {
+        self.invalidate_stable_indices();
         if self.fragments.is_empty() {
             None
         } else {
@@ -536,14 +535,14 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
                     None
                 } else {
                     self.len -= 1;
-                    self.fragments.pop();
+                    self.release_fragments_after(f);
                     self.fragments[f - 1].pop()
                 }
             } else {
                 self.len -= 1;
                 let popped = self.fragments[f].pop();
                 if self.fragments[f].is_empty() {
-                    self.fragments.pop();
+                    self.release_fragments_after(f);
                 }
                 popped
             }
@@ -575,6 +574,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     fn remove(&mut self, index: usize) -> T {
+        self.invalidate_stable_indices();
         self.drop_last_empty_fragment();
 
         let (f, i) = self
@@ -587,7 +587,8 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
             let x = self.fragments[f2].remove(0);
             self.fragments[f2 - 1].push(x);
             if self.fragments[f2].is_empty() {
-                self.fragments.remove(f2);
+                // the emptied fragment is the last one since all fragments but the last are full
+                self.release_fragments_after(f2);
                 break;
             }
         }
@@ -599,6 +600,7 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     fn swap(&mut self, a: usize, b: usize) {
+        self.invalidate_stable_indices();
         let (af, ai) = self
             .get_fragment_and_inner_indices(a)
             .expect("first index is out-of-bounds");
@@ -616,8 +618,9 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     fn truncate(&mut self, len: usize) {
+        self.invalidate_stable_indices();
         if let Some((f, i)) = self.get_fragment_and_inner_indices(len) {
-            self.fragments.truncate(f + 1);
+            self.release_fragments_after(f + 1);
             self.fragments[f].truncate(i);
             self.len = len;
 
@@ -701,11 +704,13 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         }
     }
 
-    /// Returns a mutable view on the required `range` as a vector of slices:
+    /// Returns a mutable view on the required `range` as an iterator of slices:
     ///
-    /// * returns an empty vector if the range is out of bounds;
-    /// * returns a vector with one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
-    /// * returns an ordered vector of slices when chained forms the required range.
+    /// * returns an empty iterator if the range is out of bounds;
+    /// * returns an iterator of one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
+    /// * returns an iterator of ordered slices when chained forms the required range.
+    ///
+    /// The slices are created lazily from the fragments; hence, this method does not allocate.
     ///
     /// # Examples
     ///
@@ -722,19 +727,21 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// // single fragment
     /// let mut slices = vec.slices_mut(0..4);
     /// assert_eq!(slices.len(), 1);
-    /// assert_eq!(slices[0], &[0, 1, 2, 3]);
-    /// slices[0][1] *= 10;
+    /// let slice = slices.next().unwrap();
+    /// assert_eq!(slice, &[0, 1, 2, 3]);
+    /// slice[1] *= 10;
     /// assert_eq!(vec.fragments()[0], &[0, 10, 2, 3]);
     ///
     /// // single fragment - partially
     /// let mut slices = vec.slices_mut(5..7);
     /// assert_eq!(slices.len(), 1);
-    /// assert_eq!(slices[0], &[5, 6]);
-    /// slices[0][1] *= 10;
+    /// let slice = slices.next().unwrap();
+    /// assert_eq!(slice, &[5, 6]);
+    /// slice[1] *= 10;
     /// assert_eq!(vec.fragments()[1], &[4, 5, 60, 7]);
     ///
     /// // multiple fragments
-    /// let slices = vec.slices_mut(2..6);
+    /// let slices: Vec<_> = vec.slices_mut(2..6).collect();
     /// assert_eq!(slices.len(), 2);
     /// assert_eq!(slices[0], &[2, 3]);
     /// assert_eq!(slices[1], &[4, 5]);
@@ -753,33 +760,26 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// assert!(vec.slices_mut(10..11).is_empty());
     /// ```
     fn slices_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Self::SliceMutIter<'_> {
-        use alloc::vec;
-        use core::slice::from_raw_parts_mut;
-
         let a = range_start(&range);
         let b = range_end(&range, self.len());
 
         match b.saturating_sub(a) {
-            0 => Vec::new(),
+            0 => SliceMutIter::default(),
             _ => match self.get_fragment_and_inner_indices(a) {
-                None => Vec::new(),
+                None => SliceMutIter::default(),
                 Some((sf, si)) => match self.get_fragment_and_inner_indices(b - 1) {
-                    None => Vec::new(),
+                    None => SliceMutIter::default(),
                     Some((ef, ei)) => match sf.cmp(&ef) {
-                        Ordering::Equal => vec![&mut self.fragments[sf][si..=ei]],
+                        Ordering::Equal => {
+                            let first = &mut self.fragments[sf][si..=ei];
+                            SliceMutIter::new(Some(first), &mut [], None)
+                        }
                         _ => {
-                            let mut vec = Vec::with_capacity(ef - sf + 1);
-
-                            let ptr_s = unsafe { self.fragments[sf].as_mut_ptr().add(si) };
-                            let slice_len = self.fragments[sf].capacity() - si;
-                            vec.push(unsafe { from_raw_parts_mut(ptr_s, slice_len) });
-                            for f in sf + 1..ef {
-                                let ptr_s = self.fragments[f].as_mut_ptr();
-                                let slice_len = self.fragments[f].capacity();
-                                vec.push(unsafe { from_raw_parts_mut(ptr_s, slice_len) });
-                            }
-                            vec.push(&mut self.fragments[ef][..=ei]);
-                            vec
+                            let (first, rest) = self.fragments[sf..=ef].split_at_mut(1);
+                            let (middle, last) = rest.split_at_mut(rest.len() - 1);
+                            let first = &mut first[0][si..];
+                            let last = &mut last[0][..=ei];
+                            SliceMutIter::new(Some(first), middle, Some(last))
                         }
                     },
                 },
@@ -824,6 +824,13 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     unsafe fn set_len(&mut self, new_len: usize) {
+        if new_len > self.capacity {
+            self.adopt_reserved_fragments(new_len);
+        }
+        debug_assert!(
+            new_len <= self.capacity,
+            "new length must not exceed the capacity together with the reserved fragments"
+        );
         set_fragments_len(&mut self.fragments, new_len);
         self.len = new_len;
     }
@@ -839,14 +846,16 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     where
         T: Ord,
     {
-        algorithms::in_place_sort::in_place_sort_by(&mut self.fragments, T::cmp)
+        self.invalidate_stable_indices();
+        algorithms::stable_sort::stable_sort_by(&mut self.fragments, T::cmp)
     }
 
     fn sort_by<F>(&mut self, compare: F)
     where
         F: FnMut(&T, &T) -> Ordering,
     {
-        algorithms::in_place_sort::in_place_sort_by(&mut self.fragments, compare)
+        self.invalidate_stable_indices();
+        algorithms::stable_sort::stable_sort_by(&mut self.fragments, compare)
     }
 
     fn sort_by_key<K, F>(&mut self, mut f: F)
@@ -854,8 +863,9 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         F: FnMut(&T) -> K,
         K: Ord,
     {
+        self.invalidate_stable_indices();
         let compare = |a: &T, b: &T| f(a).cmp(&f(b));
-        algorithms::in_place_sort::in_place_sort_by(&mut self.fragments, compare)
+        algorithms::stable_sort::stable_sort_by(&mut self.fragments, compare)
     }
 }
 
@@ -1280,7 +1290,7 @@ mod tests {
                 vec.push(0);
             }
 
-            fn update(slice: Vec<&mut [usize]>, begin: usize) {
+            fn update(slice: SliceMutIter<usize>, begin: usize) {
                 let mut val = begin;
                 for s in slice {
                     for x in s {
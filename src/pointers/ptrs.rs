@@ -44,6 +44,11 @@ impl<T> Ptrs<T> {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    clippy::needless_range_loop,
+    clippy::let_and_return
+)]
 mod tests {
     use super::*;
     use alloc::{
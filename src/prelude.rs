@@ -1,14 +1,51 @@
-pub use crate::common_traits::iterator::iter::Iter;
+#[cfg(feature = "bytes")]
+pub use crate::buf::SplitVecBuf;
+pub use crate::cached_accessor::CachedAccessor;
+#[cfg(feature = "async")]
+pub use crate::chunk_stream::ChunkStream;
+pub use crate::common_traits::iterator::{
+    into_iter::IntoIter, iter::Iter, iter_mut::IterMut, iter_mut_rev::IterMutRev,
+    iter_rev::IterRev, slice_mut_iter::SliceMutIter,
+};
+pub use crate::concurrent_metrics::ConcurrentSplitVecMetrics;
+pub use crate::concurrent_pinned_vec::ConcurrentSplitVec;
+pub use crate::concurrent_prefix_suffix::{ConcurrentSplitVecPrefix, ConcurrentSplitVecSuffix};
+pub use crate::drain::Drain;
+pub use crate::flattened_slices::FlattenedSlices;
 pub use crate::fragment::fragment_struct::Fragment;
 pub use crate::fragment::into_fragments::IntoFragments;
 pub use crate::growth::{
+    conformance::{growth_conformance_check, growth_with_constant_time_access_conformance_check},
     doubling::Doubling,
+    doubling_until::DoublingUntil,
+    exponential::Exponential,
+    functional::FunctionalGrowth,
     growth_trait::{Growth, GrowthWithConstantTimeAccess},
     linear::Linear,
+    recommend::{recommend_growth, AccessPattern, GrowthChoice},
     recursive::Recursive,
 };
-pub use crate::slice::SplitVecSlice;
+#[cfg(feature = "growth_observer")]
+pub use crate::growth_observer::GrowthEvent;
+pub use crate::into_con_iter::{IntoConIter, IntoConIterChunk};
+#[cfg(feature = "std")]
+pub use crate::io::SplitVecReader;
+pub use crate::memory_policy::MemoryPolicy;
+pub use crate::memory_stats::{FragmentStats, MemoryStats};
+pub use crate::new_split_vec::SplitVecUninit;
+#[cfg(feature = "rayon")]
+pub use crate::parallel::{IntoParIter, ParIter, ParIterMut};
+pub use crate::relocation::{ObservedSplitVec, RelocationObserver};
+pub use crate::slice::{SplitVecSlice, SplitVecView};
+pub use crate::splice::Splice;
+pub use crate::split_deque::SplitDeque;
+pub use crate::split_heap::SplitHeap;
+pub use crate::split_slab::SplitSlab;
+pub use crate::split_string::SplitString;
 pub use crate::split_vec::SplitVec;
+pub use crate::stable_index::StableIndex;
+pub use crate::windows::{Window, Windows};
+pub use crate::zeroable::Zeroable;
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
 };
@@ -0,0 +1,200 @@
+use crate::{Growth, SplitVec};
+use core::iter::Sum;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Sums the elements of the vector.
+    ///
+    /// Each fragment is reduced as a contiguous slice, which allows the loop to be auto-vectorized,
+    /// and then the partial sums of the fragments are summed; rather than chaining the elements
+    /// through the iterator of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(1..=10);
+    ///
+    /// assert_eq!(vec.sum::<i32>(), 55);
+    /// ```
+    pub fn sum<'a, S>(&'a self) -> S
+    where
+        S: Sum<&'a T> + Sum<S>,
+    {
+        self.fragments.iter().map(|x| x.iter().sum::<S>()).sum()
+    }
+
+    /// Returns the minimum element of the vector; returns None if the vector is empty.
+    ///
+    /// If several elements are equally minimum, the first element is returned.
+    /// Each fragment is reduced as a contiguous slice.
+    ///
+    /// Note that the method is not named `min` since it would be shadowed by `Ord::min` of the vector itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([4, 2, 7, 1, 9, 1]);
+    ///
+    /// assert_eq!(vec.min_element(), Some(&1));
+    /// assert_eq!(SplitVec::<i32>::new().min_element(), None);
+    /// ```
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.fragments.iter().filter_map(|x| x.iter().min()).min()
+    }
+
+    /// Returns the maximum element of the vector; returns None if the vector is empty.
+    ///
+    /// If several elements are equally maximum, the last element is returned.
+    /// Each fragment is reduced as a contiguous slice.
+    ///
+    /// Note that the method is not named `max` since it would be shadowed by `Ord::max` of the vector itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([4, 2, 7, 1, 9, 1]);
+    ///
+    /// assert_eq!(vec.max_element(), Some(&9));
+    /// assert_eq!(SplitVec::<i32>::new().max_element(), None);
+    /// ```
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.fragments.iter().filter_map(|x| x.iter().max()).max()
+    }
+
+    /// Returns the element that gives the minimum value from the function `f`; returns None if the vector is empty.
+    ///
+    /// If several elements are equally minimum, the first element is returned.
+    /// Each fragment is reduced as a contiguous slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([-4, 2, -7, 1, 9, -1]);
+    ///
+    /// assert_eq!(vec.min_by_key(|x: &i32| x.abs()), Some(&1));
+    /// ```
+    pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.fragments
+            .iter()
+            .filter_map(|x| x.iter().map(|y| (f(y), y)).min_by(|a, b| a.0.cmp(&b.0)))
+            .min_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, x)| x)
+    }
+
+    /// Returns the element that gives the maximum value from the function `f`; returns None if the vector is empty.
+    ///
+    /// If several elements are equally maximum, the last element is returned.
+    /// Each fragment is reduced as a contiguous slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([-4, 2, -7, 1, 9, -1]);
+    ///
+    /// assert_eq!(vec.max_by_key(|x: &i32| x.abs()), Some(&9));
+    /// ```
+    pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.fragments
+            .iter()
+            .filter_map(|x| x.iter().map(|y| (f(y), y)).max_by(|a, b| a.0.cmp(&b.0)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, x)| x)
+    }
+
+    /// Returns the number of elements satisfying the given predicate `pred`.
+    ///
+    /// Each fragment is scanned as a contiguous slice without early exit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// assert_eq!(vec.count_where(|x| x % 3 == 0), 4);
+    /// ```
+    pub fn count_where<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.fragments
+            .iter()
+            .map(|x| x.iter().filter(|y| pred(y)).count())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    #[test]
+    fn reductions() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.sum::<usize>(), 0);
+            assert_eq!(vec.min_element(), None);
+            assert_eq!(vec.max_element(), None);
+            assert_eq!(vec.min_by_key(|x| *x), None);
+            assert_eq!(vec.max_by_key(|x| *x), None);
+            assert_eq!(vec.count_where(|_| true), 0);
+
+            for i in 0..1033 {
+                vec.push(i * 7 % 101);
+            }
+
+            assert_eq!(vec.sum::<usize>(), vec.iter().sum::<usize>());
+            assert_eq!(vec.min_element(), vec.iter().min());
+            assert_eq!(vec.max_element(), vec.iter().max());
+            assert_eq!(
+                vec.count_where(|x| x % 2 == 0),
+                vec.iter().filter(|x| *x % 2 == 0).count()
+            );
+
+            // ties are resolved by the position of the element as in the iterator reductions
+            let key = |x: &usize| x % 10;
+            let ptr = |x: Option<&usize>| x.map(|x| x as *const usize);
+            assert_eq!(
+                ptr(vec.min_by_key(key)),
+                ptr(vec.iter().min_by_key(|x| key(x)))
+            );
+            assert_eq!(
+                ptr(vec.max_by_key(key)),
+                ptr(vec.iter().max_by_key(|x| key(x)))
+            );
+            assert_eq!(ptr(vec.min_element()), ptr(vec.iter().min()));
+            assert_eq!(ptr(vec.max_element()), ptr(vec.iter().max()));
+        }
+        test_all_growth_types!(test);
+    }
+}
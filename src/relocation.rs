@@ -0,0 +1,346 @@
+use crate::range_helpers::{range_end, range_start};
+use crate::{Drain, Growth, SplitVec};
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem::ManuallyDrop;
+use core::ops::{Range, RangeBounds};
+use orx_pinned_vec::PinnedVec;
+
+/// An observer which is notified when elements of a split vector are moved to different memory locations.
+///
+/// The split vector keeps its elements pinned while it grows; however, operations such as `insert`, `remove`,
+/// `swap`, `sort`, `truncate`, `drain`, `remove_range` or `retain` necessarily move or drop elements.
+/// When these operations are called through [`SplitVec::observe_relocations`], the observer is notified with
+/// the exact positions of the moved elements, so that dependent structures caching raw pointers, such as arenas
+/// or intrusive lists, can invalidate only the affected entries.
+///
+/// Only the operations of [`ObservedSplitVec`] are reported. Other element moving operations of the split vector,
+/// such as `splice`, `insert_many`, `drop_front`, `rotate_left`, `rotate_right` or `compact_capacity`, are not
+/// reported; dependent structures must invalidate all entries after calling them.
+///
+/// The trait is implemented for all `FnMut(Range<usize>)` closures.
+pub trait RelocationObserver {
+    /// Called after the elements which now are at the positions within `range` are moved;
+    /// i.e., pointers to these positions now point to different elements, or to no element.
+    fn on_elements_relocated(&mut self, range: Range<usize>);
+}
+
+impl<F: FnMut(Range<usize>)> RelocationObserver for F {
+    fn on_elements_relocated(&mut self, range: Range<usize>) {
+        self(range)
+    }
+}
+
+/// A mutable view of a split vector, whose element moving operations notify the relocation observer.
+///
+/// This struct is created by `SplitVec::observe_relocations()` method.
+pub struct ObservedSplitVec<'a, T, G: Growth, O: RelocationObserver> {
+    vec: &'a mut SplitVec<T, G>,
+    observer: O,
+}
+
+impl<T, G: Growth, O: RelocationObserver> ObservedSplitVec<'_, T, G, O> {
+    fn notify(&mut self, range: Range<usize>) {
+        if !range.is_empty() {
+            self.observer.on_elements_relocated(range);
+        }
+    }
+
+    /// Inserts the `value` at the `index`, shifting all elements after it to the right;
+    /// and notifies the observer with the range of the shifted elements, `index + 1..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.vec.insert(index, value);
+        self.notify((index + 1)..self.vec.len());
+    }
+
+    /// Removes and returns the element at the `index`, shifting all elements after it to the left;
+    /// and notifies the observer with the range of the shifted elements together with the vacated last position,
+    /// `index..(len + 1)` where `len` is the length after the removal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.vec.remove(index);
+        self.notify(index..(self.vec.len() + 1));
+        value
+    }
+
+    /// Swaps the elements at positions `a` and `b`; and notifies the observer once for each of the positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.vec.swap(a, b);
+        if a != b {
+            self.notify(a..(a + 1));
+            self.notify(b..(b + 1));
+        }
+    }
+
+    /// Sorts the vector; and notifies the observer with the entire range of the vector.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.vec.sort();
+        self.notify(0..self.vec.len());
+    }
+
+    /// Sorts the vector with the `compare` function; and notifies the observer with the entire range of the vector.
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.vec.sort_by(compare);
+        self.notify(0..self.vec.len());
+    }
+
+    /// Sorts the vector with the key extraction function `f`; and notifies the observer with the entire range of the vector.
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.vec.sort_by_key(f);
+        self.notify(0..self.vec.len());
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest;
+    /// and notifies the observer with the range of the dropped positions, `len..old_len`.
+    ///
+    /// Has no effect if `len` is greater than or equal to the length of the vector.
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.vec.len();
+        self.vec.truncate(len);
+        self.notify(len..old_len);
+    }
+
+    /// Removes the elements in the `range`, shifting all elements after it to the left;
+    /// and notifies the observer with the range of the shifted elements together with the vacated positions,
+    /// `range.start..old_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end is greater than the length of the vector.
+    pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, old_len) = (range_start(&range), self.vec.len());
+        let end = range_end(&range, old_len);
+        self.vec.remove_range(range);
+        if start < end {
+            self.notify(start..old_len);
+        }
+    }
+
+    /// Creates a draining iterator that removes the elements in the `range` and yields them;
+    /// once the iterator is dropped, the elements after the range are shifted to the left and the observer is
+    /// notified with the range of the shifted elements together with the vacated positions, `range.start..old_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end is greater than the length of the vector.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> ObservedDrain<'_, T, G, O> {
+        let (start, old_len) = (range_start(&range), self.vec.len());
+        let end = range_end(&range, old_len);
+        let relocated = match start < end {
+            true => start..old_len,
+            false => start..start,
+        };
+        ObservedDrain {
+            drain: ManuallyDrop::new(self.vec.drain(range)),
+            observer: &mut self.observer,
+            relocated,
+        }
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the order of the retained elements;
+    /// and notifies the observer with the positions starting at the first removed element, `first_removed..old_len`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let old_len = self.vec.len();
+        let mut idx = 0;
+        let mut first_removed = old_len;
+        self.vec.retain(|x| {
+            let retain = f(x);
+            if !retain && first_removed == old_len {
+                first_removed = idx;
+            }
+            idx += 1;
+            retain
+        });
+        self.notify(first_removed..old_len);
+    }
+
+    /// Returns the observer, consuming the view.
+    pub fn into_observer(self) -> O {
+        self.observer
+    }
+}
+
+/// A draining iterator of an observed split vector, which notifies the relocation observer once it is dropped.
+///
+/// This struct is created by `ObservedSplitVec::drain()` method.
+pub struct ObservedDrain<'a, T, G: Growth, O: RelocationObserver> {
+    drain: ManuallyDrop<Drain<'a, T, G>>,
+    observer: &'a mut O,
+    relocated: Range<usize>,
+}
+
+impl<T, G: Growth, O: RelocationObserver> Iterator for ObservedDrain<'_, T, G, O> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<T, G: Growth, O: RelocationObserver> DoubleEndedIterator for ObservedDrain<'_, T, G, O> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<T, G: Growth, O: RelocationObserver> ExactSizeIterator for ObservedDrain<'_, T, G, O> {
+    fn len(&self) -> usize {
+        self.drain.len()
+    }
+}
+
+impl<T, G: Growth, O: RelocationObserver> FusedIterator for ObservedDrain<'_, T, G, O> {}
+
+impl<T, G: Growth, O: RelocationObserver> Drop for ObservedDrain<'_, T, G, O> {
+    fn drop(&mut self) {
+        // SAFETY: the drain is dropped only once here, so that the gap is closed before the observer is notified
+        unsafe { ManuallyDrop::drop(&mut self.drain) };
+        if !self.relocated.is_empty() {
+            self.observer.on_elements_relocated(self.relocated.clone());
+        }
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Creates a mutable view of the vector, whose operations that move elements notify the `observer`
+    /// with the exact range of positions that are affected.
+    ///
+    /// See [`RelocationObserver`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use std::ops::Range;
+    ///
+    /// let mut vec: SplitVec<_> = (0..10).collect();
+    /// let mut relocated: Vec<Range<usize>> = vec![];
+    ///
+    /// let mut observed = vec.observe_relocations(|range| relocated.push(range));
+    /// observed.insert(7, 42);
+    /// observed.remove(0);
+    /// observed.swap(1, 3);
+    /// observed.remove(9); // last element, its position now points to no element
+    ///
+    /// assert_eq!(relocated, [8..11, 0..11, 1..2, 3..4, 9..10]);
+    /// assert_eq!(vec, [1, 4, 3, 2, 5, 6, 42, 7, 8]);
+    /// ```
+    pub fn observe_relocations<O: RelocationObserver>(
+        &mut self,
+        observer: O,
+    ) -> ObservedSplitVec<'_, T, G, O> {
+        ObservedSplitVec {
+            vec: self,
+            observer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+
+    #[test]
+    fn observe_relocations() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 77;
+            vec.extend(0..n);
+
+            for (index, insert) in [(0, true), (5, false), (40, true), (76, false), (75, true)] {
+                let before: Vec<_> = vec.iter().copied().collect();
+
+                let mut relocated: Vec<Range<usize>> = Vec::new();
+                let mut observed = vec.observe_relocations(|r| relocated.push(r));
+                match insert {
+                    true => observed.insert(index, 1000),
+                    false => _ = observed.remove(index),
+                }
+
+                // positions holding a different element than before or no element, except for the inserted one
+                let changed: Vec<_> = (0..before.len().max(vec.len()))
+                    .filter(|i| !(insert && *i == index))
+                    .filter(|i| before.get(*i) != vec.get(*i))
+                    .collect();
+                let notified: Vec<_> = relocated.into_iter().flatten().collect();
+                assert_eq!(notified, changed);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn observe_relocations_truncate_drain_retain() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 77;
+            vec.extend(0..n);
+
+            for op in 0..5 {
+                let before: Vec<_> = vec.iter().copied().collect();
+
+                let mut relocated: Vec<Range<usize>> = Vec::new();
+                let mut observed = vec.observe_relocations(|r| relocated.push(r));
+                match op {
+                    0 => observed.truncate(70),
+                    1 => observed.remove_range(3..9),
+                    2 => assert_eq!(observed.drain(10..40).count(), 30),
+                    3 => observed.retain(|x| x % 4 != 1),
+                    _ => observed.drain(5..5).for_each(drop),
+                }
+
+                let changed: Vec<_> = (0..before.len())
+                    .filter(|i| before.get(*i) != vec.get(*i))
+                    .collect();
+                let notified: Vec<_> = relocated.into_iter().flatten().collect();
+                assert_eq!(notified, changed);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn observe_relocations_sort() {
+        let mut vec: SplitVec<_> = (0..20).rev().collect();
+        let mut count = 0;
+        let mut observed = vec.observe_relocations(|r: Range<usize>| count += r.len());
+        observed.sort();
+        observed.sort_by(|a, b| b.cmp(a));
+        observed.sort_by_key(|x| *x);
+        observed.swap(3, 3);
+
+        assert_eq!(count, 60);
+        assert_eq!(vec, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use core::hash::Hash;
+use orx_pinned_vec::PinnedVec;
+
+#[cfg(feature = "hashbrown")]
+use hashbrown::HashSet;
+#[cfg(not(feature = "hashbrown"))]
+use std::collections::HashSet;
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+    T: Hash + Eq,
+{
+    /// Removes all but the first occurrence of each element in the vector, preserving the order of the remaining elements.
+    ///
+    /// Unlike `dedup` of the standard vector, duplicates do not need to be consecutive; i.e., the vector does not need to be sorted.
+    /// Seen values are tracked by a hash set, and the vector is compacted in place in a single fragment-aware pass by
+    /// swapping the elements to keep towards the front, and finally, truncating the duplicates at the end.
+    ///
+    /// This method is available with the **std** or **hashbrown** features.
+    ///
+    /// Note that the remaining elements might be moved; therefore, this method does not keep the elements pinned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[3, 1, 3, 2, 1, 4, 2, 3, 5]);
+    ///
+    /// vec.remove_duplicates();
+    /// assert_eq!(vec, &[3, 1, 2, 4, 5]);
+    /// ```
+    pub fn remove_duplicates(&mut self) {
+        self.invalidate_stable_indices();
+        let keep: Vec<bool> = {
+            let mut seen = HashSet::with_capacity(self.len);
+            self.iter().map(|x| seen.insert(x)).collect()
+        };
+
+        let (mut wf, mut wi) = (0, 0);
+        let mut new_len = 0;
+        let mut idx = 0;
+        for rf in 0..self.fragments.len() {
+            for ri in 0..self.fragments[rf].len() {
+                if keep[idx] {
+                    if wf == rf {
+                        self.fragments[rf].swap(wi, ri);
+                    } else {
+                        let (left, right) = self.fragments.split_at_mut(rf);
+                        core::mem::swap(&mut left[wf][wi], &mut right[0][ri]);
+                    }
+
+                    new_len += 1;
+                    wi += 1;
+                    if wi == self.fragments[wf].len() {
+                        (wf, wi) = (wf + 1, 0);
+                    }
+                }
+                idx += 1;
+            }
+        }
+
+        self.truncate(new_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn remove_duplicates() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.remove_duplicates();
+            assert!(vec.is_empty());
+
+            for i in 0..500 {
+                vec.push((i * 7 % 31).to_string());
+            }
+            vec.remove_duplicates();
+
+            let mut expected = Vec::new();
+            for i in 0..500 {
+                let x = (i * 7 % 31).to_string();
+                if !expected.contains(&x) {
+                    expected.push(x);
+                }
+            }
+            assert_eq!(vec, expected);
+
+            vec.remove_duplicates();
+            assert_eq!(vec, expected);
+
+            vec.push("new".to_string());
+            assert_eq!(vec.len(), expected.len() + 1);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn remove_duplicates_no_duplicates() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            vec.remove_duplicates();
+            assert_eq!(vec, (0..100).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Growth, SplitVec};
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Reserves capacity for at least `additional` more elements to be pushed to the vector.
+    ///
+    /// Unlike a standard vector, already allocated fragments are never reallocated; instead, the subsequent
+    /// fragments that the growth strategy would create are allocated ahead, until the capacity of the fragments
+    /// in use together with the reserved fragments is at least `len + additional`.
+    /// Further, the collection holding the fragments is grown so that the reserved fragments can be added without
+    /// reallocating it. Therefore, pushing `additional` elements afterwards does not allocate.
+    ///
+    /// Capacities of the fragments are determined by the growth strategy; hence, the reserved capacity might exceed
+    /// the requested amount. The collection holding the fragments might be grown more than required to amortize its growth;
+    /// see [`SplitVec::reserve_exact`] to avoid this.
+    ///
+    /// This is different than [`SplitVec::concurrent_reserve`] which only grows the collection holding the fragments
+    /// without allocating the fragments.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.push(0);
+    ///
+    /// vec.reserve(20);
+    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(vec.reserved_capacity(), 8 + 16);
+    ///
+    /// let fragments = vec.fragments().as_ptr();
+    /// vec.extend(1..21);
+    /// assert_eq!(vec.fragments().as_ptr(), fragments);
+    /// assert_eq!(vec.reserved_capacity(), 0);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.reserve_fragments(self.len.saturating_add(additional));
+        self.fragments.reserve(self.reserved.len());
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the vector,
+    /// allocating ahead the minimum number of fragments.
+    ///
+    /// This method behaves as [`SplitVec::reserve`] except that the collection holding the fragments is
+    /// grown exactly for the reserved fragments.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(4);
+    /// vec.extend(0..10);
+    ///
+    /// vec.reserve_exact(30);
+    /// assert_eq!(vec.capacity(), 16);
+    /// assert_eq!(vec.reserved_capacity(), 32);
+    ///
+    /// vec.reserve_exact(30);
+    /// assert_eq!(vec.reserved_capacity(), 32);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_fragments(self.len.saturating_add(additional));
+        self.fragments.reserve_exact(self.reserved.len());
+    }
+
+    /// Clears the vector, removing all values, while keeping all allocated fragments for reuse.
+    ///
+    /// Unlike `clear` which keeps only the first fragment, all fragments except for the first one are moved
+    /// to the reserved fragments (see [`SplitVec::reserved_capacity`]), in their original order.
+    /// Subsequent pushes then reuse these fragments instead of allocating new ones, as long as they follow the
+    /// growth strategy. This is useful for workloads which repeatedly fill and clear the vector.
+    ///
+    /// The kept fragments are released together with the vector, or once they no longer follow the growth strategy,
+    /// such as when the vector grows with fragments of different capacities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend(0..20);
+    /// assert_eq!(vec.capacity(), 4 + 8 + 16);
+    ///
+    /// vec.clear_keeping_capacity();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(vec.reserved_capacity(), 8 + 16);
+    ///
+    /// let fragments: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+    /// vec.extend(0..20);
+    /// assert_eq!(vec.reserved_capacity(), 0);
+    /// assert_eq!(vec.fragments()[0].as_ptr(), fragments[0]);
+    /// ```
+    pub fn clear_keeping_capacity(&mut self) {
+        self.invalidate_stable_indices();
+        while self.fragments.len() > 1 {
+            if let Some(mut fragment) = self.pop_fragment() {
+                fragment.clear();
+                self.reserved.push_front(fragment);
+            }
+        }
+        if let Some(first) = self.fragments.first_mut() {
+            first.clear();
+        }
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn reserve() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut len = 0;
+            for (additional, exact) in [
+                (0, false),
+                (3, true),
+                (10, false),
+                (100, true),
+                (1000, false),
+            ] {
+                match exact {
+                    true => vec.reserve_exact(additional),
+                    false => vec.reserve(additional),
+                }
+                assert!(vec.capacity() + vec.reserved_capacity() >= len + additional);
+
+                let fragments = vec.fragments().as_ptr();
+                let reserved: Vec<_> = vec.reserved.iter().map(|x| x.as_ptr()).collect();
+                let num_fragments = vec.fragments().len();
+
+                vec.extend(len..(len + additional));
+                len += additional;
+
+                assert_eq!(vec.fragments().as_ptr(), fragments);
+                let used: Vec<_> = vec.fragments()[num_fragments..]
+                    .iter()
+                    .map(|x| x.as_ptr())
+                    .collect();
+                assert_eq!(used, reserved[..used.len()]);
+            }
+
+            assert_eq!(vec, (0..len).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn clear_keeping_capacity() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for n in [0, 1, 4, 33, 157, 20, 157] {
+                vec.extend(0..n);
+                let capacity = vec.capacity() + vec.reserved_capacity();
+                let fragments: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+
+                vec.clear_keeping_capacity();
+                assert!(vec.is_empty());
+                assert_eq!(vec.fragments().len(), 1);
+                assert_eq!(vec.capacity() + vec.reserved_capacity(), capacity);
+
+                vec.extend(0..n);
+                assert_eq!(vec, (0..n).collect::<Vec<_>>());
+                let refilled: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+                let num_common = refilled.len().min(fragments.len());
+                assert_eq!(refilled[..num_common], fragments[..num_common]);
+
+                vec.clear_keeping_capacity();
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Retains only the elements specified by the predicate, preserving the order of the retained elements.
+    ///
+    /// In other words, removes all elements `e` for which `f(&e)` returns false.
+    /// The predicate is called exactly once for each element in the original order.
+    ///
+    /// The vector is compacted in place in a single fragment-aware pass by swapping the retained elements
+    /// towards the front, and finally, truncating the removed elements at the end; no new vector is allocated.
+    ///
+    /// Note that the retained elements might be moved; therefore, this method does not keep the elements pinned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.retain(|x| x % 3 == 0);
+    /// assert_eq!(vec, [0, 3, 6, 9]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate which is allowed to mutate the elements,
+    /// preserving the order of the retained elements.
+    ///
+    /// In other words, removes all elements `e` for which `f(&mut e)` returns false.
+    /// The predicate is called exactly once for each element in the original order.
+    ///
+    /// The vector is compacted in place in a single fragment-aware pass by swapping the retained elements
+    /// towards the front, and finally, truncating the removed elements at the end; no new vector is allocated.
+    ///
+    /// Note that the retained elements might be moved; therefore, this method does not keep the elements pinned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x < 50
+    /// });
+    /// assert_eq!(vec, [0, 10, 20, 30, 40]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.invalidate_stable_indices();
+        let (mut wf, mut wi) = (0, 0);
+        let mut new_len = 0;
+        for rf in 0..self.fragments.len() {
+            for ri in 0..self.fragments[rf].len() {
+                if f(&mut self.fragments[rf][ri]) {
+                    // skips the fully written and empty fragments; the write position never passes the read position
+                    while wi == self.fragments[wf].len() {
+                        (wf, wi) = (wf + 1, 0);
+                    }
+                    if wf == rf {
+                        self.fragments[rf].swap(wi, ri);
+                    } else {
+                        let (left, right) = self.fragments.split_at_mut(rf);
+                        core::mem::swap(&mut left[wf][wi], &mut right[0][ri]);
+                    }
+
+                    new_len += 1;
+                    wi += 1;
+                }
+            }
+        }
+
+        self.truncate(new_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn retain() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.retain(|_| true);
+            assert!(vec.is_empty());
+
+            let n = 333;
+            for modulo in [1, 2, 3, 7, 100, 1000] {
+                vec.clear();
+                vec.extend((0..n).map(|x| x.to_string()));
+
+                let mut visited = Vec::new();
+                vec.retain(|x| {
+                    visited.push(x.clone());
+                    x.parse::<usize>().expect("is-ok") % modulo == 0
+                });
+
+                let expected: Vec<_> = (0..n)
+                    .filter(|x| x % modulo == 0)
+                    .map(|x| x.to_string())
+                    .collect();
+                assert_eq!(vec, expected);
+                assert_eq!(visited, (0..n).map(|x| x.to_string()).collect::<Vec<_>>());
+
+                vec.push("new".to_string());
+                assert_eq!(vec.len(), expected.len() + 1);
+            }
+
+            vec.retain(|_| false);
+            assert!(vec.is_empty());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..200);
+            vec.retain_mut(|x| {
+                *x += 1;
+                *x % 2 == 0
+            });
+            assert_eq!(vec, (1..201).filter(|x| x % 2 == 0).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_with_empty_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.extend(0..10);
+        vec.append(Vec::<usize>::with_capacity(5));
+        vec.append(alloc::vec![10, 11]);
+        vec.append(Vec::<usize>::new());
+        vec.append(alloc::vec![12]);
+
+        vec.retain(|_| true);
+        assert_eq!(vec, (0..13).collect::<Vec<_>>());
+
+        vec.retain(|x| x % 2 == 1);
+        assert_eq!(vec, [1, 3, 5, 7, 9, 11]);
+        vec.push(13);
+        assert_eq!(vec, [1, 3, 5, 7, 9, 11, 13]);
+    }
+}
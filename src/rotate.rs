@@ -0,0 +1,185 @@
+use crate::{Growth, SplitVec};
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Reverses the order of elements in the vector, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// vec.reverse();
+    /// assert_eq!(vec, [5, 4, 3, 2, 1, 0]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.invalidate_stable_indices();
+        self.reverse_range(0, self.len);
+    }
+
+    /// Rotates the vector in place such that the first `mid` elements move to the end
+    /// while the last `len - mid` elements move to the front.
+    ///
+    /// After calling `rotate_left`, the element previously at index `mid` becomes the first element.
+    ///
+    /// The rotation is performed across the fragments by three reversals; hence, it takes ***O(n)*** swaps
+    /// and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// vec.rotate_left(2);
+    /// assert_eq!(vec, [2, 3, 4, 5, 0, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "rotation amount must not exceed length");
+        self.invalidate_stable_indices();
+        self.reverse_range(0, mid);
+        self.reverse_range(mid, self.len);
+        self.reverse_range(0, self.len);
+    }
+
+    /// Rotates the vector in place such that the first `len - k` elements move to the end
+    /// while the last `k` elements move to the front.
+    ///
+    /// After calling `rotate_right`, the element previously at index `len - k` becomes the first element.
+    ///
+    /// The rotation is performed across the fragments by three reversals; hence, it takes ***O(n)*** swaps
+    /// and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// vec.rotate_right(2);
+    /// assert_eq!(vec, [4, 5, 0, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "rotation amount must not exceed length");
+        self.rotate_left(self.len - k);
+    }
+
+    /// Reverses the elements within positions `begin..end`, swapping the elements pairwise from both ends
+    /// while the cursors move across the fragments.
+    fn reverse_range(&mut self, begin: usize, end: usize) {
+        if end.saturating_sub(begin) < 2 {
+            return;
+        }
+
+        let (Some(mut front), Some(mut back)) = (
+            self.get_fragment_and_inner_indices(begin),
+            self.get_fragment_and_inner_indices(end - 1),
+        ) else {
+            return;
+        };
+
+        for _ in 0..((end - begin) / 2) {
+            let a = unsafe { self.fragments[front.0].as_mut_ptr().add(front.1) };
+            let b = unsafe { self.fragments[back.0].as_mut_ptr().add(back.1) };
+            // SAFETY: both positions are in bounds and distinct since the front cursor precedes the back cursor
+            unsafe { core::ptr::swap_nonoverlapping(a, b, 1) };
+
+            front.1 += 1;
+            while front.1 == self.fragments[front.0].len() && front.0 + 1 < self.fragments.len() {
+                front = (front.0 + 1, 0);
+            }
+
+            while back.1 == 0 && back.0 > 0 {
+                back = (back.0 - 1, self.fragments[back.0 - 1].len());
+            }
+            back.1 = back.1.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn reverse() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for n in [0, 1, 2, 3, 4, 5, 17, 64, 157] {
+                vec.clear();
+                vec.extend(0..n);
+                vec.reverse();
+                assert_eq!(vec, (0..n).rev().collect::<Vec<_>>());
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn rotate() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for n in [0, 1, 2, 5, 17, 64, 157] {
+                for k in [0, 1, 2, 3, 4, 16, 50, 100, 157] {
+                    if k > n {
+                        continue;
+                    }
+
+                    let mut expected: Vec<_> = (0..n).collect();
+
+                    vec.clear();
+                    vec.extend(0..n);
+                    vec.rotate_left(k);
+                    expected.rotate_left(k);
+                    assert_eq!(vec, expected);
+
+                    vec.rotate_right(k);
+                    expected.rotate_right(k);
+                    assert_eq!(vec, expected);
+
+                    vec.rotate_right(k);
+                    expected.rotate_right(k);
+                    assert_eq!(vec, expected);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn rotate_with_empty_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.append((0..3).collect::<Vec<_>>());
+        vec.append(Vec::<usize>::new());
+        vec.append((3..7).collect::<Vec<_>>());
+
+        vec.rotate_left(3);
+        assert_eq!(vec, [3, 4, 5, 6, 0, 1, 2]);
+        vec.reverse();
+        assert_eq!(vec, [2, 1, 0, 6, 5, 4, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.rotate_left(11);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{Fragment, Growth, SplitVec};
+use core::iter::{Enumerate, FusedIterator};
+use core::slice::Iter;
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Returns an iterator yielding indices of the elements satisfying the given predicate `pred`,
+    /// in ascending order.
+    ///
+    /// Elements are scanned fragment by fragment and the flat index of each match is computed
+    /// from the offset of its fragment; therefore, it is more efficient than the
+    /// `vec.iter().enumerate().filter(..).map(..)` alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 4, 2, 8, 3, 6, 5]);
+    ///
+    /// let even: Vec<_> = vec.indices_of(|x| x % 2 == 0).collect();
+    /// assert_eq!(even, [1, 2, 3, 5]);
+    ///
+    /// let large: Vec<_> = vec.indices_of(|x| *x > 10).collect();
+    /// assert!(large.is_empty());
+    /// ```
+    pub fn indices_of<'a, P>(&'a self, pred: P) -> impl Iterator<Item = usize> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        IndicesOf::new(&self.fragments, pred)
+    }
+
+    /// Returns the index of the first element equal to the given `value`; returns None if there is no such element.
+    ///
+    /// Unlike `index_of` which searches for the element by its memory location, this method compares the values.
+    /// Fragments are scanned slice by slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 4, 2, 8, 2]);
+    ///
+    /// assert_eq!(vec.position(&2), Some(2));
+    /// assert_eq!(vec.position(&8), Some(3));
+    /// assert_eq!(vec.position(&7), None);
+    /// ```
+    pub fn position(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let mut offset = 0;
+        for fragment in &self.fragments {
+            if let Some(i) = fragment.iter().position(|x| x == value) {
+                return Some(offset + i);
+            }
+            offset += fragment.len();
+        }
+        None
+    }
+
+    /// Returns whether or not the vector contains an element equal to the given `value`.
+    ///
+    /// Unlike `contains_reference` which checks the memory location of the element, this method compares the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 4, 2, 8]);
+    ///
+    /// assert!(vec.contains(&8));
+    /// assert!(!vec.contains(&7));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.fragments.iter().any(|x| x.contains(value))
+    }
+}
+
+impl<T, G> SplitVec<T, G>
+where
+    T: Copy + PartialEq,
+    G: Growth,
+{
+    /// Returns whether or not the vector contains an element equal to the given `value`.
+    ///
+    /// This is the by-value counterpart of [`SplitVec::contains`] intended for primitives: each fragment is searched
+    /// as a slice by the standard library's slice search, which is accelerated for bytes and integers
+    /// (`memchr` for `u8`) rather than comparing the elements one by one through the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(b"hello world");
+    ///
+    /// assert!(vec.contains_value(b'w'));
+    /// assert!(!vec.contains_value(b'x'));
+    /// ```
+    pub fn contains_value(&self, value: T) -> bool {
+        self.fragments.iter().any(|x| x.as_slice().contains(&value))
+    }
+
+    /// Returns the number of elements equal to the given `value`.
+    ///
+    /// Each fragment is scanned as a slice without early exit, which allows the comparisons to be auto-vectorized
+    /// for primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(b"hello world");
+    ///
+    /// assert_eq!(vec.count_value(b'l'), 3);
+    /// assert_eq!(vec.count_value(b'x'), 0);
+    /// ```
+    pub fn count_value(&self, value: T) -> usize {
+        self.fragments
+            .iter()
+            .map(|x| x.as_slice().iter().filter(|y| **y == value).count())
+            .sum()
+    }
+}
+
+impl<G: Growth> SplitVec<u8, G> {
+    /// Returns the index of the first byte equal to the given `byte`; returns None if there is no such byte.
+    ///
+    /// Each fragment is scanned once as a byte slice, which is faster than scanning the vector element by element
+    /// through the generic iterator. See [`SplitVec::contains_value`] to only check whether or not the byte exists.
+    ///
+    /// This method is limited to bytes; the positions of other primitives can be found by [`SplitVec::position`],
+    /// which scans the fragments slice by slice as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(b"hello world");
+    ///
+    /// assert_eq!(vec.find_byte(b'o'), Some(4));
+    /// assert_eq!(vec.find_byte(b'd'), Some(10));
+    /// assert_eq!(vec.find_byte(b'x'), None);
+    /// ```
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        let mut offset = 0;
+        for fragment in &self.fragments {
+            if let Some(i) = fragment.as_slice().iter().position(|x| *x == byte) {
+                return Some(offset + i);
+            }
+            offset += fragment.len();
+        }
+        None
+    }
+}
+
+struct IndicesOf<'a, T, P> {
+    fragments: Iter<'a, Fragment<T>>,
+    inner: Enumerate<Iter<'a, T>>,
+    offset: usize,
+    next_offset: usize,
+    pred: P,
+}
+
+impl<'a, T, P> IndicesOf<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn new(fragments: &'a [Fragment<T>], pred: P) -> Self {
+        Self {
+            fragments: fragments.iter(),
+            inner: [].iter().enumerate(),
+            offset: 0,
+            next_offset: 0,
+            pred,
+        }
+    }
+}
+
+impl<T, P> Iterator for IndicesOf<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pred = &mut self.pred;
+            if let Some((i, _)) = self.inner.find(|(_, x)| pred(x)) {
+                return Some(self.offset + i);
+            }
+
+            let fragment = self.fragments.next()?;
+            self.offset = self.next_offset;
+            self.next_offset += fragment.len();
+            self.inner = fragment.iter().enumerate();
+        }
+    }
+}
+
+impl<T, P> FusedIterator for IndicesOf<'_, T, P> where P: FnMut(&T) -> bool {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn indices_of() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.indices_of(|_| true).count(), 0);
+
+            for i in 0..1033 {
+                vec.push(i * 7 % 13);
+            }
+
+            let expected: Vec<_> = vec
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| **x < 4)
+                .map(|(i, _)| i)
+                .collect();
+            let indices: Vec<_> = vec.indices_of(|x| *x < 4).collect();
+            assert_eq!(indices, expected);
+
+            assert_eq!(
+                vec.indices_of(|_| true).collect::<Vec<_>>(),
+                (0..vec.len()).collect::<Vec<_>>()
+            );
+            assert_eq!(vec.indices_of(|_| false).count(), 0);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn position_and_contains() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.position(&0), None);
+            assert!(!vec.contains(&0));
+
+            for i in 0..1033 {
+                vec.push(i * 7 % 1013);
+            }
+
+            for value in [0, 1, 6, 7, 500, 1012, 1013, 5000] {
+                let expected = vec.iter().position(|x| *x == value);
+                assert_eq!(vec.position(&value), expected);
+                assert_eq!(vec.contains(&value), expected.is_some());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn value_search() {
+        fn test<G: Growth>(mut vec: SplitVec<u8, G>) {
+            assert_eq!(vec.find_byte(0), None);
+            assert!(!vec.contains_value(0));
+            assert_eq!(vec.count_value(0), 0);
+
+            for i in 0..1033 {
+                vec.push((i * 7 % 251) as u8);
+            }
+
+            for value in [0, 1, 6, 7, 200, 250, 251, 255] {
+                let expected = vec.iter().position(|x| *x == value);
+                assert_eq!(vec.find_byte(value), expected);
+                assert_eq!(vec.contains_value(value), expected.is_some());
+                let count = vec.iter().filter(|x| **x == value).count();
+                assert_eq!(vec.count_value(value), count);
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn value_search_non_byte() {
+        let mut vec: SplitVec<u64, Doubling> = SplitVec::with_doubling_growth();
+        vec.extend((0..500).map(|x| x % 17));
+
+        assert!(vec.contains_value(16));
+        assert!(!vec.contains_value(17));
+        assert_eq!(vec.count_value(3), 30);
+        assert_eq!(vec.count_value(17), 0);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     range_helpers::{range_end, range_start},
-    Growth, SplitVec,
+    FlattenedSlices, Growth, SplitVec,
 };
+use alloc::vec::Vec;
 use core::{cmp::Ordering, ops::RangeBounds};
 use orx_pinned_vec::PinnedVec;
 
@@ -57,7 +58,7 @@ impl<T, G: Growth> SplitVec<T, G> {
     /// assert_eq!(SplitVecSlice::OutOfBounds, vec.try_get_slice(5..12));
     /// assert_eq!(SplitVecSlice::OutOfBounds, vec.try_get_slice(10..11));
     /// ```
-    pub fn try_get_slice<R: RangeBounds<usize>>(&self, range: R) -> SplitVecSlice<T> {
+    pub fn try_get_slice<R: RangeBounds<usize>>(&self, range: R) -> SplitVecSlice<'_, T> {
         let a = range_start(&range);
         let b = range_end(&range, self.len());
 
@@ -77,6 +78,216 @@ impl<T, G: Growth> SplitVec<T, G> {
     }
 }
 
+/// A borrowed view on a range of a split vector which might be spread over multiple fragments.
+///
+/// Unlike [`SplitVecSlice`] which only provides the contiguous slice when the range belongs to a single fragment,
+/// the view provides slice-like access regardless of how the range is fragmented:
+/// * `len`, `get` and indexing by position within the view,
+/// * `iter` over the elements, and `slices` to access the contiguous pieces,
+/// * further sub-slicing with `slice`,
+/// * equality with slices, arrays and other views.
+///
+/// Created by [`SplitVec::view`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_linear_growth(2);
+/// vec.extend(0..10);
+///
+/// let view = vec.view(3..9).unwrap();
+/// assert_eq!(view.len(), 6);
+/// assert_eq!(view.slices().len(), 3);
+/// assert_eq!(view, [3, 4, 5, 6, 7, 8]);
+/// assert_eq!(view[1], 4);
+/// assert_eq!(view.get(6), None);
+///
+/// let sub = view.slice(1..3);
+/// assert_eq!(sub, &[4, 5][..]);
+/// assert_eq!(sub.iter().sum::<i32>(), 9);
+/// ```
+#[derive(Clone)]
+pub struct SplitVecView<'a, T> {
+    slices: Vec<&'a [T]>,
+    len: usize,
+}
+
+impl<'a, T> SplitVecView<'a, T> {
+    fn new(slices: Vec<&'a [T]>) -> Self {
+        let len = slices.iter().map(|x| x.len()).sum();
+        Self { slices, len }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the view is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the contiguous slices which form the view when chained.
+    pub fn slices(&self) -> &[&'a [T]] {
+        &self.slices
+    }
+
+    /// Returns a reference to the `index`-th element of the view; returns None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let mut index = index;
+        for slice in &self.slices {
+            match index < slice.len() {
+                true => return slice.get(index),
+                false => index -= slice.len(),
+            }
+        }
+        None
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> FlattenedSlices<'a, T> {
+        FlattenedSlices::from(self.slices.clone())
+    }
+
+    /// Returns the view on the given `range` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..20);
+    ///
+    /// let view = vec.view(2..18).unwrap();
+    /// let sub = view.slice(5..);
+    /// assert_eq!(sub, (7..18).collect::<Vec<_>>().as_slice());
+    ///
+    /// let sub = sub.slice(..4);
+    /// assert_eq!(sub, [7, 8, 9, 10]);
+    /// ```
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len);
+        assert!(a <= b, "slice range start must not exceed end");
+        assert!(b <= self.len, "slice range end is out of bounds");
+
+        let mut slices = Vec::new();
+        let mut begin = 0;
+        for slice in &self.slices {
+            let end = begin + slice.len();
+            let (x, y) = (a.max(begin), b.min(end));
+            if x < y {
+                slices.push(&slice[(x - begin)..(y - begin)]);
+            }
+            begin = end;
+        }
+
+        Self { slices, len: b - a }
+    }
+}
+
+impl<T> core::ops::Index<usize> for SplitVecView<'_, T> {
+    type Output = T;
+
+    /// Returns a reference to the `index`-th element of the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index is out of bounds")
+    }
+}
+
+impl<'a, T> IntoIterator for SplitVecView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = FlattenedSlices<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FlattenedSlices::from(self.slices)
+    }
+}
+
+impl<'a, T> IntoIterator for &SplitVecView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = FlattenedSlices<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SplitVecView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for SplitVecView<'_, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for SplitVecView<'_, T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self == *other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SplitVecView<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for SplitVecView<'_, T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for SplitVecView<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns a borrowed view on the given `range` of the vector, which provides slice-like access
+    /// even if the range is spread over multiple fragments;
+    /// returns None if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// let view = vec.view(3..6).unwrap();
+    /// assert_eq!(vec.try_get_slice(3..6), SplitVecSlice::Fragmented(0, 1));
+    /// assert_eq!(view, [3, 4, 5]);
+    ///
+    /// assert!(vec.view(..).is_some_and(|x| x.len() == 10));
+    /// assert!(vec.view(5..5).is_some_and(|x| x.is_empty()));
+    /// assert!(vec.view(5..12).is_none());
+    /// ```
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> Option<SplitVecView<'_, T>> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len());
+        match a <= b && b <= self.len() {
+            true => Some(SplitVecView::new(self.slices(a..b))),
+            false => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -135,4 +346,48 @@ mod tests {
         }
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn view() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let n = 157;
+            vec.extend(0..n);
+            let expected: alloc::vec::Vec<_> = (0..n).collect();
+
+            for (a, b) in [(0, 0), (0, 1), (3, 4), (3, 60), (10, n), (0, n), (n - 1, n)] {
+                let view = vec.view(a..b).expect("is-some");
+                assert_eq!(view.len(), b - a);
+                assert_eq!(view.is_empty(), a == b);
+                assert_eq!(view, &expected[a..b]);
+                assert!(view.iter().eq(expected[a..b].iter()));
+                for i in 0..(b - a) {
+                    assert_eq!(view.get(i), Some(&(a + i)));
+                    assert_eq!(view[i], a + i);
+                }
+                assert_eq!(view.get(b - a), None);
+
+                for (x, y) in [(0, 0), (0, 1), (1, 3), (0, b - a), (b - a, b - a)] {
+                    if y > b - a || x > y {
+                        continue;
+                    }
+                    let sub = view.slice(x..y);
+                    assert_eq!(sub, &expected[(a + x)..(a + y)]);
+                    assert_eq!(sub, vec.view((a + x)..(a + y)).expect("is-some"));
+                }
+            }
+
+            assert!(vec.view(0..(n + 1)).is_none());
+            assert!(vec.view((n + 1)..).is_none());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_slice_out_of_bounds() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let view = vec.view(2..8).expect("is-some");
+        let _ = view.slice(3..7);
+    }
 }
@@ -0,0 +1,175 @@
+use crate::algorithms::in_place_sort::in_place_sort_by;
+use crate::{Growth, SplitVec};
+use core::cmp::Ordering;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Sorts the vector without preserving the initial order of equal elements.
+    ///
+    /// The elements are sorted in place across the fragments without allocating an auxiliary buffer.
+    ///
+    /// On the other hand, `sort`, `sort_by` and `sort_by_key` methods of the `PinnedVec` implementation
+    /// are stable; i.e., equal elements keep their initial order. The stable sort moves the elements through
+    /// an auxiliary buffer of the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([5, 1, 4, 2, 3, 0]);
+    ///
+    /// vec.sort_unstable();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.invalidate_stable_indices();
+        in_place_sort_by(&mut self.fragments, T::cmp)
+    }
+
+    /// Sorts the vector with the `compare` function without preserving the initial order of equal elements.
+    ///
+    /// See [`SplitVec::sort_unstable`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([5, 1, 4, 2, 3, 0]);
+    ///
+    /// vec.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, [5, 4, 3, 2, 1, 0]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.invalidate_stable_indices();
+        in_place_sort_by(&mut self.fragments, compare)
+    }
+
+    /// Sorts the vector with the key extraction function `f` without preserving the initial order of equal elements.
+    ///
+    /// See [`SplitVec::sort_unstable`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([-5, 1, -4, 2, 3, 0]);
+    ///
+    /// vec.sort_unstable_by_key(|x: &i32| x.abs());
+    /// assert_eq!(vec, [0, 1, 2, 3, -4, -5]);
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.invalidate_stable_indices();
+        in_place_sort_by(&mut self.fragments, |a, b| f(a).cmp(&f(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    fn records(n: usize) -> impl Iterator<Item = (usize, String)> {
+        (0..n).map(|i| ((i * 7919) % 13, i.to_string()))
+    }
+
+    #[test]
+    fn sort_is_stable() {
+        fn test<G: Growth>(mut vec: SplitVec<(usize, String), G>) {
+            let n = 500;
+            vec.extend(records(n));
+            let mut expected: Vec<_> = records(n).collect();
+
+            vec.sort_by_key(|x| x.0);
+            expected.sort_by_key(|x| x.0);
+            assert_eq!(vec, expected);
+
+            vec.sort_by(|a, b| b.0.cmp(&a.0));
+            expected.sort_by_key(|x| core::cmp::Reverse(x.0));
+            assert_eq!(vec, expected);
+
+            vec.sort();
+            expected.sort();
+            assert_eq!(vec, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn sort_unstable() {
+        fn test<G: Growth>(mut vec: SplitVec<(usize, String), G>) {
+            let n = 500;
+            vec.extend(records(n));
+            let mut expected: Vec<_> = records(n).collect();
+
+            vec.sort_unstable_by_key(|x| x.0);
+            expected.sort_unstable_by_key(|x| x.0);
+            assert!(vec.iter().map(|x| x.0).eq(expected.iter().map(|x| x.0)));
+
+            vec.sort_unstable_by(|a, b| b.cmp(a));
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            assert_eq!(vec, expected);
+
+            vec.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(vec, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn stable_sort_with_panicking_compare() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(records(50));
+        let expected: Vec<_> = records(50).collect();
+
+        let mut count = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.sort_by(|a, b| {
+                count += 1;
+                assert!(count < 20);
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn stable_sort_with_compare_mutating_and_panicking() {
+        use alloc::boxed::Box;
+        use core::cell::Cell;
+
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend((0..20).map(|x| Cell::new(Some(Box::new(x as u64)))));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.sort_by(|a, _| {
+                // takes the value out, and then, panics
+                assert!(a.take().is_none());
+                core::cmp::Ordering::Less
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 20);
+        assert_eq!(vec.iter().filter(|x| x.take().is_some()).count(), 19);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Growth, SplitVec};
+use core::mem::MaybeUninit;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns the remaining spare capacity of the vector as an iterator of slices of `MaybeUninit<T>`.
+    ///
+    /// The first slice is the spare capacity of the last fragment, which is followed by the capacities
+    /// of the fragments allocated ahead, such as by [`SplitVec::reserve`]; empty slices are skipped.
+    /// Chained, the slices represent the positions `len..(len + n)` where `n` is the total length of the slices.
+    ///
+    /// The returned slices can be used to fill the vector with data before marking the data as initialized
+    /// using the unsafe `set_len` method; e.g., to decode or read data directly into the vector's tail without copying.
+    /// The `set_len` method of the split vector accepts a length beyond the `capacity` covering the spare capacity
+    /// returned by this method, in which case the fragments allocated ahead are added to the vector.
+    ///
+    /// Fragments allocated ahead which do not follow the growth strategy anymore, such as after fragments are removed,
+    /// are released by this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend([0, 1, 2]);
+    /// vec.reserve(10);
+    ///
+    /// let lens: Vec<_> = vec.spare_capacity_mut().map(|x| x.len()).collect();
+    /// assert_eq!(lens, [1, 8, 16]);
+    ///
+    /// // fill the first 7 positions of the spare capacity
+    /// let mut value = 3;
+    /// for slice in vec.spare_capacity_mut() {
+    ///     for x in slice.iter_mut().take(10 - value) {
+    ///         x.write(value);
+    ///         value += 1;
+    ///     }
+    /// }
+    ///
+    /// // mark the 7 positions as initialized
+    /// unsafe { vec.set_len(10) };
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(vec.capacity(), 4 + 8);
+    /// assert_eq!(vec.reserved_capacity(), 16);
+    /// ```
+    pub fn spare_capacity_mut(&mut self) -> impl Iterator<Item = &mut [MaybeUninit<T>]> {
+        self.release_outdated_reserved();
+        let last = self.fragments.last_mut().map(|x| x.spare_capacity_mut());
+        let reserved = self.reserved.iter_mut().map(|x| x.spare_capacity_mut());
+        last.into_iter().chain(reserved).filter(|x| !x.is_empty())
+    }
+
+    /// Moves the reserved fragments to the fragments of the vector until the capacity reaches `required_capacity`,
+    /// or until reserved fragments are exhausted.
+    pub(crate) fn adopt_reserved_fragments(&mut self, required_capacity: usize) {
+        self.release_outdated_reserved();
+        while self.capacity < required_capacity {
+            match self.reserved.pop_front() {
+                Some(fragment) => self.push_fragment(fragment),
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn spare_capacity_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut len = 0;
+            for additional in [0, 1, 5, 33, 100, 1000] {
+                vec.reserve(additional);
+                let spare: usize = vec.spare_capacity_mut().map(|x| x.len()).sum();
+                assert_eq!(spare, vec.capacity() - len + vec.reserved_capacity());
+                assert!(spare >= additional);
+
+                let mut value = len;
+                for slice in vec.spare_capacity_mut() {
+                    for x in slice.iter_mut().take(len + additional - value) {
+                        x.write(value);
+                        value += 1;
+                    }
+                }
+                assert_eq!(value, len + additional);
+
+                len += additional;
+                unsafe { vec.set_len(len) };
+                assert_eq!(vec.len(), len);
+                assert_eq!(vec, (0..len).collect::<Vec<_>>());
+
+                vec.push(len);
+                len += 1;
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn spare_capacity_mut_with_kept_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for policy in [MemoryPolicy::KeepAll, MemoryPolicy::KeepAtMost(2)] {
+                vec.clear();
+                vec.set_memory_policy(policy);
+                vec.extend(0..200);
+                vec.truncate(7);
+
+                let mut len = 7;
+                for additional in [0, 1, 5, 33, 100, 1000] {
+                    vec.reserve(additional);
+                    let spare: usize = vec.spare_capacity_mut().map(|x| x.len()).sum();
+                    assert_eq!(spare, vec.capacity() - len + vec.reserved_capacity());
+                    assert!(spare >= additional);
+
+                    let mut value = len;
+                    for slice in vec.spare_capacity_mut() {
+                        for x in slice.iter_mut().take(len + additional - value) {
+                            x.write(value);
+                            value += 1;
+                        }
+                    }
+                    assert_eq!(value, len + additional);
+
+                    len += additional;
+                    unsafe { vec.set_len(len) };
+                    assert_eq!(vec.len(), len);
+                    assert_eq!(vec, (0..len).collect::<Vec<_>>());
+
+                    vec.truncate(len / 2);
+                    len /= 2;
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn spare_capacity_mut_releases_outdated_reserved() {
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend(0..20);
+        vec.reserve(100);
+        assert!(vec.reserved_capacity() > 0);
+
+        vec.truncate(2);
+        let lens: Vec<_> = vec.spare_capacity_mut().map(|x| x.len()).collect();
+        assert_eq!(lens, [2]);
+        assert_eq!(vec.reserved_capacity(), 0);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::range_helpers::{range_end, range_start};
+use crate::{Drain, Growth, SplitVec};
+use core::iter::FusedIterator;
+use core::ops::RangeBounds;
+
+/// A splicing iterator for a split vector, yielding the removed elements of the range by value.
+///
+/// When the iterator is dropped, the elements of the range which are not yet yielded are dropped,
+/// and the items of the replacement iterator are inserted in place of the range.
+///
+/// This struct is created by `SplitVec::splice()` method.
+pub struct Splice<'a, T, G: Growth, I: Iterator<Item = T>> {
+    /// Drain of the range.
+    drain: Option<Drain<'a, T, G>>,
+    /// Position of the range which the replacement items are inserted at.
+    start: usize,
+    remaining: usize,
+    replace_with: I,
+}
+
+impl<T, G: Growth, I: Iterator<Item = T>> Iterator for Splice<'_, T, G, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                self.remaining -= 1;
+                self.drain.as_mut().and_then(|x| x.next())
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, G: Growth, I: Iterator<Item = T>> ExactSizeIterator for Splice<'_, T, G, I> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T, G: Growth, I: Iterator<Item = T>> FusedIterator for Splice<'_, T, G, I> {}
+
+impl<T, G: Growth, I: Iterator<Item = T>> Drop for Splice<'_, T, G, I> {
+    fn drop(&mut self) {
+        if let Some(mut drain) = self.drain.take() {
+            for _ in 0..self.remaining {
+                drop(drain.next());
+            }
+            self.remaining = 0;
+
+            let vec = drain.finish();
+            vec.insert_many(self.start, self.replace_with.by_ref());
+        }
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Creates a splicing iterator that replaces the specified `range` in the vector with the given `replace_with` iterator
+    /// and yields the removed items.
+    /// `replace_with` does not need to be the same length as `range`.
+    ///
+    /// The range is removed even if the iterator is not consumed until the end.
+    /// The items of `replace_with` are inserted when the splicing iterator is dropped.
+    ///
+    /// The elements after the range are shifted in place across the fragments, first to close the gap of the removed
+    /// range and then to open the gap of the replacement items, as in [`SplitVec::insert_many`];
+    /// hence, memory locations of the elements after the range change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([1, 2, 3, 4]);
+    ///
+    /// let removed: Vec<_> = vec.splice(1..3, [7, 8, 9]).collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(vec, [1, 7, 8, 9, 4]);
+    ///
+    /// vec.splice(..2, []);
+    /// assert_eq!(vec, [8, 9, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, T, G, I::IntoIter>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let start = range_start(&range);
+        let end = range_end(&range, self.len);
+        assert!(start <= end, "splice range start must not exceed end");
+        assert!(end <= self.len, "splice range end is out of bounds");
+
+        Splice {
+            drain: Some(self.drain(start..end)),
+            start,
+            remaining: end - start,
+            replace_with: replace_with.into_iter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn splice() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 97;
+            let ranges = [(0, 0), (0, n), (3, 4), (10, 60), (40, n), (n, n)];
+            for (a, b) in ranges {
+                for num_replace in [0, 1, 5, 100] {
+                    vec.clear();
+                    vec.extend((0..n).map(|x| x.to_string()));
+                    let mut expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+                    let replace_with = (0..num_replace).map(|x| (1000 + x).to_string());
+                    let removed: Vec<_> = vec.splice(a..b, replace_with.clone()).collect();
+                    let expected_removed: Vec<_> = expected.splice(a..b, replace_with).collect();
+
+                    assert_eq!(removed, expected_removed);
+                    assert_eq!(vec, expected);
+
+                    vec.push("x".to_string());
+                    expected.push("x".to_string());
+                    assert_eq!(vec, expected);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn splice_not_consumed() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..50);
+            let mut splice = vec.splice(10..20, [100, 101]);
+            assert_eq!(splice.len(), 10);
+            assert_eq!(splice.next(), Some(10));
+            assert_eq!(splice.len(), 9);
+            drop(splice);
+
+            let expected: Vec<_> = (0..10).chain([100, 101]).chain(20..50).collect();
+            assert_eq!(vec, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,473 @@
+use crate::{Doubling, Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+/// A double-ended queue backed by two split vectors growing in opposite directions.
+///
+/// * Elements pushed to the back are pushed to the `back` split vector, while the elements pushed to the front
+///   are pushed to the `front` split vector; hence, the front vector stores its elements in reverse order.
+/// * Elements are popped from the top of the corresponding split vector when possible. Otherwise, they are taken
+///   from the bottom of the other split vector by moving forward its logical start offset, without moving any element.
+/// * Once all elements of a split vector are taken, the split vector is cleared and its offset is reset.
+///   Further, once the elements taken from the bottom of a split vector outnumber its remaining elements,
+///   the remaining elements are moved to the bottom; so that the memory of the taken elements is reused,
+///   and the memory of the deque is bounded by its length rather than the number of operations.
+///
+/// Therefore, all operations are amortized ***O(1)***. Since the split vectors never reallocate, elements remain
+/// pinned to their memory locations while they are in the deque, unless they are moved by such a compaction;
+/// which can only happen while taking elements from the bottom of the split vector, such as by `pop_front` when
+/// all elements are pushed to the back.
+///
+/// Access by index is ***O(1)*** for growth strategies implementing `GrowthWithConstantTimeAccess`,
+/// such as `Doubling` and `Linear`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut deque = SplitDeque::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+/// deque.push_front(0);
+///
+/// assert_eq!(deque.len(), 4);
+/// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+/// assert_eq!(deque.get(1), Some(&1));
+///
+/// assert_eq!(deque.pop_front(), Some(0));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_back(), Some(2));
+/// assert_eq!(deque.pop_back(), Some(1));
+/// assert_eq!(deque.pop_back(), None);
+/// ```
+pub struct SplitDeque<T, G: Growth = Doubling> {
+    front: Half<T, G>,
+    back: Half<T, G>,
+}
+
+impl<T> SplitDeque<T, Doubling> {
+    /// Creates an empty deque with the default `Doubling` growth.
+    pub fn new() -> Self {
+        Self::with_growth(Doubling)
+    }
+}
+
+impl<T> Default for SplitDeque<T, Doubling> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, G: Growth> SplitDeque<T, G> {
+    /// Creates an empty deque where the underlying split vectors have the given `growth` strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut deque = SplitDeque::with_growth(Linear::new(4));
+    /// deque.push_front('x');
+    /// assert_eq!(deque.front(), Some(&'x'));
+    /// ```
+    pub fn with_growth(growth: G) -> Self {
+        Self {
+            front: Half::new(growth.clone()),
+            back: Half::new(growth),
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns whether or not the deque is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the `value` to the back of the deque.
+    pub fn push_back(&mut self, value: T) {
+        self.back.vec.push(value);
+    }
+
+    /// Prepends the `value` to the front of the deque.
+    pub fn push_front(&mut self, value: T) {
+        self.front.vec.push(value);
+    }
+
+    /// Removes the last element of the deque and returns it; returns None if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        match self.back.is_empty() {
+            false => self.back.pop_top(),
+            true => self.front.pop_bottom(),
+        }
+    }
+
+    /// Removes the first element of the deque and returns it; returns None if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self.front.is_empty() {
+            false => self.front.pop_top(),
+            true => self.back.pop_bottom(),
+        }
+    }
+
+    /// Returns a reference to the first element of the deque; returns None if the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last element of the deque; returns None if the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.len().checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns a reference to the element at the given `index` from the front; returns None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let num_front = self.front.len();
+        match index < num_front {
+            true => self.front.vec.get(self.front.vec.len() - 1 - index),
+            false => self.back.vec.get(self.back.head + index - num_front),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the given `index` from the front; returns None if the index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let num_front = self.front.len();
+        match index < num_front {
+            true => {
+                let i = self.front.vec.len() - 1 - index;
+                self.front.vec.get_mut(i)
+            }
+            false => self.back.vec.get_mut(self.back.head + index - num_front),
+        }
+    }
+
+    /// Returns an iterator over the elements of the deque from front to back.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let front = self.front.vec.iter_over_range(self.front.head..).rev();
+        let back = self.back.vec.iter_over_range(self.back.head..);
+        front.chain(back)
+    }
+
+    /// Clears the deque, removing all elements.
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+    }
+}
+
+impl<T, G: Growth> Drop for SplitDeque<T, G> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, G: Growth> Extend<T> for SplitDeque<T, G> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.back.vec.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for SplitDeque<T, Doubling> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T: core::fmt::Debug, G: Growth> core::fmt::Debug for SplitDeque<T, G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// One direction of the deque; elements at positions `head..vec.len()` are alive,
+/// while the elements before `head` are already moved out.
+struct Half<T, G: Growth> {
+    vec: SplitVec<T, G>,
+    head: usize,
+}
+
+impl<T, G: Growth> Half<T, G> {
+    fn new(growth: G) -> Self {
+        Self {
+            vec: SplitVec::with_growth(growth),
+            head: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.vec.len() - self.head
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn pop_top(&mut self) -> Option<T> {
+        let value = match self.is_empty() {
+            true => None,
+            false => self.vec.pop(),
+        };
+        self.reset_if_empty();
+        value
+    }
+
+    fn pop_bottom(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let ptr = self.vec.get_ptr(self.head)?;
+        self.head += 1;
+        // SAFETY: the element is alive and it is moved out exactly once since head is moved forward
+        let value = unsafe { ptr.read() };
+        self.reset_if_empty();
+        if self.head > self.len() {
+            self.compact();
+        }
+        Some(value)
+    }
+
+    /// Moves the alive elements to the bottom of the split vector, and releases the fragments which are not
+    /// required anymore.
+    ///
+    /// Since it is called only when the number of taken elements exceeds the number of alive elements,
+    /// the number of moves is bounded by the number of elements taken since the last compaction.
+    fn compact(&mut self) {
+        let alive = self.len();
+        let fragments = &mut self.vec.fragments;
+        let (mut src, mut dst) = ((0, 0), (0, 0));
+
+        // skip the taken elements
+        let mut to_skip = self.head;
+        while to_skip > 0 {
+            let n = (fragments[src.0].len() - src.1).min(to_skip);
+            src.1 += n;
+            to_skip -= n;
+            if src.1 == fragments[src.0].len() {
+                src = (src.0 + 1, 0);
+            }
+        }
+
+        let mut remaining = alive;
+        while remaining > 0 {
+            while src.1 == fragments[src.0].len() {
+                src = (src.0 + 1, 0);
+            }
+            while dst.1 == fragments[dst.0].len() {
+                dst = (dst.0 + 1, 0);
+            }
+            let n = (fragments[src.0].len() - src.1)
+                .min(fragments[dst.0].len() - dst.1)
+                .min(remaining);
+            // SAFETY: source elements are alive and destination positions are taken; the destination precedes
+            // the source and ptr::copy allows overlapping regions
+            unsafe {
+                let src_ptr = fragments[src.0].as_ptr().add(src.1);
+                let dst_ptr = fragments[dst.0].as_mut_ptr().add(dst.1);
+                core::ptr::copy(src_ptr, dst_ptr, n);
+            }
+            src.1 += n;
+            dst.1 += n;
+            remaining -= n;
+        }
+
+        // SAFETY: the first `alive` positions hold the alive elements; the remaining ones are moved out
+        unsafe { self.vec.set_len(alive) };
+        let num_fragments = self
+            .vec
+            .fragments
+            .iter()
+            .take_while(|x| !x.is_empty())
+            .count();
+        self.vec.release_fragments_after(num_fragments.max(1));
+        self.head = 0;
+    }
+
+    fn reset_if_empty(&mut self) {
+        if self.head > 0 && self.is_empty() {
+            // SAFETY: all elements are already moved out
+            unsafe { self.vec.set_len(0) };
+            self.vec.clear();
+            self.head = 0;
+        }
+    }
+
+    fn clear(&mut self) {
+        let (head, len) = (self.head, self.vec.len());
+        // the lengths are set before dropping; if a drop panics, the remaining elements are leaked
+        // rather than being dropped again by the split vector
+        // SAFETY: the alive elements are dropped right after
+        unsafe { self.vec.set_len(0) };
+        self.head = 0;
+        for i in head..len {
+            if let Some(ptr) = self.vec.get_ptr_mut(i) {
+                // SAFETY: the element is alive, and it is not owned by the split vector anymore
+                unsafe { ptr.drop_in_place() };
+            }
+        }
+        self.vec.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::collections::VecDeque;
+    use alloc::rc::Rc;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn push_pop_both_ends() {
+        fn test<G: Growth>(vec: SplitVec<String, G>) {
+            let mut deque = SplitDeque::with_growth(vec.growth().clone());
+            let mut expected = VecDeque::new();
+
+            for i in 0..3000 {
+                match (i * 7919) % 11 {
+                    0..=2 => {
+                        deque.push_back(i.to_string());
+                        expected.push_back(i.to_string());
+                    }
+                    3..=5 => {
+                        deque.push_front(i.to_string());
+                        expected.push_front(i.to_string());
+                    }
+                    6 | 7 => assert_eq!(deque.pop_front(), expected.pop_front()),
+                    _ => assert_eq!(deque.pop_back(), expected.pop_back()),
+                }
+
+                assert_eq!(deque.len(), expected.len());
+                assert_eq!(deque.front(), expected.front());
+                assert_eq!(deque.back(), expected.back());
+            }
+
+            assert!(deque.iter().eq(expected.iter()));
+            assert!(deque.iter().rev().eq(expected.iter().rev()));
+            for i in 0..expected.len() {
+                assert_eq!(deque.get(i), expected.get(i));
+            }
+            assert_eq!(deque.get(expected.len()), None);
+
+            while let Some(x) = expected.pop_front() {
+                assert_eq!(deque.pop_front(), Some(x));
+            }
+            assert!(deque.is_empty());
+            assert_eq!(deque.pop_front(), None);
+            assert_eq!(deque.pop_back(), None);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn queue_reuses_memory() {
+        let mut deque = SplitDeque::with_growth(Linear::new(2));
+        for i in 0..1000 {
+            deque.push_back(i);
+            deque.push_back(i);
+            assert_eq!(deque.pop_front(), Some(i));
+            assert_eq!(deque.pop_front(), Some(i));
+        }
+        assert!(deque.is_empty());
+        assert!(deque.back.vec.fragments().len() <= 1);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut deque: SplitDeque<_> = (0..10).collect();
+        deque.push_front(100);
+        *deque.get_mut(0).expect("is-some") += 1;
+        *deque.get_mut(10).expect("is-some") += 1;
+        assert_eq!(
+            deque.iter().copied().collect::<Vec<_>>(),
+            [101, 0, 1, 2, 3, 4, 5, 6, 7, 8, 10]
+        );
+        assert!(deque.get_mut(11).is_none());
+    }
+
+    #[test]
+    fn drops_alive_elements() {
+        let rc = Rc::new(0);
+        let mut deque = SplitDeque::new();
+        for _ in 0..100 {
+            deque.push_back(rc.clone());
+            deque.push_front(rc.clone());
+        }
+        for _ in 0..30 {
+            deque.pop_front();
+            deque.pop_back();
+        }
+        assert_eq!(Rc::strong_count(&rc), 140 + 1);
+
+        // take from the bottoms of both halves
+        for _ in 0..80 {
+            deque.pop_front();
+        }
+        assert_eq!(Rc::strong_count(&rc), 60 + 1);
+
+        deque.clear();
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        deque.extend((0..10).map(|_| rc.clone()));
+        deque.pop_front();
+        drop(deque);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn queue_memory_is_bounded() {
+        fn test<G: Growth>(vec: SplitVec<String, G>) {
+            let mut deque = SplitDeque::with_growth(vec.growth().clone());
+            let mut expected = VecDeque::new();
+            for i in 0..10 {
+                deque.push_back(i.to_string());
+                expected.push_back(i.to_string());
+            }
+
+            for i in 10..20000 {
+                deque.push_back(i.to_string());
+                expected.push_back(i.to_string());
+                assert_eq!(deque.pop_front(), expected.pop_front());
+            }
+            assert!(deque.back.vec.capacity() <= 200);
+            assert!(deque.iter().eq(expected.iter()));
+            for i in 0..expected.len() {
+                assert_eq!(deque.get(i), expected.get(i));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn clear_with_panicking_drop() {
+        struct PanicOnDrop(#[allow(dead_code)] Rc<usize>, bool);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                assert!(!self.1, "panics on drop");
+            }
+        }
+
+        let rc = Rc::new(0);
+        let mut deque = SplitDeque::new();
+        for i in 0..20 {
+            deque.push_back(PanicOnDrop(rc.clone(), i == 10));
+        }
+        deque.pop_front();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| deque.clear()));
+        assert!(result.is_err());
+        assert!(deque.is_empty());
+        drop(deque);
+
+        // elements after the panicking one are leaked, but none is dropped twice
+        assert_eq!(Rc::strong_count(&rc), 1 + 9);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{Doubling, Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+/// A priority queue implemented with a binary max-heap backed by a [`SplitVec<T, G>`].
+///
+/// Since the storage is a split vector, the heap grows without copying the already pushed elements.
+///
+/// Elements are accessed by `get_ptr_mut` during the sift operations; therefore, each access is ***O(1)***
+/// for growth strategies implementing `GrowthWithConstantTimeAccess`, such as `Doubling` and `Linear`.
+/// Note that the positions of the elements change as they are sifted up or down; hence, the heap does not
+/// keep the elements pinned.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut heap = SplitHeap::new();
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(1);
+///
+/// assert_eq!(heap.len(), 3);
+/// assert_eq!(heap.peek(), Some(&7));
+///
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Clone)]
+pub struct SplitHeap<T: Ord, G: Growth = Doubling> {
+    vec: SplitVec<T, G>,
+}
+
+impl<T: Ord> SplitHeap<T, Doubling> {
+    /// Creates an empty heap with the default `Doubling` growth.
+    pub fn new() -> Self {
+        Self {
+            vec: SplitVec::new(),
+        }
+    }
+}
+
+impl<T: Ord> Default for SplitHeap<T, Doubling> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, G: Growth> SplitHeap<T, G> {
+    /// Creates an empty heap where the underlying split vector has the given `growth` strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut heap = SplitHeap::with_growth(Linear::new(4));
+    /// heap.push('x');
+    /// assert_eq!(heap.peek(), Some(&'x'));
+    /// ```
+    pub fn with_growth(growth: G) -> Self {
+        Self {
+            vec: SplitVec::with_growth(growth),
+        }
+    }
+
+    /// Pushes the `value` to the heap.
+    ///
+    /// Time complexity is ***O(log n)*** element accesses.
+    pub fn push(&mut self, value: T) {
+        self.vec.push(value);
+        self.sift_up(self.vec.len() - 1);
+    }
+
+    /// Removes the greatest element from the heap and returns it; returns None if the heap is empty.
+    ///
+    /// Time complexity is ***O(log n)*** element accesses.
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.vec.len().checked_sub(1)?;
+        if last > 0 {
+            let (root, last) = (self.ptr(0), self.ptr(last));
+            // SAFETY: both positions are in bounds and distinct
+            unsafe { core::ptr::swap(root, last) };
+        }
+        let value = self.vec.pop();
+        self.sift_down(0, self.vec.len());
+        value
+    }
+
+    /// Returns a reference to the greatest element in the heap; returns None if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.vec.first()
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns whether or not the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Clears the heap, removing all elements.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// Returns the underlying split vector holding the elements in heap order.
+    pub fn as_split_vec(&self) -> &SplitVec<T, G> {
+        &self.vec
+    }
+
+    /// Consumes the heap and returns the underlying split vector holding the elements in heap order.
+    pub fn into_split_vec(self) -> SplitVec<T, G> {
+        self.vec
+    }
+
+    /// Consumes the heap and returns the underlying split vector with the elements sorted in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let heap: SplitHeap<_> = [4, 1, 8, 3].into_iter().collect();
+    /// assert_eq!(heap.into_sorted_split_vec(), [1, 3, 4, 8]);
+    /// ```
+    pub fn into_sorted_split_vec(mut self) -> SplitVec<T, G> {
+        let mut end = self.vec.len();
+        while end > 1 {
+            end -= 1;
+            let (root, last) = (self.ptr(0), self.ptr(end));
+            // SAFETY: both positions are in bounds and distinct
+            unsafe { core::ptr::swap(root, last) };
+            self.sift_down(0, end);
+        }
+        self.vec
+    }
+
+    fn ptr(&mut self, index: usize) -> *mut T {
+        self.vec.get_ptr_mut(index).expect("index is within bounds")
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            let (child_ptr, parent_ptr) = (self.ptr(index), self.ptr(parent));
+            // SAFETY: both positions are in bounds and distinct
+            unsafe {
+                if *child_ptr <= *parent_ptr {
+                    break;
+                }
+                core::ptr::swap(child_ptr, parent_ptr);
+            }
+            index = parent;
+        }
+    }
+
+    /// Sifts down the element at `index` considering only the first `end` elements.
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        loop {
+            let left = 2 * index + 1;
+            if left >= end {
+                break;
+            }
+
+            let mut child = left;
+            let mut child_ptr = self.ptr(left);
+            if left + 1 < end {
+                let right_ptr = self.ptr(left + 1);
+                // SAFETY: both positions are in bounds
+                if unsafe { *right_ptr > *child_ptr } {
+                    (child, child_ptr) = (left + 1, right_ptr);
+                }
+            }
+
+            let ptr = self.ptr(index);
+            // SAFETY: both positions are in bounds and distinct
+            unsafe {
+                if *ptr >= *child_ptr {
+                    break;
+                }
+                core::ptr::swap(ptr, child_ptr);
+            }
+            index = child;
+        }
+    }
+}
+
+impl<T: Ord, G: Growth> From<SplitVec<T, G>> for SplitHeap<T, G> {
+    /// Converts the split vector into a heap in place, in ***O(n)*** element accesses.
+    fn from(vec: SplitVec<T, G>) -> Self {
+        let mut heap = Self { vec };
+        let len = heap.vec.len();
+        for index in (0..(len / 2)).rev() {
+            heap.sift_down(index, len);
+        }
+        heap
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SplitHeap<T, Doubling> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let vec: SplitVec<T, Doubling> = iter.into_iter().collect();
+        vec.into()
+    }
+}
+
+impl<T: Ord, G: Growth> Extend<T> for SplitHeap<T, G> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T: Ord + core::fmt::Debug, G: Growth> core::fmt::Debug for SplitHeap<T, G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.vec.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::collections::BinaryHeap;
+    use alloc::vec::Vec;
+
+    fn values(n: usize) -> impl Iterator<Item = usize> {
+        (0..n).map(|i| (i * 7919 + 13) % 1009)
+    }
+
+    #[test]
+    fn push_pop() {
+        fn test<G: Growth>(vec: SplitVec<usize, G>) {
+            let mut heap = SplitHeap::from(vec);
+            let mut expected = BinaryHeap::new();
+            assert_eq!(heap.pop(), None);
+
+            for (i, x) in values(2000).enumerate() {
+                heap.push(x);
+                expected.push(x);
+                if i % 3 == 0 {
+                    assert_eq!(heap.pop(), expected.pop());
+                }
+                assert_eq!(heap.peek(), expected.peek());
+                assert_eq!(heap.len(), expected.len());
+            }
+
+            while let Some(x) = expected.pop() {
+                assert_eq!(heap.pop(), Some(x));
+            }
+            assert!(heap.is_empty());
+            assert_eq!(heap.peek(), None);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn from_split_vec_and_sorted() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(values(777));
+            let heap = SplitHeap::from(vec.clone());
+
+            let mut expected: Vec<_> = values(777).collect();
+            expected.sort();
+            assert_eq!(heap.peek(), expected.last());
+            assert_eq!(heap.clone().into_sorted_split_vec(), expected);
+
+            let mut heap = heap;
+            let mut popped = Vec::new();
+            while let Some(x) = heap.pop() {
+                popped.push(x);
+            }
+            expected.reverse();
+            assert_eq!(popped, expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut heap: SplitHeap<_> = values(100).collect();
+        heap.extend(values(50));
+        assert_eq!(heap.len(), 150);
+
+        let mut expected: Vec<_> = values(100).chain(values(50)).collect();
+        expected.sort();
+        assert_eq!(heap.into_sorted_split_vec(), expected);
+    }
+}
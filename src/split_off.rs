@@ -0,0 +1,167 @@
+use crate::{Doubling, DoublingUntil, Exponential, FunctionalGrowth, Growth, Linear, SplitVec};
+use alloc::vec::Vec;
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Moves the elements `at..len` into a new vector with the same growth strategy and memory policy.
+    ///
+    /// The elements are moved in bulk, slice by slice, into the fragments of the new vector;
+    /// the fragments of this vector which become empty are released.
+    pub(crate) fn split_off_by_moving(&mut self, at: usize) -> Self {
+        self.invalidate_stable_indices();
+        assert!(at <= self.len, "split_off index is out of bounds");
+
+        let mut other = Self::with_growth(self.growth.clone());
+        other.set_memory_policy(self.memory_policy);
+
+        let Some((f, i)) = self.get_fragment_and_inner_indices(at) else {
+            return other;
+        };
+
+        // cut this vector at `at`; the tail is owned by the moves below
+        let lens: Vec<_> = self.fragments[f..].iter().map(|x| x.len()).collect();
+        // SAFETY: lengths are only decreased; elements beyond the lengths are moved into `other`
+        unsafe { self.fragments[f].set_len(i) };
+        for fragment in &mut self.fragments[(f + 1)..] {
+            unsafe { fragment.set_len(0) };
+        }
+        self.len = at;
+
+        for (k, len) in lens.into_iter().enumerate() {
+            let begin = if k == 0 { i } else { 0 };
+            // SAFETY: positions begin..len of the fragment are initialized and not owned by this vector anymore
+            let src = unsafe { self.fragments[f + k].as_ptr().add(begin) };
+            unsafe { other.move_from_ptr(src, len - begin) };
+        }
+
+        let num_fragments = match i {
+            0 => f.max(1),
+            _ => f + 1,
+        };
+        self.release_fragments_after(num_fragments);
+
+        other
+    }
+
+    /// Moves `count` elements starting at `src` to the end of the vector.
+    ///
+    /// # Safety
+    ///
+    /// `src..src + count` must be initialized elements which are not owned or used by anyone else afterwards.
+    unsafe fn move_from_ptr(&mut self, mut src: *const T, mut count: usize) {
+        while count > 0 {
+            if !self.has_capacity_for_one() {
+                self.add_fragment();
+            }
+            if let Some(last) = self.fragments.last_mut() {
+                let n = last.room().min(count);
+                let len = last.len();
+                unsafe { core::ptr::copy_nonoverlapping(src, last.as_mut_ptr().add(len), n) };
+                unsafe { last.set_len(len + n) };
+                self.len += n;
+                src = unsafe { src.add(n) };
+                count -= n;
+            }
+        }
+    }
+}
+
+macro_rules! impl_split_off_by_moving {
+    ($growth:ty) => {
+        impl<T> SplitVec<T, $growth> {
+            /// Splits the vector into two at the given index.
+            ///
+            /// Returns a newly allocated vector containing the elements in the range `[at, len)`;
+            /// after the call, this vector is left containing the elements `[0, at)`.
+            ///
+            /// The returned vector has the same growth strategy and memory policy.
+            /// The elements of the tail are moved in bulk, slice by slice, into the fragments of the returned vector;
+            /// and the fragments of this vector which become empty are released.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `at > len`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use orx_split_vec::*;
+            ///
+            /// let mut vec = SplitVec::with_linear_growth(2);
+            /// vec.extend(0..10);
+            ///
+            /// let tail = vec.split_off(3);
+            /// assert_eq!(vec, [0, 1, 2]);
+            /// assert_eq!(tail, [3, 4, 5, 6, 7, 8, 9]);
+            /// ```
+            pub fn split_off(&mut self, at: usize) -> Self {
+                self.split_off_by_moving(at)
+            }
+        }
+    };
+}
+
+impl_split_off_by_moving!(Doubling);
+impl_split_off_by_moving!(DoublingUntil);
+impl_split_off_by_moving!(Exponential);
+impl_split_off_by_moving!(FunctionalGrowth);
+impl_split_off_by_moving!(Linear);
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn split_off_by_moving() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let n = 97;
+            for at in [0, 1, 3, 4, 12, 60, 96, 97] {
+                vec.clear();
+                vec.extend((0..n).map(|x| x.to_string()));
+                let mut expected: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+
+                let mut tail = vec.split_off_by_moving(at);
+                let expected_tail = expected.split_off(at);
+                assert_eq!(vec, expected);
+                assert_eq!(tail, expected_tail);
+                assert!(!vec.fragments().is_empty());
+                assert!(
+                    vec.fragments().len() == 1
+                        || !vec.fragments().last().expect("is-some").is_empty()
+                );
+
+                vec.push("x".to_string());
+                tail.push("y".to_string());
+                expected.push("x".to_string());
+                assert_eq!(vec, expected);
+                assert_eq!(tail.last().map(|x| x.as_str()), Some("y"));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_off_keeps_growth() {
+        let mut vec = SplitVec::with_linear_growth(3);
+        vec.extend(0..20);
+        let tail = vec.split_off(5);
+        assert!(tail.fragments().iter().all(|x| x.capacity() == 8));
+        assert_eq!(tail, (5..20).collect::<Vec<_>>());
+
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend(0..20);
+        let tail = vec.split_off(20);
+        assert!(tail.is_empty());
+        assert_eq!(vec.len(), 20);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.split_off(11);
+    }
+}
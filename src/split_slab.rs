@@ -0,0 +1,294 @@
+use crate::{Doubling, Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+/// A slab (arena) storing values at stable keys, backed by a [`SplitVec<T, G>`].
+///
+/// Each inserted value receives a key which is the position of its entry in the underlying split vector.
+/// Removed entries are linked into a free list and their keys are reused by the subsequent insertions.
+///
+/// Since the storage is a split vector, the slab grows without moving the already inserted values;
+/// hence, references to or pointers of the values remain valid until the values are removed.
+///
+/// Accessing a value by its key is ***O(1)*** for growth strategies implementing `GrowthWithConstantTimeAccess`,
+/// such as `Doubling` and `Linear`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut slab = SplitSlab::new();
+/// let a = slab.insert('a');
+/// let b = slab.insert('b');
+/// assert_eq!(slab.len(), 2);
+/// assert_eq!(slab.get(a), Some(&'a'));
+///
+/// assert_eq!(slab.remove(a), Some('a'));
+/// assert_eq!(slab.get(a), None);
+/// assert_eq!(slab.remove(a), None);
+///
+/// // the key of the removed value is reused
+/// let c = slab.insert('c');
+/// assert_eq!(c, a);
+/// assert_eq!(slab.get(b), Some(&'b'));
+/// assert_eq!(slab.get(c), Some(&'c'));
+/// ```
+#[derive(Clone)]
+pub struct SplitSlab<T, G: Growth = Doubling> {
+    entries: SplitVec<Entry<T>, G>,
+    /// Key of the most recently vacated entry, which is the head of the free list.
+    free: Option<usize>,
+    len: usize,
+}
+
+#[derive(Clone)]
+enum Entry<T> {
+    Occupied(T),
+    /// Vacant entry holding the key of the next vacant entry in the free list.
+    Vacant(Option<usize>),
+}
+
+impl<T> SplitSlab<T, Doubling> {
+    /// Creates an empty slab with the default `Doubling` growth.
+    pub fn new() -> Self {
+        Self::with_growth(Doubling)
+    }
+}
+
+impl<T> Default for SplitSlab<T, Doubling> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, G: Growth> SplitSlab<T, G> {
+    /// Creates an empty slab where the underlying split vector has the given `growth` strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut slab = SplitSlab::with_growth(Linear::new(4));
+    /// let key = slab.insert("x");
+    /// assert_eq!(slab[key], "x");
+    /// ```
+    pub fn with_growth(growth: G) -> Self {
+        Self {
+            entries: SplitVec::with_growth(growth),
+            free: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the slab.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the slab is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the key that the next inserted value will receive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut slab = SplitSlab::new();
+    /// let key = slab.vacant_key();
+    /// assert_eq!(slab.insert(42), key);
+    /// ```
+    pub fn vacant_key(&self) -> usize {
+        self.free.unwrap_or(self.entries.len())
+    }
+
+    /// Inserts the `value` into the slab and returns its key.
+    ///
+    /// The key of the most recently removed value is reused if any; otherwise, the value is pushed to the end
+    /// of the underlying split vector.
+    pub fn insert(&mut self, value: T) -> usize {
+        let key = self.vacant_key();
+        match self.free.and_then(|key| self.entries.get_mut(key)) {
+            Some(entry) => {
+                if let Entry::Vacant(next) = entry {
+                    self.free = *next;
+                }
+                *entry = Entry::Occupied(value);
+            }
+            None => self.entries.push(Entry::Occupied(value)),
+        }
+        self.len += 1;
+        key
+    }
+
+    /// Removes and returns the value with the given `key`; returns None if there is no value with this key.
+    ///
+    /// The key is added to the free list to be reused by the subsequent insertions.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let entry = self.entries.get_mut(key)?;
+        match entry {
+            Entry::Occupied(_) => {
+                let vacated = core::mem::replace(entry, Entry::Vacant(self.free));
+                self.free = Some(key);
+                self.len -= 1;
+                match vacated {
+                    Entry::Occupied(value) => Some(value),
+                    Entry::Vacant(_) => None,
+                }
+            }
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Returns whether or not there exists a value with the given `key`.
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value with the given `key`; returns None if there is no value with this key.
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.entries.get(key) {
+            Some(Entry::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value with the given `key`; returns None if there is no value with this key.
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.entries.get_mut(key) {
+            Some(Entry::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the keys and references of the values in the slab, in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut slab = SplitSlab::new();
+    /// let keys: Vec<_> = (0..5).map(|x| slab.insert(x * 10)).collect();
+    /// slab.remove(keys[1]);
+    /// slab.remove(keys[3]);
+    ///
+    /// let values: Vec<_> = slab.iter().collect();
+    /// assert_eq!(values, [(0, &0), (2, &20), (4, &40)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(key, entry)| match entry {
+                Entry::Occupied(value) => Some((key, value)),
+                Entry::Vacant(_) => None,
+            })
+    }
+
+    /// Clears the slab, removing all values.
+    ///
+    /// Keys are then handed out starting from zero again.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.free = None;
+        self.len = 0;
+    }
+}
+
+impl<T, G: Growth> core::ops::Index<usize> for SplitSlab<T, G> {
+    type Output = T;
+
+    fn index(&self, key: usize) -> &Self::Output {
+        self.get(key).expect("no value with the given key")
+    }
+}
+
+impl<T, G: Growth> core::ops::IndexMut<usize> for SplitSlab<T, G> {
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        self.get_mut(key).expect("no value with the given key")
+    }
+}
+
+impl<T: core::fmt::Debug, G: Growth> core::fmt::Debug for SplitSlab<T, G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::collections::BTreeMap;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn insert_remove() {
+        fn test<G: Growth>(vec: SplitVec<usize, G>) {
+            let mut slab = SplitSlab::with_growth(vec.growth().clone());
+            let mut expected = BTreeMap::new();
+
+            for i in 0..2000 {
+                let key = slab.insert(i.to_string());
+                assert!(!expected.contains_key(&key));
+                expected.insert(key, i.to_string());
+
+                if i % 3 == 0 {
+                    let key = (i * 7919) % (key + 1);
+                    assert_eq!(slab.remove(key), expected.remove(&key));
+                }
+                assert_eq!(slab.len(), expected.len());
+            }
+
+            for (key, value) in &expected {
+                assert_eq!(slab.get(*key), Some(value));
+                assert!(slab.contains(*key));
+            }
+            let values: Vec<_> = slab.iter().map(|(k, v)| (k, v.clone())).collect();
+            let expected_values: Vec<_> = expected.into_iter().collect();
+            assert_eq!(values, expected_values);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn values_are_pinned() {
+        let mut slab: SplitSlab<String> = SplitSlab::new();
+        let key = slab.insert("pinned".to_string());
+        let ptr = slab.get(key).expect("is-some") as *const String;
+
+        for i in 0..1000 {
+            let k = slab.insert(i.to_string());
+            if i % 2 == 0 {
+                slab.remove(k);
+            }
+        }
+        assert_eq!(slab.get(key).expect("is-some") as *const String, ptr);
+    }
+
+    #[test]
+    fn get_mut_index_and_clear() {
+        let mut slab = SplitSlab::with_growth(Linear::new(2));
+        let a = slab.insert(1);
+        let b = slab.insert(2);
+
+        *slab.get_mut(a).expect("is-some") += 10;
+        slab[b] += 20;
+        assert_eq!((slab[a], slab[b]), (11, 22));
+
+        slab.remove(a);
+        assert_eq!(slab.get_mut(a), None);
+        assert_eq!(slab.get_mut(100), None);
+
+        slab.clear();
+        assert!(slab.is_empty());
+        assert_eq!(slab.vacant_key(), 0);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{Doubling, Fragment, Growth, SplitVec};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+/// A string builder backed by a [`SplitVec<u8, G>`].
+///
+/// Bytes of the pushed strings are appended to the split vector; therefore, the builder grows without copying
+/// the already accumulated text, and bytes of the string stay pinned to their memory locations.
+/// This makes it a convenient accumulator for log or text assembly workloads.
+///
+/// Since the builder only accepts `str`s and `char`s, the underlying bytes are always valid UTF-8.
+/// However, bytes of a character might be split across two fragments.
+/// Therefore, the accumulated text is viewed by [`SplitString::as_str_slices`], or it is concatenated
+/// into a `String` once by [`SplitString::into_string`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+/// use std::fmt::Write;
+///
+/// let mut text = SplitString::new();
+/// text.push_str("héllo");
+/// text.push(' ');
+/// write!(text, "wörld {}", 42).unwrap();
+///
+/// assert_eq!(text.len(), 16);
+///
+/// let pieces: String = text.as_str_slices().collect();
+/// assert_eq!(pieces, "héllo wörld 42");
+///
+/// assert_eq!(text.into_string(), "héllo wörld 42");
+/// ```
+#[derive(Clone)]
+pub struct SplitString<G: Growth = Doubling> {
+    bytes: SplitVec<u8, G>,
+}
+
+impl SplitString<Doubling> {
+    /// Creates an empty string builder with the default `Doubling` growth.
+    pub fn new() -> Self {
+        Self {
+            bytes: SplitVec::new(),
+        }
+    }
+}
+
+impl Default for SplitString<Doubling> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Growth> SplitString<G> {
+    /// Creates an empty string builder where the underlying split vector of bytes has the given `growth` strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut text = SplitString::with_growth(Linear::new(4));
+    /// text.push_str("sixteen bytes...");
+    /// text.push_str("and a few more");
+    /// assert_eq!(text.as_bytes().fragments().len(), 2);
+    /// ```
+    pub fn with_growth(growth: G) -> Self {
+        Self {
+            bytes: SplitVec::with_growth(growth),
+        }
+    }
+
+    /// Appends the given string slice to the end of the string.
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Appends the given char to the end of the string.
+    pub fn push(&mut self, c: char) {
+        let mut buffer = [0u8; 4];
+        self.push_str(c.encode_utf8(&mut buffer));
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns whether or not the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Clears the string, removing all contents.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Returns the underlying split vector of the UTF-8 bytes of the string.
+    pub fn as_bytes(&self) -> &SplitVec<u8, G> {
+        &self.bytes
+    }
+
+    /// Returns an iterator of the pieces of the string, which concatenate to the entire string.
+    ///
+    /// Pieces are borrowed from the fragments of the underlying split vector,
+    /// except for the characters whose bytes are split across fragments, which are yielded as owned strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    /// use std::borrow::Cow;
+    ///
+    /// // fragments of 4 bytes
+    /// let mut text = SplitString::with_growth(Linear::new(2));
+    /// text.push_str("abcdé");
+    ///
+    /// let pieces: Vec<_> = text.as_str_slices().collect();
+    /// assert_eq!(pieces, ["abcd", "é"]);
+    ///
+    /// text.clear();
+    /// text.push_str("abcé");
+    /// text.push_str("xyz");
+    ///
+    /// // 'é' is split across the first and second fragments
+    /// let pieces: Vec<_> = text.as_str_slices().collect();
+    /// assert_eq!(pieces, ["abc", "é", "xyz"]);
+    /// assert!(matches!(pieces[1], Cow::Owned(_)));
+    /// ```
+    pub fn as_str_slices(&self) -> impl Iterator<Item = Cow<'_, str>> + '_ {
+        StrSlices::new(&self.bytes.fragments)
+    }
+
+    /// Concatenates the fragments into a `String`, with a single allocation.
+    pub fn into_string(self) -> String {
+        let mut bytes = Vec::with_capacity(self.bytes.len());
+        for fragment in &self.bytes.fragments {
+            bytes.extend_from_slice(fragment);
+        }
+        // SAFETY: only str's and char's are pushed; hence, bytes are valid UTF-8
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<G: Growth> core::fmt::Write for SplitString<G> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl<G: Growth> core::fmt::Display for SplitString<G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for piece in self.as_str_slices() {
+            f.write_str(&piece)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: Growth> core::fmt::Debug for SplitString<G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\"")?;
+        for piece in self.as_str_slices() {
+            write!(f, "{}", piece.escape_debug())?;
+        }
+        write!(f, "\"")
+    }
+}
+
+impl<G: Growth> From<SplitString<G>> for String {
+    fn from(value: SplitString<G>) -> Self {
+        value.into_string()
+    }
+}
+
+struct StrSlices<'a> {
+    fragments: core::slice::Iter<'a, Fragment<u8>>,
+    current: &'a [u8],
+    carry: [u8; 4],
+    carry_len: usize,
+}
+
+impl<'a> StrSlices<'a> {
+    fn new(fragments: &'a [Fragment<u8>]) -> Self {
+        Self {
+            fragments: fragments.iter(),
+            current: &[],
+            carry: [0; 4],
+            carry_len: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for StrSlices<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_empty() {
+                self.current = self.fragments.next()?.as_slice();
+                continue;
+            }
+
+            if self.carry_len > 0 {
+                let width = utf8_char_width(self.carry[0]);
+                let num_taken = (width - self.carry_len).min(self.current.len());
+                let (taken, rest) = self.current.split_at(num_taken);
+                self.carry[self.carry_len..(self.carry_len + num_taken)].copy_from_slice(taken);
+                self.carry_len += num_taken;
+                self.current = rest;
+
+                if self.carry_len == width {
+                    self.carry_len = 0;
+                    // SAFETY: carry contains exactly the bytes of a single char
+                    let c = unsafe { core::str::from_utf8_unchecked(&self.carry[..width]) };
+                    return Some(Cow::Owned(c.into()));
+                }
+                continue;
+            }
+
+            let (complete, incomplete) = self.current.split_at(complete_prefix_len(self.current));
+            self.carry[..incomplete.len()].copy_from_slice(incomplete);
+            self.carry_len = incomplete.len();
+            self.current = &[];
+
+            if !complete.is_empty() {
+                // SAFETY: complete starts and ends at char boundaries of valid UTF-8 bytes
+                return Some(Cow::Borrowed(unsafe {
+                    core::str::from_utf8_unchecked(complete)
+                }));
+            }
+        }
+    }
+}
+
+/// Returns the number of bytes of the char starting with the given leading byte.
+fn utf8_char_width(leading_byte: u8) -> usize {
+    match leading_byte {
+        x if x < 0x80 => 1,
+        x if x >> 5 == 0b110 => 2,
+        x if x >> 4 == 0b1110 => 3,
+        _ => 4,
+    }
+}
+
+/// Returns the length of the prefix of `bytes` which ends at a char boundary,
+/// given that `bytes` starts at a char boundary.
+fn complete_prefix_len(bytes: &[u8]) -> usize {
+    let len = bytes.len();
+    for k in 1..=len.min(3) {
+        let byte = bytes[len - k];
+        let is_leading_byte = byte & 0b1100_0000 != 0b1000_0000;
+        if is_leading_byte {
+            return match utf8_char_width(byte) > k {
+                true => len - k,
+                false => len,
+            };
+        }
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Write;
+
+    const TEXT: &str = "a€ é😀 ßç x漢字 y🎉z!";
+
+    #[test]
+    fn push_and_into_string() {
+        fn test<G: Growth>(vec: SplitVec<u8, G>) {
+            let mut text = SplitString::with_growth(vec.growth().clone());
+            assert!(text.is_empty());
+            assert_eq!(text.as_str_slices().count(), 0);
+
+            let mut expected = String::new();
+            for _ in 0..20 {
+                text.push_str(TEXT);
+                expected.push_str(TEXT);
+                for c in TEXT.chars().rev() {
+                    text.push(c);
+                    expected.push(c);
+                }
+                write!(text, "{}-", expected.len()).expect("is-ok");
+                write!(expected, "{}-", expected.len()).expect("is-ok");
+            }
+
+            assert_eq!(text.len(), expected.len());
+            let pieces: String = text.as_str_slices().collect();
+            assert_eq!(pieces, expected);
+            assert_eq!(alloc::format!("{}", text), expected);
+            assert_eq!(
+                alloc::format!("{:?}", text),
+                alloc::format!("{:?}", expected)
+            );
+            assert_eq!(String::from(text.clone()), expected);
+            assert_eq!(text.into_string(), expected);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn as_str_slices_with_tiny_fragments() {
+        // fragments of 2 bytes split 3 and 4 byte chars across three fragments
+        for prefix_len in 0..4 {
+            let mut text = SplitString::with_growth(Linear::new(1));
+            let mut expected = String::new();
+            for _ in 0..prefix_len {
+                text.push('a');
+                expected.push('a');
+            }
+            text.push_str(TEXT);
+            expected.push_str(TEXT);
+
+            let pieces: Vec<_> = text.as_str_slices().collect();
+            assert!(pieces.iter().all(|x| !x.is_empty()));
+            assert_eq!(pieces.concat(), expected);
+        }
+    }
+
+    #[test]
+    fn as_str_slices_borrows_complete_fragments() {
+        let mut text = SplitString::with_growth(Linear::new(2));
+        text.push_str("abcdefgh");
+        let pieces: Vec<_> = text.as_str_slices().collect();
+        assert_eq!(pieces, ["abcd", "efgh"]);
+        assert!(pieces
+            .iter()
+            .all(|x| matches!(x, alloc::borrow::Cow::Borrowed(_))));
+    }
+}
@@ -1,6 +1,9 @@
-use crate::{fragment::fragment_struct::Fragment, Doubling, Growth};
+use crate::growth_observer::GrowthEvent;
+use crate::{fragment::fragment_struct::Fragment, Doubling, Growth, MemoryPolicy};
+use alloc::collections::VecDeque;
 use alloc::string::String;
 use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
 
 /// A split vector consisting of a vector of fragments.
 ///
@@ -50,21 +53,140 @@ where
     pub(crate) len: usize,
     pub(crate) fragments: Vec<Fragment<T>>,
     pub(crate) growth: G,
+    /// Total capacity of the fragments; kept in sync with `fragments` so that `capacity` is O(1).
+    pub(crate) capacity: usize,
+    /// Empty fragments allocated ahead, in the order they will be added to `fragments`.
+    pub(crate) reserved: VecDeque<Fragment<T>>,
+    /// Policy determining whether the emptied fragments are released or kept in `reserved` for reuse.
+    pub(crate) memory_policy: MemoryPolicy,
+    /// Generation of the positions of the elements; incremented by the operations moving or removing elements.
+    pub(crate) generation: usize,
+    /// Callback invoked whenever a fragment is added to or removed from the vector.
+    #[cfg(feature = "growth_observer")]
+    pub(crate) growth_observer: Option<crate::growth_observer::GrowthObserver>,
 }
 
 impl<T, G> SplitVec<T, G>
 where
     G: Growth,
 {
-    pub(crate) fn from_raw_parts(len: usize, fragments: Vec<Fragment<T>>, growth: G) -> Self {
+    /// Creates a split vector from its parts; the fragments must satisfy the structure described in [`SplitVec::from_raw_parts`].
+    pub(crate) fn from_parts(len: usize, fragments: Vec<Fragment<T>>, growth: G) -> Self {
         debug_assert_eq!(len, fragments.iter().map(|x| x.len()).sum());
+        let capacity = fragments.iter().map(|x| x.capacity()).sum();
         Self {
             len,
             fragments,
             growth,
+            capacity,
+            reserved: VecDeque::new(),
+            memory_policy: MemoryPolicy::default(),
+            generation: 0,
+            #[cfg(feature = "growth_observer")]
+            growth_observer: None,
         }
     }
 
+    /// Creates a split vector directly from its raw parts: the length `len`, the `fragments` and the `growth` strategy.
+    ///
+    /// The fragments are adopted as they are; hence, no element is copied.
+    /// Together with [`SplitVec::into_raw_parts`], this allows downstream collections to deconstruct and reconstruct
+    /// split vectors without copies.
+    ///
+    /// The structure of the parts listed below is validated by debug assertions.
+    ///
+    /// # Safety
+    ///
+    /// The parts must satisfy the following structure:
+    /// * `len` is equal to the sum of the lengths of the fragments;
+    /// * all fragments have a positive capacity;
+    /// * the fragments follow the growth strategy, such that the capacity of each fragment is the capacity the growth
+    ///   would create given the preceding fragments; `Recursive` growth accepts fragments of any capacity;
+    /// * none of the fragments but the last one has room, unless the growth is `Recursive`.
+    ///
+    /// Parts obtained by [`SplitVec::into_raw_parts`] satisfy this structure.
+    /// Breaking this structure invalidates the `SplitVec` struct, and its methods lead to UB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..10).collect();
+    /// let pointers: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+    ///
+    /// let (len, fragments, growth) = vec.into_raw_parts();
+    /// assert_eq!(len, 10);
+    /// assert_eq!(fragments.len(), 2);
+    ///
+    /// let vec = unsafe { SplitVec::from_raw_parts(len, fragments, growth) };
+    /// assert_eq!(vec, (0..10).collect::<Vec<_>>());
+    /// assert_eq!(vec.fragments()[1].as_ptr(), pointers[1]);
+    /// ```
+    pub unsafe fn from_raw_parts(len: usize, fragments: Vec<Fragment<T>>, growth: G) -> Self {
+        debug_assert!(fragments.iter().all(|x| x.capacity() > 0));
+        debug_assert_eq!(
+            crate::growth::conformance::fragments_conformance_check(&growth, &fragments),
+            Ok(())
+        );
+        Self::from_parts(len, fragments, growth)
+    }
+
+    /// Decomposes the split vector into its raw parts: the length, the fragments and the growth strategy.
+    ///
+    /// The vector can be reconstructed without copies by [`SplitVec::from_raw_parts`].
+    ///
+    /// Note that the parts do not contain the entire state of the vector:
+    /// * the reserved fragments allocated ahead, if any, are released;
+    /// * the memory policy is dropped, and the reconstructed vector uses the default [`MemoryPolicy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..6);
+    ///
+    /// let (len, fragments, growth) = vec.into_raw_parts();
+    /// assert_eq!(len, 6);
+    /// assert_eq!(fragments.len(), 2);
+    /// assert_eq!(fragments[0], [0, 1, 2, 3]);
+    /// assert_eq!(fragments[1], [4, 5]);
+    /// assert_eq!(growth, Linear::new(2));
+    /// ```
+    pub fn into_raw_parts(self) -> (usize, Vec<Fragment<T>>, G) {
+        (self.len, self.fragments, self.growth)
+    }
+
+    /// Recomputes the cached total capacity from the fragments.
+    ///
+    /// Must be called after the fragments are added or removed in bulk.
+    pub(crate) fn refresh_capacity(&mut self) {
+        self.capacity = self.fragments.iter().map(|x| x.capacity()).sum();
+    }
+
+    /// Pushes the `fragment` to the end of the fragments, and updates the cached capacity.
+    pub(crate) fn push_fragment(&mut self, fragment: Fragment<T>) {
+        self.on_growth_event(GrowthEvent::FragmentAdded {
+            fragment_index: self.fragments.len(),
+            capacity: fragment.capacity(),
+        });
+        self.capacity += fragment.capacity();
+        self.fragments.push(fragment);
+    }
+
+    /// Pops the last fragment, and updates the cached capacity.
+    pub(crate) fn pop_fragment(&mut self) -> Option<Fragment<T>> {
+        let fragment = self.fragments.pop()?;
+        self.on_growth_event(GrowthEvent::FragmentRemoved {
+            fragment_index: self.fragments.len(),
+            capacity: fragment.capacity(),
+        });
+        self.capacity -= fragment.capacity();
+        Some(fragment)
+    }
+
     // get
     /// Growth strategy of the split vector.
     ///
@@ -84,6 +206,28 @@ where
         &self.growth
     }
 
+    /// Returns the total capacity of the empty fragments which are allocated ahead, such as by [`SplitVec::with_capacity`],
+    /// and will be used as the vector grows.
+    ///
+    /// Note that this capacity is not included in [`capacity`] which reports the capacity of the fragments in use.
+    ///
+    /// [`capacity`]: orx_pinned_vec::PinnedVec::capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<char> = SplitVec::with_capacity(10);
+    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(vec.reserved_capacity(), 8);
+    /// ```
+    pub fn reserved_capacity(&self) -> usize {
+        self.reserved
+            .iter()
+            .fold(0, |x, f| x.saturating_add(f.capacity()))
+    }
+
     /// Returns a mutable reference to the vector of fragments.
     ///
     /// # Safety
@@ -95,6 +239,8 @@ where
     /// * if there exist F fragments in the vector:
     ///     * none of the fragments with indices `0..F-2` has capacity; i.e., len==capacity,
     ///     * the last fragment at position `F-1` might or might not have capacity.
+    /// * the total capacity of the fragments is cached by the split vector;
+    ///   therefore, fragments must not be added or removed, and their capacities must not change.
     ///
     /// Breaking this structure invalidates the `SplitVec` struct,
     /// and its methods lead to UB.
@@ -132,6 +278,191 @@ where
         &self.fragments
     }
 
+    /// Returns the number of fragments of the split vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// assert_eq!(vec.fragment_count(), 1);
+    ///
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragment_count(), 2);
+    /// ```
+    pub fn fragment_count(&self) -> usize {
+        self.fragments.len()
+    }
+
+    /// Returns an iterator over the fragments of the split vector yielding a `(fragment_index, slice, capacity)` tuple
+    /// for each fragment, where `slice` is the filled portion of the fragment.
+    ///
+    /// This allows writing fragment-aware algorithms working on the contiguous slices of the vector
+    /// without accessing the fragments themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let fragments: Vec<_> = vec.fragment_slices().collect();
+    /// assert_eq!(fragments, [(0, &[0, 1, 2, 3][..], 4), (1, &[4, 5][..], 4)]);
+    ///
+    /// let sum_of_maximums: i32 = vec
+    ///     .fragment_slices()
+    ///     .filter_map(|(_, slice, _)| slice.iter().max())
+    ///     .sum();
+    /// assert_eq!(sum_of_maximums, 3 + 5);
+    /// ```
+    pub fn fragment_slices(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (usize, &[T], usize)> + DoubleEndedIterator + '_ {
+        self.fragments
+            .iter()
+            .enumerate()
+            .map(|(f, fragment)| (f, fragment.as_slice(), fragment.capacity()))
+    }
+
+    /// Returns the filled portion of the first fragment of the split vector;
+    /// returns an empty slice if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// assert!(vec.first_slice().is_empty());
+    ///
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.first_slice(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn first_slice(&self) -> &[T] {
+        self.fragments.first().map(|x| x.as_slice()).unwrap_or(&[])
+    }
+
+    /// Returns the filled portion of the first fragment of the split vector as a mutable slice;
+    /// returns an empty slice if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// vec.first_slice_mut()[1] = 10;
+    /// assert_eq!(vec, &[0, 10, 2, 3, 4, 5]);
+    /// ```
+    pub fn first_slice_mut(&mut self) -> &mut [T] {
+        self.fragments
+            .first_mut()
+            .map(|x| x.as_mut_slice())
+            .unwrap_or(&mut [])
+    }
+
+    /// Returns the filled portion of the last fragment of the split vector;
+    /// returns an empty slice if the vector is empty.
+    ///
+    /// Note that the last fragment might be empty when a new fragment has just been allocated,
+    /// in which case an empty slice is returned although the vector is not empty.
+    ///
+    /// This is convenient for processing the most recently added elements, such as elements added since the last flush,
+    /// without computing ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// assert!(vec.last_slice().is_empty());
+    ///
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.last_slice(), &[4, 5]);
+    /// ```
+    pub fn last_slice(&self) -> &[T] {
+        self.fragments.last().map(|x| x.as_slice()).unwrap_or(&[])
+    }
+
+    /// Returns the filled portion of the last fragment of the split vector as a mutable slice;
+    /// returns an empty slice if the vector is empty.
+    ///
+    /// Note that the last fragment might be empty when a new fragment has just been allocated,
+    /// in which case an empty slice is returned although the vector is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// vec.last_slice_mut().iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(vec, &[0, 1, 2, 3, 40, 50]);
+    /// ```
+    pub fn last_slice_mut(&mut self) -> &mut [T] {
+        self.fragments
+            .last_mut()
+            .map(|x| x.as_mut_slice())
+            .unwrap_or(&mut [])
+    }
+
+    /// Returns a reference to the `i`-th element from the end of the vector, where `from_end(0)` is the last element;
+    /// returns None if `i` is out of bounds.
+    ///
+    /// Time complexity is the same as that of `get`; i.e., ***O(1)*** for growth strategies with constant time access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// assert_eq!(vec.from_end(0), None);
+    ///
+    /// vec.extend_from_slice(&[0, 1, 2, 3]);
+    /// assert_eq!(vec.from_end(0), Some(&3));
+    /// assert_eq!(vec.from_end(3), Some(&0));
+    /// assert_eq!(vec.from_end(4), None);
+    /// ```
+    pub fn from_end(&self, i: usize) -> Option<&T> {
+        match i < self.len {
+            true => self.get(self.len - 1 - i),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the `i`-th element from the end of the vector, where `from_end_mut(0)` is the last element;
+    /// returns None if `i` is out of bounds.
+    ///
+    /// Time complexity is the same as that of `get_mut`; i.e., ***O(1)*** for growth strategies with constant time access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// vec.extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// *vec.from_end_mut(1).unwrap() = 42;
+    /// assert_eq!(vec, &[0, 1, 42, 3]);
+    /// assert_eq!(vec.from_end_mut(4), None);
+    /// ```
+    pub fn from_end_mut(&mut self, i: usize) -> Option<&mut T> {
+        match i < self.len {
+            true => self.get_mut(self.len - 1 - i),
+            false => None,
+        }
+    }
+
     /// Maximum capacity that can safely be reached by the vector in a concurrent program.
     /// This value is often related with the capacity of the container holding meta information about allocations.
     /// Note that the split vector can naturally grow beyond this number, this bound is only relevant when the vector is `Sync`ed among threads.
@@ -210,6 +541,68 @@ where
             .get_fragment_and_inner_indices(self.len, &self.fragments, index)
     }
 
+    /// Returns the index of the fragment that the element at the given `index` belongs to;
+    /// returns None if the `index` is out of bounds.
+    ///
+    /// Time complexity is ***O(1)*** for growth strategies with constant time access, and ***O(f)*** otherwise
+    /// where f is the number of fragments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(vec.fragment_of(0), Some(0));
+    /// assert_eq!(vec.fragment_of(3), Some(0));
+    /// assert_eq!(vec.fragment_of(4), Some(1));
+    /// assert_eq!(vec.fragment_of(6), None);
+    /// ```
+    pub fn fragment_of(&self, index: usize) -> Option<usize> {
+        self.get_fragment_and_inner_indices(index).map(|(f, _)| f)
+    }
+
+    /// Returns the total capacity of the fragments before the `f`-th fragment.
+    ///
+    /// Note that the result is a capacity rather than an element index. It is equal to the index of the first
+    /// element of the `f`-th fragment only when all preceding fragments are full, which is guaranteed for growth
+    /// strategies such as `Doubling` and `Linear`, but not for `Recursive` whose appended fragments may have room.
+    ///
+    /// If `f` is greater than the number of fragments, the capacities of the fragments which are not yet allocated
+    /// are computed by the growth strategy of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments().len(), 2);
+    ///
+    /// assert_eq!(vec.capacity_up_to_fragment(0), 0);
+    /// assert_eq!(vec.capacity_up_to_fragment(1), 4);
+    /// assert_eq!(vec.capacity_up_to_fragment(2), 4 + 8);
+    /// assert_eq!(vec.capacity_up_to_fragment(4), 4 + 8 + 16 + 32);
+    /// ```
+    pub fn capacity_up_to_fragment(&self, f: usize) -> usize {
+        match f <= self.fragments.len() {
+            true => self.fragments[..f].iter().map(|x| x.capacity()).sum(),
+            false => {
+                let mut capacities: Vec<_> = self.fragments.iter().map(|x| x.capacity()).collect();
+                while capacities.len() < f {
+                    let capacity = self
+                        .growth
+                        .new_fragment_capacity_from(capacities.iter().copied());
+                    capacities.push(capacity);
+                }
+                capacities.iter().sum()
+            }
+        }
+    }
+
     // helpers
 
     #[inline(always)]
@@ -233,27 +626,83 @@ where
     fn add_fragment_get_fragment_capacity(&mut self, zeroed: bool) -> usize {
         let new_fragment_capacity = self.growth.new_fragment_capacity(&self.fragments);
 
-        let mut new_fragment = Fragment::new(new_fragment_capacity);
+        let mut new_fragment = self.new_fragment(new_fragment_capacity);
         if zeroed {
             // SAFETY: new_fragment empty with len=0, zeroed elements will not be read with safe api
             unsafe { new_fragment.zero() };
         }
 
-        self.fragments.push(new_fragment);
+        self.push_fragment(new_fragment);
 
         new_fragment_capacity
     }
 
     pub(crate) fn add_fragment_with_first_value(&mut self, first_value: T) {
         let capacity = self.growth.new_fragment_capacity(&self.fragments);
-        let new_fragment = Fragment::new_with_first_value(capacity, first_value);
-        self.fragments.push(new_fragment);
+        let mut new_fragment = self.new_fragment(capacity);
+        new_fragment.push(first_value);
+        self.push_fragment(new_fragment);
+    }
+
+    /// Returns an empty fragment with the given `capacity`; the next reserved fragment is used if it has the
+    /// required capacity, a new fragment is allocated otherwise.
+    ///
+    /// Reserved fragments which do not match the growth anymore, due to fragments being removed or the growth
+    /// being changed, are released.
+    fn new_fragment(&mut self, capacity: usize) -> Fragment<T> {
+        match self.reserved.front().map(|x| x.capacity()) {
+            Some(c) if c == capacity => self.reserved.pop_front().expect("is-some"),
+            Some(_) => {
+                self.reserved.clear();
+                Fragment::new(capacity)
+            }
+            None => Fragment::new(capacity),
+        }
+    }
+
+    /// Allocates empty fragments ahead, following the growth strategy, until the total capacity of the
+    /// fragments and the reserved fragments reaches `required_capacity`.
+    pub(crate) fn reserve_fragments(&mut self, required_capacity: usize) {
+        self.release_outdated_reserved();
+        let mut total = self.capacity.saturating_add(self.reserved_capacity());
+        if total >= required_capacity {
+            return;
+        }
+
+        let mut capacities: Vec<_> = self.fragments.iter().map(|x| x.capacity()).collect();
+        capacities.extend(self.reserved.iter().map(|x| x.capacity()));
+        while total < required_capacity {
+            let capacity = self
+                .growth
+                .new_fragment_capacity_from(capacities.iter().copied());
+            self.reserved.push_back(Fragment::new(capacity));
+            capacities.push(capacity);
+            total = total.saturating_add(capacity);
+        }
+    }
+
+    /// Releases the reserved fragments which do not follow the growth strategy anymore, due to fragments being
+    /// removed or the growth being changed; so that the remaining reserved fragments can be directly added to the fragments.
+    pub(crate) fn release_outdated_reserved(&mut self) {
+        let mut capacities: Vec<_> = self.fragments.iter().map(|x| x.capacity()).collect();
+        let mut num_valid = 0;
+        for fragment in &self.reserved {
+            let capacity = self
+                .growth
+                .new_fragment_capacity_from(capacities.iter().copied());
+            if capacity != fragment.capacity() {
+                break;
+            }
+            capacities.push(capacity);
+            num_valid += 1;
+        }
+        self.reserved.truncate(num_valid);
     }
 
     pub(crate) fn drop_last_empty_fragment(&mut self) {
         let drop_empty_last_fragment = self.fragments.last().map(|f| f.is_empty()).unwrap_or(false);
         if drop_empty_last_fragment {
-            _ = self.fragments.pop();
+            self.release_fragments_after(self.fragments.len() - 1);
         }
     }
 
@@ -298,6 +747,26 @@ mod tests {
     use crate::*;
     use alloc::vec;
 
+    #[test]
+    fn fragment_slices() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for i in 0..142 {
+                vec.push(i);
+                assert_eq!(vec.fragment_count(), vec.fragments().len());
+
+                let mut concat = alloc::vec::Vec::new();
+                for (f, slice, capacity) in vec.fragment_slices() {
+                    assert_eq!(slice, vec.fragments()[f].as_slice());
+                    assert_eq!(capacity, vec.fragments()[f].capacity());
+                    concat.extend_from_slice(slice);
+                }
+                assert_eq!(vec, concat);
+                assert_eq!(vec.fragment_slices().len(), vec.fragment_count());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
     #[test]
     fn fragments() {
         fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
@@ -323,6 +792,131 @@ mod tests {
         test_all_growth_types!(test);
     }
 
+    #[test]
+    fn cached_capacity() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let expected = |vec: &SplitVec<usize, G>| -> usize {
+                vec.fragments().iter().map(|x| x.capacity()).sum()
+            };
+            assert_eq!(vec.capacity(), expected(&vec));
+
+            vec.extend(0..100);
+            assert_eq!(vec.capacity(), expected(&vec));
+
+            vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            vec.insert(3, 42);
+            assert_eq!(vec.capacity(), expected(&vec));
+
+            for _ in 0..20 {
+                vec.pop();
+                vec.remove(0);
+                assert_eq!(vec.capacity(), expected(&vec));
+            }
+
+            vec.truncate(30);
+            assert_eq!(vec.capacity(), expected(&vec));
+
+            vec.adopt(vec![1, 2, 3]);
+            assert_eq!(vec.capacity(), expected(&vec));
+
+            vec.clear();
+            assert_eq!(vec.capacity(), expected(&vec));
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn first_last_slices() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert!(vec.first_slice().is_empty());
+            assert!(vec.last_slice().is_empty());
+            assert!(vec.first_slice_mut().is_empty());
+            assert!(vec.last_slice_mut().is_empty());
+
+            for i in 0..42 {
+                vec.push(i);
+
+                let first = vec.fragments().first().expect("is-some").as_slice();
+                assert_eq!(vec.first_slice(), first);
+                let last = vec.fragments().last().expect("is-some").as_slice();
+                assert_eq!(vec.last_slice(), last);
+                assert_eq!(vec.last_slice().last(), Some(&i));
+            }
+
+            vec.first_slice_mut()[0] = 100;
+            let last_len = vec.last_slice().len();
+            vec.last_slice_mut()[last_len - 1] = 200;
+
+            assert_eq!(vec[0], 100);
+            assert_eq!(vec[41], 200);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn from_end() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.from_end(0), None);
+            assert_eq!(vec.from_end_mut(0), None);
+
+            for i in 0..142 {
+                vec.push(i);
+                assert_eq!(vec.from_end(0), Some(&i));
+                assert_eq!(vec.from_end(i), Some(&0));
+                assert_eq!(vec.from_end(i + 1), None);
+            }
+
+            for i in 0..142 {
+                *vec.from_end_mut(i).expect("is-some") += 1000;
+                assert_eq!(vec[141 - i], 141 - i + 1000);
+            }
+            assert_eq!(vec.from_end_mut(142), None);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn fragment_of_and_capacity_up_to_fragment() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.fragment_of(0), None);
+            assert_eq!(vec.capacity_up_to_fragment(0), 0);
+
+            for i in 0..432 {
+                vec.push(i);
+            }
+
+            for i in 0..432 {
+                let f = vec.fragment_of(i).expect("is-some");
+                assert_eq!(f, vec.get_fragment_and_inner_indices(i).expect("is-some").0);
+
+                let begin = vec.capacity_up_to_fragment(f);
+                let end = vec.capacity_up_to_fragment(f + 1);
+                assert!(begin <= i && i < end);
+            }
+            assert_eq!(vec.fragment_of(432), None);
+
+            let num_fragments = vec.fragments().len();
+            assert_eq!(vec.capacity_up_to_fragment(num_fragments), vec.capacity());
+
+            let expected = vec.capacity() + vec.growth().new_fragment_capacity(vec.fragments());
+            assert_eq!(vec.capacity_up_to_fragment(num_fragments + 1), expected);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn capacity_up_to_fragment_with_non_full_fragments() {
+        let mut first = alloc::vec::Vec::with_capacity(8);
+        first.extend_from_slice(&[0, 1, 2]);
+        let mut vec: SplitVec<usize, Recursive> = first.into();
+        vec.append(vec![3, 4]);
+
+        assert_eq!(vec.fragments()[0].capacity(), 8);
+        assert_eq!(vec.capacity_up_to_fragment(1), 8);
+        assert_eq!(vec.fragment_of(3), Some(1));
+        assert_eq!(vec.get_fragment_and_inner_indices(3), Some((1, 0)));
+    }
+
     #[test]
     fn get_fragment_and_inner_indices() {
         fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
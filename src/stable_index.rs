@@ -0,0 +1,183 @@
+use crate::{Growth, SplitVec};
+use orx_pinned_vec::PinnedVec;
+
+/// A handle to a position of a split vector which is validated by the generation of the vector.
+///
+/// The split vector keeps a generation counter which is incremented by the operations that move or remove elements,
+/// such as `insert`, `remove`, `pop`, `swap`, `truncate`, `clear`, `drain`, `retain` or `sort`.
+/// Growing the vector by `push` or `extend` does not change the generation, since the elements are pinned.
+///
+/// Therefore, a stable index created by [`SplitVec::stable_index`] refers to the same element as long as the
+/// generation of the vector is unchanged; and [`SplitVec::checked_get`] detects the invalidation otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StableIndex {
+    index: usize,
+    generation: usize,
+}
+
+impl StableIndex {
+    /// Returns the position of the element in the vector at the time the handle was created.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the vector at the time the handle was created.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Increments the generation of the vector, invalidating all stable indices created so far.
+    #[inline(always)]
+    pub(crate) fn invalidate_stable_indices(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns the current generation of the positions of the elements of the vector.
+    ///
+    /// The generation is incremented by the operations that move or remove elements; see [`StableIndex`].
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Creates a [`StableIndex`] handle to the element at the given `index`; returns None if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(['a', 'b', 'c']);
+    ///
+    /// let b = vec.stable_index(1).unwrap();
+    /// assert_eq!(vec.checked_get(b), Some(&'b'));
+    ///
+    /// // growth does not invalidate the handle
+    /// vec.extend(['d', 'e', 'f', 'g']);
+    /// assert_eq!(vec.checked_get(b), Some(&'b'));
+    ///
+    /// // moving elements does
+    /// vec.insert(0, 'x');
+    /// assert_eq!(vec.checked_get(b), None);
+    ///
+    /// let b = vec.stable_index(2).unwrap();
+    /// assert_eq!(vec.checked_get(b), Some(&'b'));
+    ///
+    /// assert_eq!(vec.stable_index(8), None);
+    /// ```
+    pub fn stable_index(&self, index: usize) -> Option<StableIndex> {
+        match index < self.len {
+            true => Some(StableIndex {
+                index,
+                generation: self.generation,
+            }),
+            false => None,
+        }
+    }
+
+    /// Returns whether or not the `stable_index` is still valid; i.e., whether the vector is not modified by an operation
+    /// moving or removing elements since the handle is created.
+    pub fn is_valid(&self, stable_index: StableIndex) -> bool {
+        stable_index.generation == self.generation && stable_index.index < self.len
+    }
+
+    /// Returns a reference to the element of the `stable_index`; returns None if the handle is invalidated.
+    ///
+    /// See [`SplitVec::stable_index`] for an example.
+    pub fn checked_get(&self, stable_index: StableIndex) -> Option<&T> {
+        match self.is_valid(stable_index) {
+            true => self.get(stable_index.index),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element of the `stable_index`; returns None if the handle is invalidated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend([1, 2, 3]);
+    ///
+    /// let idx = vec.stable_index(2).unwrap();
+    /// *vec.checked_get_mut(idx).unwrap() *= 10;
+    /// assert_eq!(vec, [1, 2, 30]);
+    ///
+    /// vec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec.checked_get_mut(idx), None);
+    /// ```
+    pub fn checked_get_mut(&mut self, stable_index: StableIndex) -> Option<&mut T> {
+        match self.is_valid(stable_index) {
+            true => self.get_mut(stable_index.index),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn stable_index_survives_growth() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..10);
+            let indices: Vec<_> = (0..10)
+                .map(|i| vec.stable_index(i).expect("is-some"))
+                .collect();
+
+            vec.extend(10..500);
+            vec.push(500);
+            *vec.get_mut(3).expect("is-some") += 1000;
+
+            for (i, idx) in indices.iter().enumerate() {
+                let expected = if i == 3 { 1003 } else { i };
+                assert_eq!(vec.checked_get(*idx), Some(&expected));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn stable_index_invalidated_by_moves() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let operations: [fn(&mut SplitVec<usize, G>); 14] = [
+                |v| v.insert(0, 42),
+                |v| _ = v.remove(0),
+                |v| _ = v.pop(),
+                |v| v.swap(0, 1),
+                |v| v.truncate(5),
+                |v| v.clear(),
+                |v| v.sort(),
+                |v| v.sort_unstable(),
+                |v| v.retain(|x| x % 2 == 0),
+                |v| v.reverse(),
+                |v| v.remove_range(1..3),
+                |v| v.insert_slice(1, &[1, 2]),
+                |v| v.clear_keeping_capacity(),
+                |v| v.rotate_left(3),
+            ];
+
+            for operation in operations {
+                vec.clear();
+                vec.extend(0..20);
+                let idx = vec.stable_index(0).expect("is-some");
+                assert!(vec.is_valid(idx));
+
+                operation(&mut vec);
+                assert!(!vec.is_valid(idx));
+                assert_eq!(vec.checked_get(idx), None);
+                assert_eq!(vec.checked_get_mut(idx), None);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -5,6 +5,7 @@ macro_rules! test_all_growth_types {
     ($fun:tt) => {
         $fun::<$crate::Linear>(SplitVec::with_linear_growth(2));
         $fun::<$crate::Doubling>(SplitVec::with_doubling_growth());
+        $fun::<$crate::DoublingUntil>(SplitVec::with_doubling_until_growth(4));
         $fun::<$crate::Recursive>(SplitVec::with_recursive_growth());
     };
 }
@@ -0,0 +1,119 @@
+use crate::{
+    range_helpers::{range_end, range_start},
+    Growth, SplitVec,
+};
+use core::ops::RangeBounds;
+
+impl<T, G> SplitVec<T, G>
+where
+    G: Growth,
+{
+    /// Calls `f` on each of the mutable slices which, when chained, form the required `range` of the vector.
+    ///
+    /// This is the allocation-free alternative of iterating over `slices_mut(range)`:
+    /// slices are passed to the closure fragment by fragment rather than being collected into a `Vec`,
+    /// and the mutable borrows do not escape the closure.
+    ///
+    /// Similar to `slices_mut`, the closure is never called if the range is empty or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let mut num_slices = 0;
+    /// vec.visit_mut(3..9, |slice| {
+    ///     num_slices += 1;
+    ///     for x in slice {
+    ///         *x *= 10;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(num_slices, 3);
+    /// assert_eq!(vec, &[0, 1, 2, 30, 40, 50, 60, 70, 80, 9]);
+    /// ```
+    pub fn visit_mut<R, F>(&mut self, range: R, mut f: F)
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut [T]),
+    {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len);
+
+        if b <= a {
+            return;
+        }
+
+        let (Some((sf, si)), Some((ef, ei))) = (
+            self.get_fragment_and_inner_indices(a),
+            self.get_fragment_and_inner_indices(b - 1),
+        ) else {
+            return;
+        };
+
+        match sf == ef {
+            true => f(&mut self.fragments[sf][si..=ei]),
+            false => {
+                f(&mut self.fragments[sf][si..]);
+                for fragment in &mut self.fragments[(sf + 1)..ef] {
+                    f(fragment);
+                }
+                f(&mut self.fragments[ef][..=ei]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn visit_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut num_calls = 0;
+            vec.visit_mut(.., |_| num_calls += 1);
+            assert_eq!(num_calls, 0);
+
+            for i in 0..184 {
+                vec.push(i);
+            }
+
+            for (a, b) in [(0, 184), (0, 1), (3, 4), (3, 60), (10, 184), (150, 184)] {
+                let expected_num_slices = vec.slices_mut(a..b).len();
+                let mut num_slices = 0;
+
+                vec.visit_mut(a..b, |slice| {
+                    num_slices += 1;
+                    for x in slice {
+                        *x += 1000;
+                    }
+                });
+
+                assert_eq!(num_slices, expected_num_slices);
+                for i in 0..vec.len() {
+                    let expected = match (a..b).contains(&i) {
+                        true => i + 1000,
+                        false => i,
+                    };
+                    assert_eq!(vec[i], expected);
+                }
+
+                vec.visit_mut(a..b, |slice| slice.iter_mut().for_each(|x| *x -= 1000));
+                assert_eq!(vec, (0..184).collect::<Vec<_>>());
+            }
+
+            #[allow(clippy::reversed_empty_ranges)]
+            for range in [(4..4), (5..4), (100..185), (184..200)] {
+                vec.visit_mut(range, |_| num_calls += 1);
+            }
+            assert_eq!(num_calls, 0);
+        }
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{Fragment, Growth, SplitVec};
+use core::iter::FusedIterator;
+
+/// A window of consecutive elements of a split vector, which might straddle fragment boundaries.
+///
+/// The window is a view over the memory of the vector; no element is copied to create a window.
+///
+/// This enum is yielded by the iterator created by `SplitVec::windows()` method.
+#[derive(Debug)]
+pub enum Window<'a, T> {
+    /// The window completely belongs to one fragment.
+    Contiguous(&'a [T]),
+    /// The window is composed of the end of a fragment followed by the beginning of the next fragment.
+    Split(&'a [T], &'a [T]),
+    /// The window spans more than two fragments: the end of a fragment, all elements of the `middle` fragments,
+    /// and the beginning of the following fragment.
+    Spanning {
+        /// End of the first fragment of the window.
+        first: &'a [T],
+        /// Fragments completely belonging to the window.
+        middle: &'a [Fragment<T>],
+        /// Beginning of the last fragment of the window.
+        last: &'a [T],
+    },
+}
+
+impl<T> Clone for Window<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Window<'_, T> {}
+
+impl<'a, T> Window<'a, T> {
+    fn parts(&self) -> (&'a [T], &'a [Fragment<T>], &'a [T]) {
+        match *self {
+            Self::Contiguous(slice) => (slice, &[], &[]),
+            Self::Split(first, last) => (first, &[], last),
+            Self::Spanning {
+                first,
+                middle,
+                last,
+            } => (first, middle, last),
+        }
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize {
+        let (first, middle, last) = self.parts();
+        first.len() + middle.iter().map(|x| x.len()).sum::<usize>() + last.len()
+    }
+
+    /// Returns true if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the `index`-th element of the window; None if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let (first, middle, last) = self.parts();
+        let mut index = index;
+        for slice in core::iter::once(first)
+            .chain(middle.iter().map(|x| x.as_slice()))
+            .chain(core::iter::once(last))
+        {
+            match index < slice.len() {
+                true => return Some(&slice[index]),
+                false => index -= slice.len(),
+            }
+        }
+        None
+    }
+
+    /// Returns an iterator over the elements of the window.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T> {
+        let (first, middle, last) = self.parts();
+        first
+            .iter()
+            .chain(middle.iter().flat_map(|x| x.iter()))
+            .chain(last.iter())
+    }
+}
+
+/// An iterator over overlapping windows of length `n` of a split vector.
+///
+/// This struct is created by `SplitVec::windows()` method.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<'a, T> {
+    fragments: &'a [Fragment<T>],
+    /// Fragment and inner indices of the first element of the next window.
+    position: (usize, usize),
+    window_len: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    fn new(fragments: &'a [Fragment<T>], len: usize, window_len: usize) -> Self {
+        let mut windows = Self {
+            fragments,
+            position: (0, 0),
+            window_len,
+            remaining: (len + 1).saturating_sub(window_len),
+        };
+        windows.skip_exhausted_fragments();
+        windows
+    }
+
+    fn skip_exhausted_fragments(&mut self) {
+        let (mut f, mut i) = self.position;
+        while f < self.fragments.len() && i == self.fragments[f].len() {
+            (f, i) = (f + 1, 0);
+        }
+        self.position = (f, i);
+    }
+
+    fn window_at(&self, (f, i): (usize, usize)) -> Window<'a, T> {
+        let first = &self.fragments[f][i..];
+        if first.len() >= self.window_len {
+            return Window::Contiguous(&first[..self.window_len]);
+        }
+
+        let mut required = self.window_len - first.len();
+        let mut g = f + 1;
+        while self.fragments[g].len() < required {
+            required -= self.fragments[g].len();
+            g += 1;
+        }
+
+        let middle = &self.fragments[(f + 1)..g];
+        let last = &self.fragments[g][..required];
+        match middle.iter().all(|x| x.is_empty()) {
+            true => Window::Split(first, last),
+            false => Window::Spanning {
+                first,
+                middle,
+                last,
+            },
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Window<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            0 => None,
+            _ => {
+                let window = self.window_at(self.position);
+                self.remaining -= 1;
+                self.position.1 += 1;
+                self.skip_exhausted_fragments();
+                Some(window)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+impl<T, G: Growth> SplitVec<T, G> {
+    /// Returns an iterator over all contiguous windows of length `n`, which overlap.
+    /// If the vector is shorter than `n`, the iterator returns no values.
+    ///
+    /// Since a window might straddle fragment boundaries, each window is yielded as a [`Window`] which is
+    /// a view over one or more fragments; no element is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend([0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments()[0], &[0, 1, 2, 3]);
+    ///
+    /// let mut windows = vec.windows(3);
+    /// assert_eq!(windows.len(), 4);
+    ///
+    /// let window = windows.next().unwrap();
+    /// assert!(matches!(window, Window::Contiguous(&[0, 1, 2])));
+    ///
+    /// let window = windows.nth(1).unwrap();
+    /// assert!(matches!(window, Window::Split(&[2, 3], &[4])));
+    /// assert_eq!(window.len(), 3);
+    /// assert_eq!(window.get(2), Some(&4));
+    /// assert_eq!(window.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    ///
+    /// let sums: Vec<i32> = vec.windows(2).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, [1, 3, 5, 7, 9]);
+    ///
+    /// assert_eq!(vec.windows(7).count(), 0);
+    /// ```
+    pub fn windows(&self, n: usize) -> Windows<'_, T> {
+        assert!(n != 0, "window size must be non-zero");
+        Windows::new(&self.fragments, self.len, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn windows() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            assert_eq!(vec.windows(1).count(), 0);
+
+            let n = 157;
+            vec.extend(0..n);
+            let std_vec: Vec<_> = (0..n).collect();
+
+            for window_len in [1, 2, 3, 4, 5, 17, 40, n - 1, n, n + 1] {
+                let windows = vec.windows(window_len);
+                let expected = std_vec.windows(window_len);
+                assert_eq!(windows.len(), expected.len());
+
+                for (window, expected) in windows.zip(expected) {
+                    assert_eq!(window.len(), window_len);
+                    assert_eq!(window.iter().copied().collect::<Vec<_>>(), expected);
+                    assert_eq!(window.iter().rev().count(), window_len);
+                    for (i, x) in expected.iter().enumerate() {
+                        assert_eq!(window.get(i), Some(x));
+                    }
+                    assert_eq!(window.get(window_len), None);
+                }
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn windows_spanning_fragments() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(0..12);
+
+        let window = vec.windows(10).next().expect("has-window");
+        assert!(matches!(
+            window,
+            Window::Spanning { first, middle, last } if first.len() == 4 && middle.len() == 1 && last.len() == 2
+        ));
+        assert_eq!(
+            window.iter().copied().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn windows_with_empty_fragments() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.append((0..3).collect::<Vec<_>>());
+        vec.append(Vec::<usize>::new());
+        vec.append((3..6).collect::<Vec<_>>());
+
+        let windows: Vec<Vec<_>> = vec
+            .windows(4)
+            .map(|w| w.iter().copied().collect())
+            .collect();
+        assert_eq!(windows, [[0, 1, 2, 3], [1, 2, 3, 4], [2, 3, 4, 5]]);
+
+        let window = vec.windows(4).next().expect("has-window");
+        assert!(matches!(window, Window::Split(&[0, 1, 2], &[3])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_zero_len() {
+        let vec: SplitVec<_> = (0..10).collect();
+        _ = vec.windows(0);
+    }
+}
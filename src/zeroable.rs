@@ -0,0 +1,22 @@
+/// Marker trait for types whose all-zero bit pattern is a valid value.
+///
+/// This allows to allocate memory with `alloc_zeroed` and use the allocated positions
+/// as initialized elements without writing each element one by one.
+///
+/// # Safety
+///
+/// The implementor must guarantee that `core::mem::zeroed::<Self>()` is a valid instance of the type.
+/// For instance, numeric primitives and `bool` satisfy this requirement, while references or `NonZeroUsize` do not.
+pub unsafe trait Zeroable {}
+
+macro_rules! impl_zeroable {
+    ($($t:ty),*) => {
+        $(unsafe impl Zeroable for $t {})*
+    };
+}
+
+impl_zeroable!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char
+);
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
@@ -0,0 +1,74 @@
+use orx_split_vec::*;
+
+/// A growth with non-monotonic fragment capacities: 64, 8, 64, 8, ...
+#[derive(Clone)]
+struct Alternating;
+
+impl PseudoDefault for Alternating {
+    fn pseudo_default() -> Self {
+        Alternating
+    }
+}
+
+impl Growth for Alternating {
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        self.fragment_capacity_of(fragment_capacities.len())
+    }
+}
+
+impl GrowthWithConstantTimeAccess for Alternating {
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        let pair = element_index / 72;
+        let i = element_index % 72;
+        match i < 64 {
+            true => (2 * pair, i),
+            false => (2 * pair + 1, i - 64),
+        }
+    }
+
+    fn fragment_capacity_of(&self, fragment_index: usize) -> usize {
+        match fragment_index % 2 {
+            0 => 64,
+            _ => 8,
+        }
+    }
+}
+
+#[test]
+fn custom_growth_conforms() {
+    assert_eq!(
+        growth_with_constant_time_access_conformance_check(&Alternating, 1000),
+        Ok(())
+    );
+}
+
+#[test]
+fn custom_growth_con_vec_reserve_and_grow() {
+    const LEN: usize = 1000;
+
+    let vec: SplitVec<String, _> = SplitVec::with_growth(Alternating);
+    let mut con_vec = vec.into_concurrent();
+
+    let mut len = 0;
+    for max_capacity in [100, 300, 301, 700, LEN] {
+        let new_max_capacity =
+            unsafe { con_vec.reserve_maximum_concurrent_capacity(len, max_capacity) };
+        assert!(new_max_capacity >= max_capacity);
+
+        len = con_vec
+            .extend_sequential((len..max_capacity).map(|i| i.to_string()))
+            .unwrap();
+        assert_eq!(len, max_capacity);
+    }
+
+    assert_eq!(
+        con_vec.grow_to(con_vec.max_capacity() + 1),
+        Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+    );
+
+    let vec = unsafe { con_vec.into_inner(len) };
+    assert_eq!(vec, (0..LEN).map(|i| i.to_string()).collect::<Vec<_>>());
+}
@@ -0,0 +1,43 @@
+use orx_split_vec::*;
+use test_case::test_matrix;
+
+#[test_matrix([0, 1, 7, 100, 1033], [0, 1, 5, 64, 1000])]
+fn grow_by(len: usize, n: usize) {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>, len: usize, n: usize) {
+        let mut vec = vec;
+        vec.extend((0..len).map(|x| x.to_string()));
+        let con_vec = vec.into_concurrent();
+
+        let ptrs = con_vec
+            .grow_by(len, n)
+            .expect("must grow within maximum capacity");
+        assert_eq!(ptrs.len(), n);
+        for (i, ptr) in ptrs.enumerate() {
+            unsafe { ptr.write((len + i).to_string()) };
+        }
+        assert!(con_vec.capacity() >= len + n);
+
+        let vec = unsafe { con_vec.into_inner(len + n) };
+        let expected: Vec<_> = (0..(len + n)).map(|x| x.to_string()).collect();
+        assert_eq!(vec, expected);
+    }
+
+    test(
+        SplitVec::with_doubling_growth_and_fragments_capacity(32),
+        len,
+        n,
+    );
+    test(
+        SplitVec::with_linear_growth_and_fragments_capacity(3, 512),
+        len,
+        n,
+    );
+}
+
+#[test]
+fn grow_by_beyond_maximum_capacity() {
+    let vec: SplitVec<usize, Linear> = SplitVec::with_linear_growth_and_fragments_capacity(2, 2);
+    let con_vec = vec.into_concurrent();
+    assert!(con_vec.grow_by(0, 8).is_ok());
+    assert!(con_vec.grow_by(8, 1).is_err());
+}
@@ -1,6 +1,7 @@
 use orx_split_vec::*;
 
 #[test]
+#[allow(clippy::useless_conversion, clippy::reversed_empty_ranges)]
 fn iter_over_range() {
     let vec = SplitVec::<_, Doubling>::from_iter([0, 1, 2, 3, 4, 5, 6].into_iter());
     let con_vec = vec.into_concurrent();
@@ -28,3 +29,46 @@ fn iter_over_range() {
         assert_eq!(vec, &[1, 2, 3, 4]);
     }
 }
+
+#[test]
+fn slices_and_iter_over_range_of_len() {
+    fn test<G: GrowthWithConstantTimeAccess>(mut vec: SplitVec<usize, G>) {
+        let len = 777;
+        vec.extend(0..len);
+        let con_vec = vec.into_concurrent();
+        con_vec.grow_to(con_vec.capacity() + 1).unwrap();
+        assert!(con_vec.capacity() > len);
+
+        for (a, b) in [
+            (0, 0),
+            (0, 1),
+            (3, 60),
+            (100, len),
+            (0, len),
+            (500, 2000),
+            (len, 2000),
+        ] {
+            let expected: Vec<_> = (a..b.min(len)).collect();
+
+            let slices = unsafe { con_vec.slices_of_len(a..b, len) };
+            assert!(slices.iter().all(|x| !x.is_empty()));
+            let flat: Vec<_> = slices.iter().flat_map(|x| x.iter()).copied().collect();
+            assert_eq!(flat, expected);
+
+            let values: Vec<_> = unsafe { con_vec.iter_over_range_of_len(a..b, len) }
+                .copied()
+                .collect();
+            assert_eq!(values, expected);
+        }
+
+        let values: Vec<_> = unsafe { con_vec.iter_over_range_of_len(.., len) }
+            .copied()
+            .collect();
+        assert_eq!(values, (0..len).collect::<Vec<_>>());
+
+        let _ = unsafe { con_vec.into_inner(len) };
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(4, 512));
+}
@@ -1,3 +1,4 @@
+use orx_pinned_vec::PinnedVecGrowthError;
 use orx_split_vec::*;
 
 #[test]
@@ -50,3 +51,43 @@ fn drop_con_pin_vec_as_con_pin_vec() {
     test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
     test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
 }
+
+#[test]
+fn con_pin_vec_fragments_bounded_by_max_fragments() {
+    let max_fragments = Doubling.max_fragments().expect("doubling is bounded");
+
+    let vec: SplitVec<String, Doubling> =
+        SplitVec::with_doubling_growth_and_fragments_capacity(1000);
+
+    let mut con_vec = vec.into_concurrent();
+    let max_capacity = con_vec.max_capacity();
+    assert_eq!(
+        max_capacity,
+        Doubling.maximum_concurrent_capacity::<String>(&[], max_fragments)
+    );
+
+    assert_eq!(
+        con_vec.try_reserve_maximum_concurrent_capacity_exact(usize::MAX),
+        Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+    );
+    assert_eq!(con_vec.max_capacity(), max_capacity);
+
+    let vec = SplitVec::with_doubling_growth_and_fragments_capacity(1);
+    let mut con_vec: ConcurrentSplitVec<String, Doubling> = vec.into_concurrent();
+    assert!(con_vec
+        .try_reserve_maximum_concurrent_capacity_exact(max_capacity + 1)
+        .is_err());
+    assert_eq!(con_vec.max_capacity(), 4);
+
+    let result = con_vec.try_reserve_maximum_concurrent_capacity_exact(max_capacity);
+    assert_eq!(result, Ok((max_capacity, max_fragments - 1)));
+    let new_max_capacity = unsafe { con_vec.reserve_maximum_concurrent_capacity(0, 100) };
+    assert_eq!(new_max_capacity, max_capacity);
+
+    _ = con_vec.grow_to(100).expect("must grow");
+    for i in 0..100 {
+        unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+    }
+    let vec = unsafe { con_vec.into_inner(100) };
+    assert_eq!(vec, (0..100).map(|x| x.to_string()).collect::<Vec<_>>());
+}
@@ -103,6 +103,46 @@ fn con_pin_vec_grow_filled() {
     test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
 }
 
+#[test]
+fn con_pin_vec_grow_zeroed() {
+    const LEN: usize = 1486;
+
+    fn test<G: GrowthWithConstantTimeAccess>(mut vec: SplitVec<u64, G>) {
+        let initial_capacity = vec.capacity();
+        for _ in 0..initial_capacity {
+            vec.push(42);
+        }
+
+        let con_pinned_vec = vec.into_concurrent();
+
+        let new_capacity = con_pinned_vec.grow_to_zeroed(LEN).unwrap();
+        assert!(new_capacity >= LEN);
+        assert_eq!(con_pinned_vec.capacity(), new_capacity);
+
+        for i in 0..new_capacity {
+            let expected = match i < initial_capacity {
+                true => 42,
+                false => 0,
+            };
+            assert_eq!(unsafe { con_pinned_vec.get(i) }, Some(&expected));
+        }
+
+        for i in initial_capacity..LEN {
+            unsafe { *con_pinned_vec.get_ptr_mut(i) += i as u64 };
+        }
+
+        let vec = unsafe { con_pinned_vec.into_inner(LEN) };
+
+        assert_eq!(vec.len(), LEN);
+        for i in initial_capacity..vec.len() {
+            assert_eq!(vec[i], i as u64);
+        }
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
+}
+
 #[test]
 fn reserve() {
     fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
@@ -118,7 +158,7 @@ fn reserve() {
         unsafe { con_vec.reserve_maximum_concurrent_capacity(0, max_cap + 1) };
         let new_capacity = con_vec.capacity();
         assert_eq!(new_capacity, initial_capacity);
-        assert!(con_vec.max_capacity() >= max_cap + 1);
+        assert!(con_vec.max_capacity() > max_cap);
 
         let vec = unsafe { con_vec.into_inner(1) };
 
@@ -181,7 +221,7 @@ fn reserve_fill_with() {
         };
         let new_capacity = con_vec.capacity();
         assert_eq!(new_capacity, initial_capacity);
-        assert!(con_vec.max_capacity() >= max_cap + 1);
+        assert!(con_vec.max_capacity() > max_cap);
 
         let vec = unsafe { con_vec.into_inner(initial_capacity) };
 
@@ -198,3 +238,213 @@ fn reserve_fill_with() {
     test(SplitVec::with_doubling_growth_and_fragments_capacity(16));
     test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
 }
+
+#[test]
+fn metrics() {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let growth = vec.growth().clone();
+        let initial_capacity = vec.capacity();
+
+        let con_vec = vec.into_concurrent();
+        assert_eq!(con_vec.metrics(), ConcurrentSplitVecMetrics::default());
+
+        _ = con_vec.grow_to(initial_capacity).unwrap();
+        let metrics = con_vec.metrics();
+        assert_eq!(metrics.num_allocations, 0);
+        assert_eq!(metrics.grown_capacity, 0);
+        assert_eq!(metrics.num_grow_calls_with_sufficient_capacity, 1);
+
+        let new_capacity = con_vec.grow_to(initial_capacity + 1).unwrap();
+        let new_capacity = con_vec
+            .grow_to_and_fill_with(new_capacity + 1, || "x".to_string())
+            .unwrap();
+        let metrics = con_vec.metrics();
+        assert_eq!(metrics.num_allocations, 2);
+        assert_eq!(
+            metrics.grown_capacity,
+            growth.fragment_capacity_of(1) + growth.fragment_capacity_of(2)
+        );
+        assert_eq!(new_capacity, initial_capacity + metrics.grown_capacity);
+
+        _ = con_vec.grow_to(new_capacity - 1).unwrap();
+        assert_eq!(con_vec.metrics().num_grow_calls_with_sufficient_capacity, 2);
+
+        let _ = unsafe { con_vec.into_inner(0) };
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
+}
+
+#[test]
+fn extend_sequential() {
+    fn test<G: GrowthWithConstantTimeAccess>(mut vec: SplitVec<String, G>, len: usize) {
+        for i in 0..len {
+            vec.push(i.to_string());
+        }
+
+        let mut con_vec = vec.into_concurrent();
+
+        let new_len = con_vec.extend_sequential(core::iter::empty()).unwrap();
+        assert_eq!(new_len, len);
+
+        let new_len = con_vec
+            .extend_sequential((len..1486).map(|i| i.to_string()))
+            .unwrap();
+        assert_eq!(new_len, 1486);
+        assert!(con_vec.capacity() >= new_len);
+        assert!(con_vec.max_capacity() >= con_vec.capacity());
+
+        let new_len = con_vec
+            .extend_sequential((1486..2000).map(|i| i.to_string()))
+            .unwrap();
+        assert_eq!(new_len, 2000);
+
+        let vec = unsafe { con_vec.into_inner(new_len) };
+        assert_eq!(vec, (0..2000).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+
+    for len in [0, 1, 4, 5, 33] {
+        test(
+            SplitVec::with_doubling_growth_and_fragments_capacity(4),
+            len,
+        );
+        test(
+            SplitVec::with_linear_growth_and_fragments_capacity(3, 4),
+            len,
+        );
+    }
+}
+
+#[test]
+fn truncate() {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let growth = vec.growth().clone();
+        let cap_upto = |num_fragments: usize| -> usize {
+            (0..num_fragments)
+                .map(|f| growth.fragment_capacity_of(f))
+                .sum()
+        };
+        let num_fragments_for = |len: usize| -> usize {
+            match len {
+                0 => 1,
+                _ => growth.get_fragment_and_inner_indices_unchecked(len - 1).0 + 1,
+            }
+        };
+
+        for new_len in [0, 1, 3, 4, 5, 12, 13, 100, 199, 200, 201] {
+            let mut vec = vec.clone();
+            vec.extend((0..200).map(|i| i.to_string()));
+            let mut con_vec = vec.into_concurrent();
+            let initial_capacity = con_vec.capacity();
+
+            unsafe { con_vec.truncate(new_len, 200) };
+            let len = new_len.min(200);
+            let expected_capacity = match new_len >= 200 {
+                true => initial_capacity,
+                false => cap_upto(num_fragments_for(new_len)),
+            };
+            assert_eq!(con_vec.capacity(), expected_capacity);
+
+            let new_capacity = con_vec.grow_to(300).unwrap();
+            assert!(new_capacity >= 300);
+            con_vec
+                .extend_sequential((len..300).map(|i| i.to_string()))
+                .unwrap();
+
+            let vec = unsafe { con_vec.into_inner(300) };
+            assert_eq!(vec, (0..300).map(|i| i.to_string()).collect::<Vec<_>>());
+        }
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(3, 100));
+}
+
+#[test]
+fn reserve_maximum_concurrent_capacity_exact() {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let growth = vec.growth().clone();
+        let num_fragments = growth.max_fragments().unwrap_or(200).min(200);
+        let cumulative_capacities: Vec<_> = (0..num_fragments)
+            .scan(0, |cumulative, f| {
+                *cumulative += growth.fragment_capacity_of(f);
+                Some(*cumulative)
+            })
+            .collect();
+
+        let mut con_vec = vec.into_concurrent();
+
+        for requested in [0, 1, 7, 100, 1000, 1001, 12345, 100_000] {
+            let before = con_vec.max_capacity();
+            let (max_capacity, num_added) =
+                con_vec.reserve_maximum_concurrent_capacity_exact(requested);
+            assert_eq!(max_capacity, con_vec.max_capacity());
+
+            match requested <= before {
+                true => assert_eq!((max_capacity, num_added), (before, 0)),
+                false => {
+                    assert!(max_capacity >= requested);
+                    assert!(num_added > 0);
+                    // removing the last added fragment would not be sufficient
+                    let f = cumulative_capacities
+                        .iter()
+                        .position(|x| *x == max_capacity)
+                        .expect("is-some");
+                    assert!(f > 0 && cumulative_capacities[f - 1] < requested);
+                }
+            }
+        }
+
+        assert!(con_vec.grow_to(100_000).is_ok());
+        let vec = unsafe { con_vec.into_inner(0) };
+        assert!(vec.is_empty());
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(1));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(10, 1));
+}
+
+#[test]
+fn grow_to_at_least() {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let growth = vec.growth().clone();
+        let cumulative = |f: usize| {
+            (0..f)
+                .map(|f| growth.fragment_capacity_of(f))
+                .sum::<usize>()
+        };
+
+        let con_vec = vec.into_concurrent();
+        let max_capacity = con_vec.max_capacity();
+        assert_eq!(con_vec.capacity(), cumulative(1));
+
+        assert_eq!(
+            con_vec.grow_to_at_least(cumulative(1), 3),
+            Ok(cumulative(1))
+        );
+
+        assert_eq!(
+            con_vec.grow_to_at_least(cumulative(1) + 1, 0),
+            Ok(cumulative(2))
+        );
+        assert_eq!(
+            con_vec.grow_to_at_least(cumulative(2) + 1, 1),
+            Ok(cumulative(4))
+        );
+        assert_eq!(
+            con_vec.grow_to_at_least(cumulative(4) + 1, 2),
+            Ok(cumulative(7))
+        );
+
+        let capacity = con_vec.grow_to_at_least(max_capacity, 5).unwrap();
+        assert_eq!(capacity, max_capacity);
+        assert!(con_vec.grow_to_at_least(max_capacity + 1, 0).is_err());
+
+        let vec = unsafe { con_vec.into_inner(0) };
+        assert!(vec.is_empty());
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(16));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(4, 16));
+}
@@ -0,0 +1,79 @@
+use orx_split_vec::*;
+
+#[test]
+fn split_at_committed() {
+    const COMMITTED: usize = 33;
+    const LEN: usize = 1486;
+
+    fn test<G: GrowthWithConstantTimeAccess + Sync>(mut vec: SplitVec<String, G>) {
+        for i in 0..COMMITTED {
+            vec.push(i.to_string());
+        }
+
+        let mut con_vec = vec.into_concurrent();
+        let (prefix, mut suffix) = con_vec.split_at_committed(COMMITTED);
+
+        assert_eq!(prefix.len(), COMMITTED);
+        assert_eq!(suffix.len(), COMMITTED);
+        assert_eq!(prefix.get(COMMITTED), None);
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..10 {
+                        for i in 0..COMMITTED {
+                            assert_eq!(prefix.get(i), Some(&i.to_string()));
+                        }
+                        let flattened: Vec<_> = prefix.slices().into_iter().flatten().collect();
+                        assert_eq!(flattened, prefix.iter().collect::<Vec<_>>());
+                    }
+                });
+            }
+
+            s.spawn(|| {
+                for i in COMMITTED..LEN {
+                    assert_eq!(suffix.push(i.to_string()), Ok(i));
+                }
+            });
+        });
+
+        let len = suffix.len();
+        assert_eq!(len, LEN);
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        assert_eq!(vec, (0..LEN).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(10, 32));
+}
+
+#[test]
+fn split_at_committed_exceeding_max_capacity() {
+    let vec: SplitVec<usize, _> = SplitVec::with_linear_growth_and_fragments_capacity(2, 2);
+    let mut con_vec = vec.into_concurrent();
+    let max_capacity = con_vec.max_capacity();
+
+    let (prefix, mut suffix) = con_vec.split_at_committed(0);
+    assert!(prefix.is_empty());
+
+    for i in 0..max_capacity {
+        assert_eq!(suffix.push(i), Ok(i));
+    }
+    assert_eq!(
+        suffix.push(max_capacity),
+        Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+    );
+
+    let len = suffix.len();
+    let vec = unsafe { con_vec.into_inner(len) };
+    assert_eq!(vec, (0..max_capacity).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn split_at_committed_beyond_len() {
+    let vec: SplitVec<usize> = (0..5).collect();
+    let mut con_vec = vec.into_concurrent();
+    _ = con_vec.split_at_committed(6);
+}
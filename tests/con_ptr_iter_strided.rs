@@ -0,0 +1,83 @@
+use orx_split_vec::*;
+use test_case::test_matrix;
+
+#[test_matrix(
+    [0, 1, 7, 100, 1033],
+    [1, 2, 3, 5, 64, 1000]
+)]
+fn ptr_iter_strided(len: usize, step: usize) {
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<usize, G>, len: usize, step: usize) {
+        let mut vec = vec;
+        vec.extend(0..len);
+        let con_vec = vec.into_concurrent();
+
+        for start in [
+            0,
+            1,
+            2,
+            step.saturating_sub(1),
+            len.saturating_sub(1),
+            len,
+            len + 3,
+        ] {
+            let iter = unsafe { con_vec.ptr_iter_strided(start, step, len) };
+            let expected: Vec<_> = (start..len).step_by(step).collect();
+            assert_eq!(iter.len(), expected.len());
+
+            let values: Vec<_> = iter.map(|p| unsafe { *p }).collect();
+            assert_eq!(values, expected);
+        }
+    }
+
+    test(
+        SplitVec::with_doubling_growth_and_fragments_capacity(32),
+        len,
+        step,
+    );
+    test(
+        SplitVec::with_linear_growth_and_fragments_capacity(3, 512),
+        len,
+        step,
+    );
+}
+
+/// The concurrent vector is not `Sync` on its own, it is shared by concurrent wrappers which guarantee safety.
+struct Shared<'a, T>(&'a ConcurrentSplitVec<T, Doubling>);
+unsafe impl<T> Sync for Shared<'_, T> {}
+
+#[test]
+fn ptr_iter_strided_interleaved_partitions() {
+    const LEN: usize = 4567;
+    const NUM_THREADS: usize = 4;
+
+    let vec: SplitVec<String, Doubling> = SplitVec::with_doubling_growth_and_fragments_capacity(32);
+    let con_vec = vec.into_concurrent();
+    con_vec
+        .grow_to(LEN)
+        .expect("must grow within maximum capacity");
+
+    let shared = Shared(&con_vec);
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let shared = &shared;
+            s.spawn(move || {
+                let iter = unsafe { shared.0.ptr_iter_strided(t, NUM_THREADS, LEN) };
+                for (j, p) in iter.enumerate() {
+                    let idx = t + j * NUM_THREADS;
+                    unsafe { p.write(idx.to_string()) };
+                }
+            });
+        }
+    });
+
+    let vec = unsafe { con_vec.into_inner(LEN) };
+    assert_eq!(vec, (0..LEN).map(|x| x.to_string()).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn ptr_iter_strided_zero_step() {
+    let vec: SplitVec<_> = (0..10).collect();
+    let con_vec = vec.into_concurrent();
+    let _ = unsafe { con_vec.ptr_iter_strided(0, 0, 10) };
+}
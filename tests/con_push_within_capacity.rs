@@ -0,0 +1,90 @@
+use orx_split_vec::*;
+use std::sync::{Arc, Mutex};
+
+/// The concurrent vector is not `Sync` on its own, it is shared by concurrent wrappers which guarantee safety.
+struct Shared<'a, T, G: GrowthWithConstantTimeAccess>(&'a ConcurrentSplitVec<T, G>);
+unsafe impl<T, G: GrowthWithConstantTimeAccess> Sync for Shared<'_, T, G> {}
+
+#[test]
+fn con_push_within_capacity() {
+    const NUM_THREADS: usize = 8;
+    const NUM_PER_THREAD: usize = 500;
+
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let con_vec = vec.into_concurrent();
+        assert_eq!(con_vec.len(), 0);
+        assert!(con_vec.is_empty());
+
+        let shared = Shared(&con_vec);
+        // grow_to is not safe against concurrent producers; hence, growth is serialized
+        let grow_lock = Mutex::new(());
+        std::thread::scope(|s| {
+            for t in 0..NUM_THREADS {
+                let shared = &shared;
+                let grow_lock = &grow_lock;
+                s.spawn(move || {
+                    let con_vec = shared.0;
+                    for i in 0..NUM_PER_THREAD {
+                        let mut value = (t * NUM_PER_THREAD + i).to_string();
+                        loop {
+                            match con_vec.push_within_capacity(value) {
+                                Ok(idx) => {
+                                    assert!(idx < con_vec.capacity());
+                                    break;
+                                }
+                                Err(x) => {
+                                    value = x;
+                                    let _guard = grow_lock.lock().unwrap();
+                                    con_vec.grow_to(con_vec.capacity() + 1).unwrap();
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let len = con_vec.len();
+        assert_eq!(len, NUM_THREADS * NUM_PER_THREAD);
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        let mut values: Vec<_> = vec.iter().map(|x| x.parse::<usize>().unwrap()).collect();
+        values.sort();
+        assert_eq!(values, (0..len).collect::<Vec<_>>());
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(6, 256));
+}
+
+#[test]
+fn con_push_within_capacity_fails_when_full() {
+    let vec: SplitVec<_, _> = SplitVec::with_linear_growth_and_fragments_capacity(2, 4);
+    let con_vec = vec.into_concurrent();
+
+    for i in 0..4 {
+        assert_eq!(con_vec.push_within_capacity(i), Ok(i));
+    }
+    assert_eq!(con_vec.push_within_capacity(42), Err(42));
+    assert_eq!(con_vec.len(), 4);
+
+    con_vec.grow_to(5).unwrap();
+    assert_eq!(con_vec.push_within_capacity(42), Ok(4));
+    assert_eq!(con_vec.len(), 5);
+}
+
+#[test]
+fn con_len_starts_from_split_vec_and_drops_pushed() {
+    let rc = Arc::new(0);
+    let mut vec = SplitVec::with_doubling_growth_and_fragments_capacity(16);
+    vec.extend((0..3).map(|_| rc.clone()));
+
+    let con_vec = vec.into_concurrent();
+    assert_eq!(con_vec.len(), 3);
+
+    assert_eq!(con_vec.push_within_capacity(rc.clone()), Ok(3));
+    assert_eq!(Arc::strong_count(&rc), 5);
+
+    drop(con_vec);
+    assert_eq!(Arc::strong_count(&rc), 1);
+}
@@ -0,0 +1,91 @@
+use orx_split_vec::*;
+
+/// The concurrent vector is not `Sync` on its own, it is shared by concurrent wrappers which guarantee safety.
+struct Shared<'a, T, G: GrowthWithConstantTimeAccess>(&'a ConcurrentSplitVec<T, G>);
+unsafe impl<T, G: GrowthWithConstantTimeAccess> Sync for Shared<'_, T, G> {}
+
+#[test]
+fn con_reserve_slot() {
+    const NUM_THREADS: usize = 8;
+    const NUM_PER_THREAD: usize = 1000;
+
+    fn test<G: GrowthWithConstantTimeAccess>(vec: SplitVec<String, G>) {
+        let con_vec = vec.into_concurrent();
+        let shared = Shared(&con_vec);
+
+        std::thread::scope(|s| {
+            for t in 0..NUM_THREADS {
+                let shared = &shared;
+                s.spawn(move || {
+                    let con_vec = shared.0;
+                    for i in 0..NUM_PER_THREAD {
+                        let value = (t * NUM_PER_THREAD + i).to_string();
+                        let (idx, ptr) = unsafe { con_vec.reserve_slot() }.expect("within max");
+                        assert!(idx < NUM_THREADS * NUM_PER_THREAD);
+                        unsafe { ptr.write(value) };
+                    }
+                });
+            }
+        });
+
+        let len = NUM_THREADS * NUM_PER_THREAD;
+        assert_eq!(con_vec.len(), len);
+        assert!(con_vec.capacity() >= len);
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        assert_eq!(vec.len(), len);
+        let mut values: Vec<_> = vec
+            .iter()
+            .map(|x| x.parse::<usize>().expect("number"))
+            .collect();
+        values.sort();
+        assert_eq!(values, (0..len).collect::<Vec<_>>());
+    }
+
+    test(SplitVec::with_doubling_growth_and_fragments_capacity(32));
+    test(SplitVec::with_linear_growth_and_fragments_capacity(6, 2048));
+}
+
+#[test]
+fn reserve_slot_up_to_maximum_capacity() {
+    let vec: SplitVec<usize, _> = SplitVec::with_linear_growth_and_fragments_capacity(2, 3);
+    let con_vec = vec.into_concurrent();
+    let max_capacity = con_vec.max_capacity();
+    assert_eq!(max_capacity, 12);
+
+    for i in 0..max_capacity {
+        let (idx, ptr) = unsafe { con_vec.reserve_slot() }.expect("within max");
+        assert_eq!(idx, i);
+        unsafe { ptr.write(i) };
+    }
+    assert_eq!(con_vec.capacity(), max_capacity);
+    assert!(unsafe { con_vec.reserve_slot() }.is_err());
+    assert_eq!(con_vec.len(), max_capacity);
+
+    let vec = unsafe { con_vec.into_inner(max_capacity) };
+    assert_eq!(vec, (0..max_capacity).collect::<Vec<_>>());
+}
+
+#[test]
+fn reserve_slot_after_clear() {
+    let vec: SplitVec<usize, _> = SplitVec::with_doubling_growth_and_fragments_capacity(8);
+    let mut con_vec = vec.into_concurrent();
+
+    for i in 0..3 {
+        let (_, ptr) = unsafe { con_vec.reserve_slot() }.expect("within max");
+        unsafe { ptr.write(i) };
+    }
+    unsafe { con_vec.clear(3) };
+    assert_eq!(con_vec.len(), 0);
+
+    for i in 0..10 {
+        let (idx, ptr) = unsafe { con_vec.reserve_slot() }.expect("within max");
+        assert_eq!(idx, i);
+        unsafe { ptr.write(i) };
+    }
+    assert_eq!(con_vec.len(), 10);
+    assert!(con_vec.capacity() >= 10);
+
+    let vec = unsafe { con_vec.into_inner(10) };
+    assert_eq!(vec, (0..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,83 @@
+use orx_split_vec::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+#[test]
+fn into_con_iter_concurrent() {
+    const NUM_THREADS: usize = 8;
+    const LEN: usize = 4567;
+
+    fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+        vec.extend((0..LEN).map(|x| x.to_string()));
+        let con_iter = vec.into_con_iter();
+        let collected = Mutex::new(Vec::new());
+
+        std::thread::scope(|s| {
+            for t in 0..NUM_THREADS {
+                let (con_iter, collected) = (&con_iter, &collected);
+                s.spawn(move || {
+                    let mut local = Vec::new();
+                    loop {
+                        match t % 2 == 0 {
+                            true => match con_iter.next() {
+                                Some(x) => local.push(x),
+                                None => break,
+                            },
+                            false => match con_iter.next_chunk(t + 1) {
+                                Some(chunk) => local.extend(chunk),
+                                None => break,
+                            },
+                        }
+                    }
+                    collected.lock().expect("not poisoned").extend(local);
+                });
+            }
+        });
+
+        let mut collected: Vec<_> = collected
+            .into_inner()
+            .expect("not poisoned")
+            .into_iter()
+            .map(|x| x.parse::<usize>().expect("number"))
+            .collect();
+        collected.sort();
+        assert_eq!(collected, (0..LEN).collect::<Vec<_>>());
+    }
+
+    test(SplitVec::with_doubling_growth());
+    test(SplitVec::with_linear_growth(4));
+    test(SplitVec::with_recursive_growth());
+}
+
+#[test]
+fn into_con_iter_concurrent_early_exit_drops_remaining() {
+    struct Counted<'a>(&'a AtomicUsize);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    const LEN: usize = 1000;
+    let num_dropped = AtomicUsize::new(0);
+
+    let mut vec = SplitVec::with_recursive_growth();
+    vec.extend((0..LEN).map(|_| Counted(&num_dropped)));
+    let con_iter = vec.into_con_iter();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..10 {
+                    if let Some(mut chunk) = con_iter.next_chunk(16) {
+                        _ = chunk.next();
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 4 * 10 * 16);
+
+    drop(con_iter);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), LEN);
+}
@@ -57,6 +57,7 @@ fn check_current_values(vec: &SplitVec<String, impl Growth>) {
 /// It is possible that this test passes even if the elements are
 /// moved in memory in a normal execution. However, it must fail
 /// when executed with miri.
+#[allow(clippy::ptr_arg)]
 fn validate_immediate_pointers(immediate_ptrs: &Vec<*const String>) {
     for (i, ptr) in immediate_ptrs.iter().copied().enumerate() {
         let elem = unsafe { &*ptr };
@@ -74,6 +75,7 @@ fn validate_immediate_pointers(immediate_ptrs: &Vec<*const String>) {
 ///
 /// Immediate and current pointer of each element will be the same
 /// iff the memory locations of elements remained intact.
+#[allow(clippy::ptr_arg, clippy::needless_range_loop)]
 fn compare_current_and_immediate_pointers(
     vec: &SplitVec<String, impl Growth>,
     immediate_ptrs: &Vec<*const String>,
@@ -0,0 +1,69 @@
+//! Ensures that the common functionalities of the crate are usable by importing only the prelude.
+
+use orx_split_vec::prelude::*;
+
+fn use_growth<G: Growth>(mut vec: SplitVec<usize, G>) -> SplitVec<usize, G> {
+    for i in 0..100 {
+        vec.push(i);
+    }
+    vec.insert(0, 42);
+    vec.remove(0);
+    vec
+}
+
+fn use_constant_time_growth<G: GrowthWithConstantTimeAccess>(vec: SplitVec<usize, G>) -> usize {
+    let con_vec: ConcurrentSplitVec<usize, G> = vec.into_concurrent();
+    let _: ConcurrentSplitVecMetrics = con_vec.metrics();
+    let capacity = con_vec.capacity();
+    let vec = unsafe { con_vec.into_inner(100) };
+    assert!(capacity >= vec.len());
+    vec.len()
+}
+
+#[test]
+fn prelude_only() {
+    let doubling: SplitVec<usize, Doubling> = use_growth(SplitVec::with_doubling_growth());
+    let linear: SplitVec<usize, Linear> = use_growth(SplitVec::with_linear_growth(4));
+    let mut recursive: SplitVec<usize, Recursive> = use_growth(SplitVec::with_recursive_growth());
+    assert!(doubling.iter().eq(linear.iter()));
+    assert!(doubling.iter().eq(recursive.iter()));
+
+    recursive.append(vec![100, 101]);
+    let fragments: &[Fragment<usize>] = recursive.fragments();
+    assert!(!fragments.is_empty());
+
+    let iter: Iter<'_, usize> = doubling.iter();
+    assert_eq!(iter.sum::<usize>(), (0..100).sum());
+    let iter_rev: IterRev<'_, usize> = doubling.iter_rev();
+    assert_eq!(iter_rev.copied().next(), Some(99));
+
+    let mut linear = linear;
+    let iter_mut: IterMut<'_, usize> = linear.iter_mut();
+    iter_mut.for_each(|x| *x += 1);
+    let iter_mut_rev: IterMutRev<'_, usize> = linear.iter_mut_rev();
+    iter_mut_rev.for_each(|x| *x -= 1);
+
+    let slice: Option<SplitVecSlice<'_, usize>> = Some(linear.try_get_slice(0..2));
+    assert!(slice.is_some());
+
+    let into_iter: IntoIter<usize> = linear.into_iter();
+    assert_eq!(into_iter.count(), 100);
+
+    assert_eq!(use_constant_time_growth(doubling), 100);
+    assert_eq!(growth_conformance_check(&Recursive, 100), Ok(()));
+    assert_eq!(
+        growth_with_constant_time_access_conformance_check(&Doubling, 100),
+        Ok(())
+    );
+
+    let _: Option<PinnedVecGrowthError> = None;
+    let _ = <Doubling as PseudoDefault>::pseudo_default();
+    fn _zeroable<T: Zeroable>() {}
+    _zeroable::<u64>();
+    fn _into_fragments<T, F: IntoFragments<T>>() {}
+    _into_fragments::<usize, Vec<usize>>();
+    fn _con_pinned_vec<T, C: ConcurrentPinnedVec<T>>() {}
+    _con_pinned_vec::<usize, ConcurrentSplitVec<usize>>();
+    fn _suffix<'a>(_: Option<ConcurrentSplitVecSuffix<'a, usize, Doubling>>) {}
+    fn _prefix<'a>(_: Option<ConcurrentSplitVecPrefix<'a, usize, Doubling>>) {}
+}
@@ -29,3 +29,36 @@ fn test_slices(len: usize) {
     slices(SplitVec::with_recursive_growth(), len);
     slices(SplitVec::with_linear_growth(4), len);
 }
+
+fn slices_rev<G: Growth>(mut vec: SplitVec<String, G>, len: usize) {
+    vec.clear();
+    vec.extend((0..len).map(|i| i.to_string()));
+
+    let begin = len / 4;
+    let end = len - len / 4;
+
+    let slices = vec.slices(begin..end).into_iter();
+    assert_eq!(slices.len(), vec.slices(begin..end).len());
+
+    let mut val = end;
+    for slice in slices.rev() {
+        for x in slice.iter().rev() {
+            val -= 1;
+            assert_eq!(x, &val.to_string());
+        }
+    }
+    assert_eq!(val, begin);
+
+    let num_slices = vec.slices(begin..end).len();
+    if num_slices > 0 {
+        let last = vec.slices(begin..end).into_iter().nth(num_slices - 1);
+        assert_eq!(last.and_then(|x| x.last()), Some(&(end - 1).to_string()));
+    }
+}
+
+#[test_matrix([0, 1, 4, 5, 15, 16, 17, 1033])]
+fn test_slices_rev(len: usize) {
+    slices_rev(SplitVec::with_doubling_growth(), len);
+    slices_rev(SplitVec::with_recursive_growth(), len);
+    slices_rev(SplitVec::with_linear_growth(4), len);
+}